@@ -0,0 +1,60 @@
+//! Counts the allocations of a frame without toasts when the [`Toasts`] instance is built
+//! on every frame, and when it is kept in a [`ToastsConfig`].
+//!
+//! Run with `cargo bench --bench config_show`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use egui_toast::{Toast, ToastOptions, Toasts};
+
+const FRAMES: usize = 1_000;
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn build() -> Toasts {
+    Toasts::new()
+        .anchor(egui::Align2::RIGHT_BOTTOM, (-10.0, -10.0))
+        .custom_contents(0, |ui, toast| ui.label(toast.text.clone()))
+}
+
+fn measure(name: &str, mut frame: impl FnMut()) {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..FRAMES {
+        frame();
+    }
+    let per_frame = (ALLOCATIONS.load(Ordering::Relaxed) - before) as f64 / FRAMES as f64;
+    println!("{name}: {per_frame} allocations per frame");
+}
+
+fn main() {
+    let ctx = egui::Context::default();
+    ctx.begin_pass(Default::default());
+
+    // A toast that expires right away, so the toasts are idle again after showing it
+    let mut toasts = build();
+    toasts.add(Toast::new().options(ToastOptions::default().duration_in_seconds(0.0)));
+    toasts.show(&ctx);
+
+    measure("built every frame", || build().show(&ctx));
+    let config = build().into_config();
+    measure("kept in a ToastsConfig", || config.show(&ctx));
+
+    let _ = ctx.end_pass();
+}
@@ -0,0 +1,30 @@
+//! Measures the frames that show a flood of toasts, which are capped at 200 so that a bug
+//! adding thousands of toasts does not hang the app.
+//!
+//! Run with `cargo bench --bench flood`.
+
+// The benchmark only runs natively, so it can measure with `Instant`
+#![allow(clippy::disallowed_types)]
+
+use std::time::Instant;
+
+use egui_toast::{Toast, Toasts};
+
+const TOASTS: usize = 10_000;
+
+fn main() {
+    let ctx = egui::Context::default();
+    let mut toasts = Toasts::new();
+    for i in 0..TOASTS {
+        toasts.add(Toast::new().text(format!("Log line {i}")));
+    }
+    // New toasts are measured on their first frame and drawn on the next one
+    for frame in ["first frame", "second frame"] {
+        let start = Instant::now();
+        let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+        println!(
+            "{frame} after adding {TOASTS} toasts: {:?}",
+            start.elapsed()
+        );
+    }
+}
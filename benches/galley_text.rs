@@ -0,0 +1,46 @@
+//! Compares the cost of a frame with a 50-line toast given as a string and as a galley
+//! laid out in advance, see [`Toast::galley`].
+//!
+//! Run with `cargo bench --bench galley_text`.
+
+// The benchmark only runs natively, so it can measure with `Instant`
+#![allow(clippy::disallowed_types)]
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use egui_toast::{Toast, Toasts};
+
+const FRAMES: u32 = 10_000;
+
+fn measure(name: &str, toast: impl FnOnce(&egui::Context) -> Toast) {
+    let ctx = egui::Context::default();
+    // Load the fonts
+    let _ = ctx.run(Default::default(), |_| {});
+    let mut toasts = Toasts::new();
+    toasts.add(toast(&ctx));
+    // Measure and lay out the toast before timing
+    for _ in 0..3 {
+        let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    }
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        let _ = ctx.run(Default::default(), |ctx| black_box(&mut toasts).show(ctx));
+    }
+    let per_frame = start.elapsed() / FRAMES;
+    println!("{name}: {per_frame:?} per frame");
+}
+
+fn main() {
+    let text: String = (1..=50)
+        .map(|line| format!("error[E0308]: mismatched types on line {line}\n"))
+        .collect();
+
+    measure("text", |_| Toast::new().text(text.clone()));
+    measure("galley", |ctx| {
+        let font_id = egui::FontId::monospace(12.0);
+        let galley =
+            ctx.fonts(|fonts| fonts.layout_no_wrap(text, font_id, egui::Color32::PLACEHOLDER));
+        Toast::new().galley(galley)
+    });
+}
@@ -0,0 +1,38 @@
+//! Measures the cost of [`Toasts::show`] on frames without toasts.
+//!
+//! Run with `cargo bench --bench idle_show`.
+
+// The benchmark only runs natively, so it can measure with `Instant`
+#![allow(clippy::disallowed_types)]
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use egui_toast::{Toast, ToastOptions, Toasts};
+
+const ITERATIONS: u32 = 1_000_000;
+
+fn measure(name: &str, ctx: &egui::Context) {
+    let mut toasts = Toasts::new();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(&mut toasts).show(black_box(ctx));
+    }
+    let per_call = start.elapsed() / ITERATIONS;
+    println!("{name}: {per_call:?} per call");
+}
+
+fn main() {
+    let ctx = egui::Context::default();
+    ctx.begin_pass(Default::default());
+
+    measure("never shown a toast", &ctx);
+
+    // A toast that expires right away, so the instance is idle again after showing it
+    let mut toasts = Toasts::new();
+    toasts.add(Toast::new().options(ToastOptions::default().duration_in_seconds(0.0)));
+    toasts.show(&ctx);
+    measure("after the toasts are gone", &ctx);
+
+    let _ = ctx.end_pass();
+}
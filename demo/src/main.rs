@@ -1,10 +1,14 @@
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use eframe::egui;
 use eframe::epaint::Margin;
 use egui::{Align2, Color32, Direction, Frame, Pos2, RichText, Widget};
 
-use egui_toast::{Toast, ToastKind, ToastOptions, ToastStyle, Toasts};
+use egui_toast::{
+    ProgressStyle, Toast, ToastButton, ToastKind, ToastOptions, ToastStyle, ToastTemplate, Toasts,
+    ToastsPreset,
+};
 
 /// Identifier for a custom toast kind
 const MY_CUSTOM_TOAST: u32 = 0;
@@ -49,6 +53,42 @@ struct Demo {
     kind: ToastKind,
     show_icon: bool,
     show_progress: bool,
+    progress_style: ProgressStyle,
+    style_preset: StylePreset,
+    scale: f32,
+    time_scale: f32,
+    /// Messages that can be deleted with an undo toast
+    inbox: Arc<Mutex<Vec<String>>>,
+}
+
+/// Style presets shipped with egui-toast
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum StylePreset {
+    Default,
+    MaterialDark,
+    MaterialLight,
+    Minimal,
+    HighContrast,
+}
+
+impl StylePreset {
+    const ALL: [Self; 5] = [
+        Self::Default,
+        Self::MaterialDark,
+        Self::MaterialLight,
+        Self::Minimal,
+        Self::HighContrast,
+    ];
+
+    fn style(self) -> ToastStyle {
+        match self {
+            Self::Default => ToastStyle::default(),
+            Self::MaterialDark => ToastStyle::material_dark(),
+            Self::MaterialLight => ToastStyle::material_light(),
+            Self::Minimal => ToastStyle::minimal(),
+            Self::HighContrast => ToastStyle::high_contrast(),
+        }
+    }
 }
 
 impl Default for Demo {
@@ -62,6 +102,11 @@ impl Default for Demo {
             kind: ToastKind::Info,
             show_icon: true,
             show_progress: true,
+            progress_style: ProgressStyle::Bar,
+            style_preset: StylePreset::Default,
+            scale: 1.0,
+            time_scale: 1.0,
+            inbox: Arc::new(Mutex::new(inbox())),
         }
     }
 }
@@ -72,18 +117,55 @@ impl eframe::App for Demo {
         let mut toasts = Toasts::new()
             .anchor(self.alignment, self.offset)
             .direction(self.direction)
-            .custom_contents(MY_CUSTOM_TOAST, my_custom_toast_contents);
+            .focusable(true)
+            .auto_group(5)
+            .draggable(true)
+            .clear_all_button(4)
+            .scale(self.scale)
+            .time_scale(self.time_scale)
+            .style(
+                self.style_preset
+                    .style()
+                    .progress_style(self.progress_style),
+            )
+            .custom_contents(MY_CUSTOM_TOAST, my_custom_toast_contents)
+            .register_template(
+                "destructive action",
+                ToastTemplate::new(ToastKind::Warning)
+                    .options(ToastOptions::default().duration_in_seconds(8.0))
+                    .button(ToastButton::new("Undo").primary(true)),
+            )
+            .register_template(
+                "tip",
+                ToastTemplate::new(ToastKind::Info).icon("💡").options(
+                    ToastOptions::default()
+                        .duration_in_seconds(5.0)
+                        .show_progress(false),
+                ),
+            );
+
+        let mut snackbars = ToastsPreset::snackbar_bottom_center();
 
         // Show the options window
-        self.options_window(ctx, &mut toasts);
+        let (alignment, offset, direction) = (self.alignment, self.offset, self.direction);
+        self.options_window(ctx, &mut toasts, &mut snackbars);
+
+        // Move the visible toasts smoothly if the layout was changed
+        if (alignment, offset) != (self.alignment, self.offset) {
+            toasts.set_anchor(ctx, self.alignment, self.offset);
+        }
+        if direction != self.direction {
+            toasts.set_direction(ctx, self.direction);
+        }
 
         // Draw and update the toasts
         toasts.show(ctx);
+        snackbars.show(ctx);
     }
 }
 
 impl Demo {
-    fn options_window(&mut self, ctx: &egui::Context, toasts: &mut Toasts) {
+    fn options_window(&mut self, ctx: &egui::Context, toasts: &mut Toasts, snackbars: &mut Toasts) {
         let Self {
             i,
             offset: position,
@@ -93,6 +175,11 @@ impl Demo {
             kind,
             show_icon,
             show_progress,
+            progress_style,
+            style_preset,
+            scale,
+            time_scale,
+            inbox,
         } = self;
 
         egui::Window::new("Demo options")
@@ -156,6 +243,28 @@ impl Demo {
                 ui.checkbox(show_icon, "Show icon");
                 ui.checkbox(show_progress, "Show progress");
 
+                egui::ComboBox::from_label("Progress style")
+                    .selected_text(format!("{:?}", progress_style))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(progress_style, ProgressStyle::Bar, "Bar");
+                        ui.selectable_value(
+                            progress_style,
+                            ProgressStyle::CircleAroundClose,
+                            "CircleAroundClose",
+                        );
+                    });
+
+                egui::ComboBox::from_label("Style preset")
+                    .selected_text(format!("{:?}", style_preset))
+                    .show_ui(ui, |ui| {
+                        for preset in StylePreset::ALL {
+                            ui.selectable_value(style_preset, preset, format!("{:?}", preset));
+                        }
+                    });
+
+                ui.add(egui::Slider::new(scale, 0.9..=1.5).text("Toast size"));
+                ui.add(egui::Slider::new(time_scale, 0.0..=2.0).text("Time scale"));
+
                 ui.separator();
 
                 let duration = if *duration_sec < 0.01 {
@@ -169,15 +278,49 @@ impl Demo {
                     .show_progress(*show_progress)
                     .duration(duration);
 
-                let style = ToastStyle::default();
-
                 if ui.button("Give me a toast").clicked() {
                     toasts.add(
                         Toast::default()
                             .kind(*kind)
                             .text(format!("Hello, I am a toast {}", i))
+                            .options(options),
+                    );
+
+                    *i += 1;
+                }
+
+                if ui.button("Give me a toast with buttons").clicked() {
+                    toasts.add(
+                        Toast::default()
+                            .kind(*kind)
+                            .text(format!("Hello, I am a toast {} with buttons", i))
                             .options(options)
-                            .style(style.clone()),
+                            .button(ToastButton::new("Undo").primary(true))
+                            .button(ToastButton::new("Dismiss")),
+                    );
+
+                    *i += 1;
+                }
+
+                if ui.button("Give me a grouped toast").clicked() {
+                    toasts.add(
+                        Toast::default()
+                            .kind(*kind)
+                            .text(format!("Hello, I am a grouped toast {}", i))
+                            .options(options)
+                            .group("Grouped toasts"),
+                    );
+
+                    *i += 1;
+                }
+
+                if ui.button("Give me a toast with its own icon").clicked() {
+                    toasts.add(
+                        Toast::default()
+                            .kind(*kind)
+                            .text(format!("Hello, I am a toast {} with a bell", i))
+                            .options(options)
+                            .icon("🔔"),
                     );
 
                     *i += 1;
@@ -188,16 +331,71 @@ impl Demo {
                         Toast::default()
                             .kind(ToastKind::Custom(MY_CUSTOM_TOAST))
                             .text(format!("Hello, I am a custom toast {}", i))
-                            .options(options)
-                            .style(style.clone()),
+                            .options(options),
                     );
 
                     *i += 1;
                 }
+
+                ui.separator();
+                ui.label("Templates");
+
+                ui.horizontal(|ui| {
+                    if ui.button("Delete project").clicked() {
+                        toasts.add_from_template("destructive action", "Project deleted");
+                    }
+                    if ui.button("Show a tip").clicked() {
+                        toasts.add_from_template("tip", "Drag the toasts to move them");
+                    }
+                });
+
+                ui.separator();
+                ui.label("Presets");
+
+                ui.horizontal(|ui| {
+                    for text in ["Copied", "Saved", "Zoom 150%"] {
+                        if ui.button(text).clicked() {
+                            snackbars.add(Toast::chip(text));
+                        }
+                    }
+                });
+
+                ui.separator();
+                ui.label("Inbox");
+
+                // The messages are deleted when their toast goes away, unless undone
+                let messages = inbox.lock().unwrap().clone();
+                for message in &messages {
+                    ui.horizontal(|ui| {
+                        ui.label(message);
+                        if ui.small_button("Delete").clicked() {
+                            let inbox = inbox.clone();
+                            let deleted = message.clone();
+                            toasts.undoable(
+                                format!("Deleted \"{}\"", message),
+                                "Undo",
+                                move || inbox.lock().unwrap().retain(|m| *m != deleted),
+                                options,
+                            );
+                        }
+                    });
+                }
+                if messages.is_empty() && ui.button("Refill the inbox").clicked() {
+                    *inbox.lock().unwrap() = self::inbox();
+                }
+
+                ui.separator();
+                egui::CollapsingHeader::new("Inspect toasts").show(ui, |ui| toasts.debug_ui(ui));
             });
     }
 }
 
+fn inbox() -> Vec<String> {
+    ["Lunch on Friday?", "Your invoice", "Weekly report"]
+        .map(str::to_owned)
+        .to_vec()
+}
+
 fn my_custom_toast_contents(ui: &mut egui::Ui, toast: &mut Toast) -> egui::Response {
     Frame::default()
         .fill(Color32::from_rgb(33, 150, 243))
@@ -0,0 +1,113 @@
+use std::iter::Peekable;
+use std::str::Chars;
+
+use egui::Color32;
+
+use crate::sanitize::skip_escape_sequence;
+use crate::ToastText;
+
+/// The 16 basic colors: black, red, green, yellow, blue, magenta, cyan and white, then their
+/// bright variants.
+const PALETTE: [Color32; 16] = [
+    Color32::from_rgb(0, 0, 0),
+    Color32::from_rgb(205, 49, 49),
+    Color32::from_rgb(13, 188, 121),
+    Color32::from_rgb(229, 229, 16),
+    Color32::from_rgb(36, 114, 200),
+    Color32::from_rgb(188, 63, 188),
+    Color32::from_rgb(17, 168, 205),
+    Color32::from_rgb(229, 229, 229),
+    Color32::from_rgb(102, 102, 102),
+    Color32::from_rgb(241, 76, 76),
+    Color32::from_rgb(35, 209, 139),
+    Color32::from_rgb(245, 245, 67),
+    Color32::from_rgb(59, 142, 234),
+    Color32::from_rgb(214, 112, 214),
+    Color32::from_rgb(41, 184, 219),
+    Color32::from_rgb(255, 255, 255),
+];
+
+#[derive(Copy, Clone, Default, PartialEq)]
+struct Format {
+    bold: bool,
+    color: Option<Color32>,
+}
+
+impl Format {
+    /// Apply the parameters of a Select Graphic Rendition sequence. The unsupported
+    /// parameters are ignored.
+    fn apply(&mut self, params: &str) {
+        let mut params = params
+            .split(';')
+            .map(|param| param.parse::<u8>().unwrap_or(0));
+        while let Some(param) = params.next() {
+            match param {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                22 => self.bold = false,
+                30..=37 => self.color = Some(PALETTE[usize::from(param - 30)]),
+                90..=97 => self.color = Some(PALETTE[usize::from(param - 90 + 8)]),
+                39 => self.color = None,
+                // Extended colors are not supported, skip their arguments
+                38 | 48 | 58 => match params.next() {
+                    Some(5) => {
+                        params.next();
+                    }
+                    Some(2) => {
+                        params.by_ref().take(3).for_each(drop);
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Parse the output of a process into a [`ToastText`], see
+/// [`Toast::ansi_text`](crate::Toast::ansi_text).
+pub(crate) fn parse(raw: &str) -> ToastText {
+    let mut text = ToastText::new();
+    let mut format = Format::default();
+    let mut fragment = String::new();
+    let mut chars = raw.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\u{1b}' => {
+                let Some(params) = sgr_params(&mut chars) else {
+                    continue;
+                };
+                let mut next = format;
+                next.apply(&params);
+                if next != format && !fragment.is_empty() {
+                    text = text.ansi(std::mem::take(&mut fragment), format.bold, format.color);
+                }
+                format = next;
+            }
+            '\n' | '\t' => fragment.push(c),
+            c if c.is_control() => {}
+            c => fragment.push(c),
+        }
+    }
+    if !fragment.is_empty() {
+        text = text.ansi(fragment, format.bold, format.color);
+    }
+    text
+}
+
+/// Consume the escape sequence after an ESC. Returns the parameters if it is a Select
+/// Graphic Rendition sequence.
+fn sgr_params(chars: &mut Peekable<Chars>) -> Option<String> {
+    if chars.next_if_eq(&'[').is_none() {
+        skip_escape_sequence(chars);
+        return None;
+    }
+    let mut params = String::new();
+    for c in chars.by_ref() {
+        if ('@'..='~').contains(&c) {
+            return (c == 'm').then_some(params);
+        }
+        params.push(c);
+    }
+    None
+}
@@ -0,0 +1,118 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::time::Duration;
+
+use egui::emath::TSTransform;
+use egui::{Color32, Context, Id, Response, Rounding};
+
+use crate::motion::Motion;
+use crate::{Toast, ToastId};
+
+/// A brief emphasis of a toast whose text or kind changed, see
+/// [`ToastStyle::update_blip`](crate::ToastStyle::update_blip).
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct BlipStyle {
+    /// Length of the animation. Defaults to 300 ms.
+    pub duration: Duration,
+    /// Scale the toast grows to halfway through the animation, before it shrinks back.
+    /// Defaults to 1.03. The toast is not scaled with
+    /// [reduced motion](crate::Toasts::reduce_motion).
+    pub scale: f32,
+    /// Whether the toast flashes in the [accent color](crate::ToastStyle::accent_color) of
+    /// its kind. Defaults to `false`.
+    pub flash: bool,
+}
+
+impl Default for BlipStyle {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(300),
+            scale: 1.03,
+            flash: false,
+        }
+    }
+}
+
+/// Content of a toast seen on the latest draw, and the time its blip started.
+#[derive(Clone, Copy)]
+struct BlipState {
+    content: u64,
+    started_at: Option<f64>,
+}
+
+fn state_id(toast_id: ToastId) -> Id {
+    toast_id.area_id().with("blip")
+}
+
+fn content_hash(toast: &Toast) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    toast.text.text().hash(&mut hasher);
+    toast.kind.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Take the current content of the toast as seen, so that the change does not blip, see
+/// [`ToastHandle::set_text_quiet`](crate::ToastHandle::set_text_quiet).
+pub(crate) fn acknowledge(ctx: &Context, toast: &Toast) {
+    let Some(toast_id) = toast.id else {
+        return;
+    };
+    let content = content_hash(toast);
+    ctx.data_mut(|d| {
+        let state = d.get_temp_mut_or_insert_with(state_id(toast_id), || BlipState {
+            content,
+            started_at: None,
+        });
+        state.content = content;
+    });
+}
+
+/// Progress of the blip of the toast at `time`, from 0 to 1 and back, starting a blip if its
+/// content changed since the latest draw. `None` while no blip is running, and `Some(0.0)`
+/// on the frame the blip ends.
+pub(crate) fn progress(ctx: &Context, toast: &Toast, time: f64, blip: &BlipStyle) -> Option<f32> {
+    let toast_id = toast.id?;
+    let content = content_hash(toast);
+    let id = state_id(toast_id);
+    let mut state = ctx.data(|d| d.get_temp(id)).unwrap_or(BlipState {
+        content,
+        started_at: None,
+    });
+    if state.content != content {
+        state.content = content;
+        state.started_at = Some(time);
+    }
+    let progress = state.started_at.map(|started_at| {
+        let t = (time - started_at) / blip.duration.as_secs_f64().max(f64::EPSILON);
+        if t >= 1.0 {
+            state.started_at = None;
+            0.0
+        } else {
+            ctx.request_repaint();
+            (t.clamp(0.0, 1.0) * std::f64::consts::PI).sin() as f32
+        }
+    });
+    ctx.data_mut(|d| d.insert_temp(id, state));
+    progress
+}
+
+/// Scale and flash the area of the toast for the `progress` of its blip.
+pub(crate) fn paint(
+    response: &Response,
+    rounding: Rounding,
+    accent: Color32,
+    blip: &BlipStyle,
+    progress: f32,
+    motion: Motion,
+) {
+    let (ctx, layer_id, rect) = (&response.ctx, response.layer_id, response.rect);
+    let scale = motion.blip_scale(blip.scale, progress);
+    let transform = TSTransform::new(rect.center().to_vec2() * (1.0 - scale), scale);
+    ctx.set_transform_layer(layer_id, transform);
+    if blip.flash && progress > 0.0 {
+        let fill = accent.gamma_multiply(0.25 * progress);
+        ctx.layer_painter(layer_id)
+            .rect_filled(rect, rounding, fill);
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+
+use egui::{Context, Id};
+
+use crate::handle::Command;
+use crate::state::{self, StoredToast};
+use crate::{ToastHandle, ToastId};
+
+/// The toast a copy in another lane was made of, see
+/// [`ToastOptions::broadcast`](crate::ToastOptions::broadcast).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct Source {
+    /// Lane the toast is stored in.
+    pub(crate) lane: Id,
+    pub(crate) id: ToastId,
+}
+
+/// Broadcast toasts published to a lane, by the lanes they are stored in.
+type Published = HashMap<Id, Vec<StoredToast>>;
+
+fn published_id(lane: Id) -> Id {
+    lane.with("broadcast")
+}
+
+/// Lanes whose copies of the toast are hovered.
+fn hovered_id(toast_id: ToastId) -> Id {
+    toast_id.area_id().with("broadcast_hovered")
+}
+
+/// Publish the broadcast toasts stored in `lane` to the `targets`, for their next update.
+pub(crate) fn publish(ctx: &Context, lane: Id, targets: &[Id], toasts: &[StoredToast]) {
+    let broadcast: Vec<StoredToast> = toasts
+        .iter()
+        .filter(|stored| stored.toast.options.broadcast && stored.mirror.is_none())
+        .cloned()
+        .collect();
+    for &target in targets.iter().filter(|&&target| target != lane) {
+        if broadcast.is_empty() {
+            unpublish(ctx, target, |source_lane, _| source_lane == lane);
+        } else {
+            ctx.data_mut(|d| {
+                d.get_temp_mut_or_default::<Published>(published_id(target))
+                    .insert(lane, broadcast.clone());
+            });
+            state::set_active(ctx, target, true);
+        }
+    }
+}
+
+/// Remove the toasts published to `target` for which `remove` returns true.
+fn unpublish(ctx: &Context, target: Id, remove: impl Fn(Id, &StoredToast) -> bool) {
+    let id = published_id(target);
+    ctx.data_mut(|d| {
+        let Some(mut published) = d.get_temp::<Published>(id) else {
+            return;
+        };
+        for (&lane, sources) in &mut published {
+            sources.retain(|src| !remove(lane, src));
+        }
+        published.retain(|_, sources| !sources.is_empty());
+        if published.is_empty() {
+            d.remove::<Published>(id);
+        } else {
+            d.insert_temp(id, published);
+        }
+    });
+}
+
+/// Bring the copies in `lane` up to date with the toasts published to it: copy the new
+/// toasts, follow the changes of the copied toasts, and remove the copies of the toasts that
+/// are gone. The copies that were dismissed in this lane are kept for [`forward_dismissed`].
+pub(crate) fn sync(ctx: &Context, lane: Id, toasts: &mut Vec<StoredToast>) {
+    let published: Published = ctx.data(|d| d.get_temp(published_id(lane)).unwrap_or_default());
+    let source_of = |source: Source| {
+        published
+            .get(&source.lane)
+            .and_then(|sources| sources.iter().find(|src| src.toast.id == Some(source.id)))
+    };
+    toasts.retain_mut(|stored| {
+        let Some(source) = stored.mirror else {
+            return true;
+        };
+        let Some(src) = source_of(source) else {
+            set_hovered(ctx, source.id, lane, false);
+            return false;
+        };
+        if !stored.toast.options.is_expired() {
+            let id = stored.toast.id;
+            stored.toast = src.toast.clone();
+            stored.toast.id = id;
+            stored.toast.on_dismiss = None;
+            (stored.paused, stored.count) = (src.paused, src.count);
+        }
+        true
+    });
+    for (&source_lane, sources) in &published {
+        for src in sources {
+            let Some(source_id) = src.toast.id else {
+                continue;
+            };
+            let source = Source {
+                lane: source_lane,
+                id: source_id,
+            };
+            if toasts.iter().any(|stored| stored.mirror == Some(source)) {
+                continue;
+            }
+            let mut toast = src.toast.clone();
+            toast.id = Some(ToastId::new_unique());
+            toast.on_dismiss = None;
+            let mut copy = StoredToast::new(toast);
+            (copy.paused, copy.count) = (src.paused, src.count);
+            // The sound and the show hook are for the toast, not for each copy
+            copy.announced = true;
+            copy.mirror = Some(source);
+            toasts.push(copy);
+        }
+    }
+}
+
+/// Remove the copies that were dismissed in `lane`, and dismiss the toasts they were made of
+/// for the same reason.
+pub(crate) fn forward_dismissed(ctx: &Context, lane: Id, toasts: &mut Vec<StoredToast>) {
+    toasts.retain(|stored| {
+        let Some(source) = stored.mirror else {
+            return true;
+        };
+        // The copies don't count down, so they only expire when they are dismissed
+        let Some(reason) = stored.toast.options.dismissed else {
+            return true;
+        };
+        let command = Command::Dismiss { reason, from: lane };
+        ToastHandle::new(source.lane, source.id).send(ctx, command);
+        // Not copied again before the toast is removed from its lane
+        unpublish(ctx, lane, |source_lane, src| {
+            source_lane == source.lane && src.toast.id == Some(source.id)
+        });
+        set_hovered(ctx, source.id, lane, false);
+        false
+    });
+}
+
+/// Record whether the copy of the `source` toast drawn in `lane` is hovered, so that the
+/// hover pauses the toast everywhere. Does nothing for toasts that are not copies.
+pub(crate) fn report_hover(ctx: &Context, source: Option<Source>, lane: Id, hovered: bool) {
+    if let Some(source) = source {
+        set_hovered(ctx, source.id, lane, hovered);
+    }
+}
+
+fn set_hovered(ctx: &Context, toast_id: ToastId, lane: Id, hovered: bool) {
+    let id = hovered_id(toast_id);
+    ctx.data_mut(|d| {
+        let lanes = d.get_temp_mut_or_default::<Vec<Id>>(id);
+        lanes.retain(|&other| other != lane);
+        if hovered {
+            lanes.push(lane);
+        } else if lanes.is_empty() {
+            d.remove::<Vec<Id>>(id);
+        }
+    });
+}
+
+/// Whether a copy of the toast is hovered in another lane.
+pub(crate) fn is_hovered_elsewhere(ctx: &Context, toast_id: ToastId) -> bool {
+    ctx.data(|d| {
+        d.get_temp::<Vec<Id>>(hovered_id(toast_id))
+            .is_some_and(|lanes| !lanes.is_empty())
+    })
+}
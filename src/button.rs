@@ -0,0 +1,96 @@
+use std::fmt;
+use std::sync::Arc;
+
+use egui::{Button, Color32, Response, Stroke, Ui, WidgetText};
+
+use crate::Toast;
+
+pub type ToastButtonCallback = dyn Fn(&mut Toast) + Send + Sync;
+
+/// An action button shown in a toast, such as "Undo".
+///
+/// Clicking the button calls its callback and closes the toast.
+/// ```
+/// # use egui_toast::{Toast, ToastButton};
+/// let toast = Toast::new()
+///     .text("Message deleted")
+///     .button(ToastButton::new("Undo").primary(true).on_click(|_toast| {
+///         // Restore the message
+///     }))
+///     .button(ToastButton::new("Dismiss"));
+/// ```
+#[derive(Clone)]
+pub struct ToastButton {
+    pub text: WidgetText,
+    /// Primary buttons are drawn filled with an accent color, and are activated with
+    /// Enter when the toast is [focused](crate::Toasts::focusable).
+    pub primary: bool,
+    pub on_click: Option<Arc<ToastButtonCallback>>,
+}
+
+impl fmt::Debug for ToastButton {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToastButton")
+            .field("text", &self.text.text())
+            .field("primary", &self.primary)
+            .field("on_click", &self.on_click.is_some())
+            .finish()
+    }
+}
+
+impl ToastButton {
+    pub fn new(text: impl Into<WidgetText>) -> Self {
+        Self {
+            text: text.into(),
+            primary: false,
+            on_click: None,
+        }
+    }
+
+    #[must_use]
+    pub fn primary(mut self, primary: bool) -> Self {
+        self.primary = primary;
+        self
+    }
+
+    /// Called when the button is clicked, before the toast is closed.
+    #[must_use]
+    pub fn on_click(mut self, on_click: impl Fn(&mut Toast) + Send + Sync + 'static) -> Self {
+        self.on_click = Some(Arc::new(on_click));
+        self
+    }
+}
+
+/// Look of the action buttons. Unset fields fall back to the egui visuals.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct ToastButtonStyle {
+    pub fill: Option<Color32>,
+    pub stroke: Option<Stroke>,
+    pub text_color: Option<Color32>,
+}
+
+pub(crate) fn action_button(
+    ui: &mut Ui,
+    button: &ToastButton,
+    style: &ToastButtonStyle,
+) -> Response {
+    let visuals = ui.visuals();
+    let text_color = style.text_color.unwrap_or(if button.primary {
+        visuals.selection.stroke.color
+    } else {
+        visuals.text_color()
+    });
+
+    let mut widget = Button::new(button.text.clone().color(text_color));
+    if let Some(stroke) = style.stroke {
+        widget = widget.stroke(stroke);
+    }
+    widget = match style.fill {
+        Some(fill) => widget.fill(fill),
+        None if button.primary => widget.fill(visuals.selection.bg_fill),
+        // Secondary buttons are flat by default
+        None => widget.frame(false),
+    };
+
+    ui.add(widget)
+}
@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use egui::{Context, Id};
+
+use crate::state::StoredToast;
+use crate::{Toast, ToastHandle, ToastId, ToastKind};
+
+/// What happens to a new toast when [`Toasts::capacity`](crate::Toasts::capacity) is reached.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum CapacityPolicy {
+    /// The new toast is dropped and dismissed with
+    /// [`DismissReason::Dropped`](crate::DismissReason::Dropped).
+    #[default]
+    DropNewest,
+    /// The oldest toast is dismissed with [`DismissReason::Evicted`](crate::DismissReason::Evicted)
+    /// to make room for the new toast.
+    DropOldest,
+}
+
+/// Result of [`Toasts::try_add`](crate::Toasts::try_add).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AddResult {
+    /// The toast was added.
+    Added(ToastHandle),
+    /// The toast was dropped, because the [capacity](crate::Toasts::capacity) was reached.
+    Dropped,
+    /// The toast was not added, because a toast with the [same id](crate::Toast::with_id)
+    /// is already shown or about to be. Holds the handle of that toast.
+    DuplicateId(ToastHandle),
+}
+
+impl AddResult {
+    /// Handle of the added toast, or `None` if the toast was dropped.
+    pub fn handle(self) -> Option<ToastHandle> {
+        match self {
+            Self::Added(handle) => Some(handle),
+            Self::Dropped | Self::DuplicateId(_) => None,
+        }
+    }
+}
+
+/// The most toasts kept at once, whatever the [capacity](crate::Toasts::capacity). This keeps
+/// the frame rate and the memory in check when something adds thousands of toasts by mistake.
+pub(crate) const MAX_LIVE_TOASTS: usize = 200;
+
+/// How long the toast reporting the dropped toasts is shown.
+const REPORT_DURATION: Duration = Duration::from_secs(5);
+
+/// Toasts dropped because the capacity was reached, stored in the egui context's memory.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct DroppedToasts {
+    /// Toasts dropped by [`Toasts::try_add`](crate::Toasts::try_add) since the last frame.
+    pub(crate) pending: usize,
+    /// Number of dropped toasts shown by the report toast.
+    reported: usize,
+    /// The toast reporting the dropped toasts, if any.
+    report: Option<ToastId>,
+}
+
+impl DroppedToasts {
+    fn id(toasts_id: Id) -> Id {
+        toasts_id.with("dropped")
+    }
+
+    pub(crate) fn load(ctx: &Context, toasts_id: Id) -> Self {
+        ctx.data(|d| d.get_temp(Self::id(toasts_id)).unwrap_or_default())
+    }
+
+    pub(crate) fn store(self, ctx: &Context, toasts_id: Id) {
+        ctx.data_mut(|d| d.insert_temp(Self::id(toasts_id), self));
+    }
+
+    /// Whether the toast is the report of the dropped toasts, which does not count
+    /// towards the capacity.
+    pub(crate) fn is_report(&self, toast: &Toast) -> bool {
+        self.report.is_some() && toast.id == self.report
+    }
+
+    /// Show the number of dropped toasts in a single toast, which is updated when
+    /// more toasts are dropped while it is visible.
+    pub(crate) fn report(&mut self, toasts: &mut Vec<StoredToast>, dropped: usize, text: &str) {
+        let index = toasts
+            .iter()
+            .position(|stored| self.is_report(&stored.toast) && !stored.toast.options.is_expired())
+            .unwrap_or_else(|| {
+                self.reported = 0;
+                let mut toast = Toast::new().kind(ToastKind::Warning);
+                toast.id = Some(ToastId::next());
+                toasts.push(StoredToast::new(toast));
+                toasts.len() - 1
+            });
+        let report = &mut toasts[index].toast;
+        self.reported += dropped;
+        self.report = report.id;
+        report.text = text.replace("{count}", &self.reported.to_string()).into();
+        // Keep the report visible while toasts are being dropped
+        report.options = report.options.duration(REPORT_DURATION);
+    }
+}
+
+/// Number of toasts that count towards the capacity.
+pub(crate) fn live_count(toasts: &[StoredToast], dropped: &DroppedToasts) -> usize {
+    toasts
+        .iter()
+        .filter(|stored| is_live(stored) && !dropped.is_report(&stored.toast))
+        .count()
+}
+
+pub(crate) fn is_live(stored: &StoredToast) -> bool {
+    !stored.toast.options.is_expired() && stored.superseded_at.is_none()
+}
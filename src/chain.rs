@@ -0,0 +1,141 @@
+use egui::{Context, Id};
+
+use crate::state::StoredToast;
+use crate::{DismissReason, Toast, ToastHandle, ToastId, ToastQueue};
+
+/// Handle to a sequence of toasts added with [`Toasts::add_sequence`](crate::Toasts::add_sequence),
+/// used to stop the sequence or jump to another toast of it.
+///
+/// Like a [`ToastHandle`], the handle can be kept freely. The sequence is stored in the egui
+/// context's memory while it runs, starting from the first call to
+/// [`Toasts::show`](crate::Toasts::show) after it was added.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SequenceHandle {
+    toasts_id: Id,
+    id: ToastId,
+}
+
+/// A sequence of toasts shown one after another.
+#[derive(Clone)]
+pub(crate) struct Sequence {
+    /// Id of the first toast, which identifies the sequence.
+    id: ToastId,
+    toasts: Vec<Toast>,
+    /// Index and id of the toast that is shown.
+    current: (usize, ToastId),
+    /// Set with [`SequenceHandle::skip_to`].
+    skip_to: Option<usize>,
+}
+
+impl Sequence {
+    /// A sequence whose first toast was added as `first`.
+    pub(crate) fn new(toasts: Vec<Toast>, first: ToastId) -> Self {
+        Self {
+            id: first,
+            toasts,
+            current: (0, first),
+            skip_to: None,
+        }
+    }
+}
+
+fn sequences_id(toasts_id: Id) -> Id {
+    toasts_id.with("sequences")
+}
+
+impl SequenceHandle {
+    pub(crate) fn new(toasts_id: Id, id: ToastId) -> Self {
+        Self { toasts_id, id }
+    }
+
+    /// Handle to the toast of the sequence that is shown, or `None` if the sequence has
+    /// ended or was cancelled.
+    pub fn current(self, ctx: &Context) -> Option<ToastHandle> {
+        self.with_sequence(ctx, |sequence| sequence.current.1)
+            .map(|id| ToastHandle::new(self.toasts_id, id))
+    }
+
+    /// Index of the toast of the sequence that is shown, or `None` if the sequence has
+    /// ended or was cancelled.
+    pub fn current_index(self, ctx: &Context) -> Option<usize> {
+        self.with_sequence(ctx, |sequence| sequence.current.0)
+    }
+
+    /// Don't show the rest of the sequence. The toast that is shown stays until it is
+    /// dismissed as usual.
+    pub fn cancel(self, ctx: &Context) {
+        ctx.data_mut(|d| {
+            d.get_temp_mut_or_default::<Vec<Sequence>>(sequences_id(self.toasts_id))
+                .retain(|sequence| sequence.id != self.id);
+        });
+    }
+
+    /// Close the toast that is shown and continue the sequence from the toast at `index`,
+    /// which can also be an earlier toast. The sequence ends if there is no toast at `index`.
+    pub fn skip_to(self, ctx: &Context, index: usize) {
+        self.with_sequence(ctx, |sequence| sequence.skip_to = Some(index));
+    }
+
+    fn with_sequence<R>(self, ctx: &Context, f: impl FnOnce(&mut Sequence) -> R) -> Option<R> {
+        ctx.data_mut(|d| {
+            d.get_temp_mut_or_default::<Vec<Sequence>>(sequences_id(self.toasts_id))
+                .iter_mut()
+                .find(|sequence| sequence.id == self.id)
+                .map(f)
+        })
+    }
+}
+
+/// Queue the next toast of the sequences whose toast went away. This runs before the
+/// dismissed toasts are removed, so that the reason of the dismissal is known.
+///
+/// With `advance_on_dismiss` unset, a sequence whose toast was dismissed before it
+/// expired ends there.
+pub(crate) fn advance(
+    ctx: &Context,
+    toasts_id: Id,
+    added: Vec<Sequence>,
+    toasts: &mut [StoredToast],
+    advance_on_dismiss: bool,
+) {
+    let id = sequences_id(toasts_id);
+    let mut sequences: Vec<Sequence> = ctx.data_mut(|d| d.remove_temp(id)).unwrap_or_default();
+    sequences.extend(added);
+    if sequences.is_empty() {
+        return;
+    }
+    let queue = ToastQueue::new(toasts_id);
+    sequences.retain_mut(|sequence| {
+        let (index, current) = sequence.current;
+        // A toast that is not stored was dropped or rejected when it was added
+        if let Some(stored) = toasts
+            .iter_mut()
+            .find(|stored| stored.toast.id == Some(current))
+        {
+            if sequence.skip_to.is_some() {
+                stored.toast.close();
+            }
+            let options = stored.toast.options;
+            if !options.is_expired() {
+                return true;
+            }
+            let dismissed = options
+                .dismissed
+                .is_some_and(|reason| reason != DismissReason::Expired);
+            if dismissed && !advance_on_dismiss && sequence.skip_to.is_none() {
+                return false;
+            }
+        }
+        let next = sequence.skip_to.take().unwrap_or(index + 1);
+        let Some(mut toast) = sequence.toasts.get(next).cloned() else {
+            return false;
+        };
+        // A toast shown again gets a new id
+        toast.id = None;
+        sequence.current = (next, queue.add(ctx, toast).id());
+        true
+    });
+    if !sequences.is_empty() {
+        ctx.data_mut(|d| d.insert_temp(id, sequences));
+    }
+}
@@ -0,0 +1,58 @@
+use egui::{Context, Id, Margin, Response, Rounding, Sense, Ui, Vec2};
+
+use crate::state::StoredToast;
+use crate::{DismissReason, ToastStyle};
+
+/// Whether the toast is cleared by the [clear all button](crate::Toasts::clear_all_button)
+/// or by [clicking outside](crate::Toasts::dismiss_on_click_outside) the toasts.
+/// Toasts that never expire are kept, since they usually need the attention of the user.
+fn is_clearable(stored: &StoredToast) -> bool {
+    let options = stored.toast.options;
+    !options.is_expired()
+        && options.deferred.is_none()
+        && stored.superseded_at.is_none()
+        && options.initial_duration().is_some()
+}
+
+/// Number of toasts cleared by the button.
+pub(crate) fn clearable_count(toasts: &[StoredToast]) -> usize {
+    toasts.iter().filter(|stored| is_clearable(stored)).count()
+}
+
+/// Dismiss the clearable toasts. They are removed on the next frame, or once they were
+/// shown for their [minimum display time](crate::ToastOptions::min_display).
+pub(crate) fn clear(toasts: &mut [StoredToast], reason: DismissReason) {
+    for stored in toasts.iter_mut().filter(|stored| is_clearable(stored)) {
+        stored.toast.options.dismiss_in_bulk(reason);
+    }
+}
+
+/// Size of the button measured on the previous frame, so that it is laid out like the toasts.
+pub(crate) fn load_size(ctx: &Context, button_id: Id) -> Option<Vec2> {
+    ctx.data(|d| d.get_temp(button_id))
+}
+
+pub(crate) fn store_size(ctx: &Context, button_id: Id, size: Option<Vec2>) {
+    ctx.data_mut(|d| match size {
+        Some(size) => d.insert_temp(button_id, size),
+        None => d.remove::<Vec2>(button_id),
+    });
+}
+
+/// Draw the button as a small pill with the frame and text color of the toasts.
+pub(crate) fn pill(ui: &mut Ui, label: &str, style: &ToastStyle) -> Response {
+    let margin = style.inner_margin;
+    let frame = style
+        .frame(ui.style())
+        .inner_margin(Margin::symmetric(margin, margin / 2.0))
+        // The rounding is limited to half the height, which rounds the ends fully
+        .rounding(Rounding::same(f32::INFINITY))
+        .stroke(style.frame_stroke(ui.visuals()));
+    let response = frame
+        .show(ui, |ui| {
+            style.apply_text_color(ui);
+            ui.label(label)
+        })
+        .response;
+    ui.interact(response.rect, response.id.with("clear_all"), Sense::click())
+}
@@ -0,0 +1,51 @@
+use std::f64::consts::TAU;
+
+use egui::{Context, Id};
+
+use crate::Toast;
+
+pub type ToastCloseCallback = dyn Fn(&mut Toast) -> CloseDecision + Send + Sync;
+
+/// Whether a toast is closed when the user asks to close it, see [`Toast::on_close_requested`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CloseDecision {
+    /// Close the toast.
+    Allow,
+    /// Keep the toast. If `shake` is set, the toast is shaken to point out that it
+    /// can't be closed yet.
+    Deny { shake: bool },
+}
+
+/// How long the toast is shaken, in seconds.
+const SHAKE_DURATION: f64 = 0.4;
+/// Number of back and forth movements during the shake.
+const SHAKE_CYCLES: f64 = 3.0;
+/// How far the toast moves sideways, in points.
+const SHAKE_AMPLITUDE: f32 = 6.0;
+
+fn shake_id(area_id: Id) -> Id {
+    area_id.with("shake")
+}
+
+/// Start shaking the toast drawn in the area.
+pub(crate) fn start_shake(ctx: &Context, area_id: Id) {
+    let time = ctx.input(|i| i.time);
+    ctx.data_mut(|d| d.insert_temp(shake_id(area_id), time));
+    ctx.request_repaint();
+}
+
+/// Horizontal offset of a toast that is shaking, or zero if it is not.
+pub(crate) fn shake_offset(ctx: &Context, area_id: Id) -> f32 {
+    let Some(started_at) = ctx.data(|d| d.get_temp::<f64>(shake_id(area_id))) else {
+        return 0.0;
+    };
+    let elapsed = ctx.input(|i| i.time) - started_at;
+    if !(0.0..SHAKE_DURATION).contains(&elapsed) {
+        ctx.data_mut(|d| d.remove::<f64>(shake_id(area_id)));
+        return 0.0;
+    }
+    ctx.request_repaint();
+    let progress = elapsed / SHAKE_DURATION;
+    // Ease out, so that the toast settles back in place
+    ((progress * SHAKE_CYCLES * TAU).sin() * (1.0 - progress)) as f32 * SHAKE_AMPLITUDE
+}
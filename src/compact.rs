@@ -0,0 +1,143 @@
+use egui::{Align2, Context, Id, Rect, Response, Sense, TextStyle, TextWrapMode, Ui, Vec2};
+
+use crate::{scale, Toast, ToastId, ToastStyle, LAYOUT_TRANSITION_SECS};
+
+/// Diameter of a compact toast at the scale of 1.
+const DIAMETER: f32 = 32.0;
+
+/// Time the pointer has to rest over the stack before it expands, so that a compact toast
+/// can be clicked on its own.
+const EXPAND_DELAY_SECS: f64 = 0.3;
+
+/// State of a stack shown in [compact mode](crate::Toasts::compact_when_over).
+#[derive(Clone, Default)]
+pub(crate) struct CompactStack {
+    /// Whether the stack is expanded because the pointer is over it.
+    expanded: bool,
+    /// Time the stack was last expanded or collapsed.
+    changed_at: Option<f64>,
+    /// Time the pointer moved over the stack.
+    hovered_since: Option<f64>,
+    /// Area covered by the stack on the previous frame.
+    rect: Option<Rect>,
+    /// Toasts that were clicked to show them in full.
+    opened: Vec<ToastId>,
+}
+
+impl CompactStack {
+    fn id(toasts_id: Id) -> Id {
+        toasts_id.with("compact")
+    }
+
+    /// Load the state and expand or collapse the stack for the pointer.
+    ///
+    /// The pointer is tested against the area of the stack on the previous frame, grown by
+    /// `margin`, and the stack expands once the pointer rests over it. The stack doesn't
+    /// change again until it has slid to its new layout, so that the rects moving under the
+    /// pointer don't make it flicker between the modes.
+    pub(crate) fn load(ctx: &Context, toasts_id: Id, time: f64, margin: f32) -> Self {
+        let mut stack: Self = ctx.data(|d| d.get_temp(Self::id(toasts_id)).unwrap_or_default());
+        let hovered = ctx
+            .input(|i| i.pointer.hover_pos())
+            .zip(stack.rect)
+            .is_some_and(|(pos, rect)| rect.expand(margin).contains(pos));
+        stack.hovered_since = hovered.then(|| stack.hovered_since.unwrap_or(time));
+        let rested = stack
+            .hovered_since
+            .map(|since| EXPAND_DELAY_SECS - (time - since))
+            .filter(|&left| left > 0.0);
+        if let Some(left) = rested {
+            ctx.request_repaint_after_secs(left as f32);
+        }
+        let expand = hovered && rested.is_none();
+        if expand != stack.expanded {
+            if stack.is_transitioning(time) {
+                ctx.request_repaint();
+            } else {
+                stack.expanded = expand;
+                stack.changed_at = Some(time);
+                ctx.request_repaint();
+            }
+        }
+        stack
+    }
+
+    /// Store the state with the area covered by the stack on this frame, remembering the
+    /// opened toasts that are still in `toasts`.
+    pub(crate) fn store(
+        mut self,
+        ctx: &Context,
+        toasts_id: Id,
+        occupied: &[Rect],
+        toasts: &[ToastId],
+    ) {
+        self.rect = occupied.iter().copied().reduce(Rect::union);
+        self.opened.retain(|id| toasts.contains(id));
+        ctx.data_mut(|d| d.insert_temp(Self::id(toasts_id), self));
+    }
+
+    pub(crate) fn clear(ctx: &Context, toasts_id: Id) {
+        ctx.data_mut(|d| d.remove::<Self>(Self::id(toasts_id)));
+    }
+
+    /// Whether the stack is still sliding to the layout of its mode.
+    pub(crate) fn is_transitioning(&self, time: f64) -> bool {
+        self.changed_at
+            .is_some_and(|changed_at| time - changed_at < f64::from(LAYOUT_TRANSITION_SECS))
+    }
+
+    pub(crate) fn is_compact(&self, toast_id: ToastId) -> bool {
+        !self.expanded && !self.opened.contains(&toast_id)
+    }
+
+    /// Show the toast in full until the stack is shown in full mode again.
+    pub(crate) fn open(&mut self, toast_id: ToastId) {
+        self.opened.push(toast_id);
+    }
+}
+
+/// Size of a compact toast.
+pub(crate) fn size(ctx: &Context, scale: f32) -> Vec2 {
+    Vec2::splat(scale::length(ctx, DIAMETER, scale))
+}
+
+/// Draw the toast as a circle with the icon of its kind and the number of merged toasts,
+/// surrounded by a ring that shows its progress.
+pub(crate) fn contents(ui: &mut Ui, toast: &Toast, style: &ToastStyle, count: usize) -> Response {
+    let ctx = ui.ctx().clone();
+    let toast_id = toast.id.expect("toasts are assigned an id when added");
+    let scale = toast.scale(&ctx);
+    let style = &style.scaled(&ctx, scale);
+    let (rect, response) = ui.allocate_exact_size(size(&ctx, scale), Sense::click());
+    let ring_width = scale::length(&ctx, 2.0, scale);
+    let circle = rect.shrink(ring_width);
+
+    let frame = style.frame(ui.style());
+    let stroke = style.frame_stroke(ui.visuals());
+    ui.painter()
+        .circle(circle.center(), circle.width() / 2.0, frame.fill, stroke);
+    let icon = style.icon(&ctx, toast.kind).into_galley(
+        ui,
+        Some(TextWrapMode::Extend),
+        f32::INFINITY,
+        TextStyle::Body,
+    );
+    let icon_pos = circle.center() - icon.size() / 2.0;
+    style.apply_text_color(ui);
+    let text_color = ui.visuals().text_color();
+    ui.painter().galley(icon_pos, icon, text_color);
+    if count > 1 {
+        let font_id = TextStyle::Small.resolve(ui.style());
+        ui.painter().text(
+            rect.right_bottom(),
+            Align2::RIGHT_BOTTOM,
+            count.to_string(),
+            font_id,
+            text_color,
+        );
+    }
+    if crate::shows_progress(toast) {
+        crate::progress_arc(ui, circle.shrink(ring_width / 2.0), toast_id, toast, style);
+    }
+    response
+}
@@ -0,0 +1,90 @@
+use egui::Context;
+
+use crate::{context_ext, state, Toast, ToastHandle, ToastQueue, Toasts};
+
+/// Settings of a [`Toasts`] instance that are built once and kept in the state of the app,
+/// instead of building a new instance with its style and custom contents on every frame.
+///
+/// [`Self::show`] only borrows the settings, so a frame without toasts doesn't allocate.
+/// The toasts are added through [`Self::add`], or through the [queue](Toasts::queue) and
+/// the context queue like with a [`Toasts`] instance.
+///
+/// ```
+/// # use egui_toast::{Toast, Toasts, ToastsConfig};
+/// struct App {
+///     toasts: ToastsConfig,
+/// }
+///
+/// let mut app = App {
+///     toasts: Toasts::new()
+///         .anchor(egui::Align2::RIGHT_BOTTOM, (-10.0, -10.0))
+///         .custom_contents(0, |ui, toast| ui.label(toast.text.clone()))
+///         .into_config(),
+/// };
+/// let ctx = egui::Context::default();
+/// let handle = app.toasts.add(&ctx, Toast::new().text("Saved"));
+/// for _ in 0..2 {
+///     let _ = ctx.run(Default::default(), |ctx| app.toasts.show(ctx));
+/// }
+/// assert!(ctx.read_response(handle.id().text_id()).is_some());
+///
+/// // The settings can be changed between frames
+/// app.toasts.modify(|toasts| toasts.spacing(4.0));
+/// ```
+pub struct ToastsConfig {
+    toasts: Toasts,
+}
+
+impl ToastsConfig {
+    /// Keep the settings of `toasts`. Toasts added to the instance are dropped, add them
+    /// with [`Self::add`] instead.
+    pub fn new(mut toasts: Toasts) -> Self {
+        if !toasts.added_toasts.is_empty() || !toasts.added_sequences.is_empty() {
+            log::warn!(
+                "Toasts {:?} were added before the instance was turned into a ToastsConfig, \
+                 so they are not shown. Add them with `ToastsConfig::add`.",
+                toasts.id
+            );
+            toasts.added_toasts.clear();
+            toasts.added_sequences.clear();
+        }
+        Self { toasts }
+    }
+
+    /// The settings, e.g. for [`Toasts::occupied_rects`].
+    pub fn toasts(&self) -> &Toasts {
+        &self.toasts
+    }
+
+    /// Change the settings with the builder methods or the setters of [`Toasts`]. As with
+    /// [`Self::new`], toasts added to the instance are dropped.
+    pub fn modify(&mut self, modify: impl FnOnce(Toasts) -> Toasts) {
+        let toasts = std::mem::take(&mut self.toasts);
+        *self = Self::new(modify(toasts));
+    }
+
+    /// Add a toast on the next call to [`Self::show`].
+    pub fn add(&self, ctx: &Context, toast: Toast) -> ToastHandle {
+        ToastQueue::new(self.toasts.id).add(ctx, toast)
+    }
+
+    /// Same as [`Toasts::show`], with the settings only borrowed.
+    pub fn show(&self, ctx: &Context) {
+        let toasts = &self.toasts;
+        let mut added = Vec::new();
+        if toasts.consume_context_queue {
+            added = context_ext::take(ctx, toasts.id);
+        }
+        if added.is_empty() && !state::is_active(ctx, toasts.id) {
+            return;
+        }
+        toasts.update_with(ctx, None, added, Vec::new());
+        toasts.draw_toasts(ctx);
+    }
+}
+
+impl From<Toasts> for ToastsConfig {
+    fn from(toasts: Toasts) -> Self {
+        Self::new(toasts)
+    }
+}
@@ -0,0 +1,104 @@
+use egui::{Context, Id};
+
+use crate::Toast;
+
+/// Id of the queue shared by all [`Toasts`](crate::Toasts) instances of a context.
+fn queue_id() -> Id {
+    Id::new("__egui_toast_context_queue")
+}
+
+/// Add toasts from anywhere the egui context is at hand, without a [`Toasts`](crate::Toasts)
+/// instance. Needs the `context-ext` feature.
+///
+/// The toasts are stored in a queue in the egui context's memory, and shown by the
+/// instance that [consumes the queue](crate::Toasts::consume_context_queue). The queue is
+/// meant to have a single consumer: a warning is logged if two instances take from it on the
+/// same frame, and each toast is only shown by the instance that took it first.
+///
+/// ```
+/// # use egui_toast::testing::ToastTestCtx;
+/// # use egui_toast::{ToastKind, Toasts};
+/// use egui_toast::ToastsExt as _;
+///
+/// let mut test = ToastTestCtx::new(Toasts::new().consume_context_queue(true));
+/// test.ctx().toast_error("Could not open the file");
+/// test.run_frames(2);
+/// let [toast] = test.stored_toasts().try_into().unwrap();
+/// assert_eq!(toast.kind, ToastKind::Error);
+/// ```
+#[cfg(feature = "context-ext")]
+pub trait ToastsExt {
+    /// Add a toast on the next call to [`Toasts::show`](crate::Toasts::show) of the consuming
+    /// instance. The toast gets its id right away, so it is stacked in the order it was added
+    /// in, also among the toasts added directly to the instance.
+    ///
+    /// ```
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, Toasts};
+    /// use egui_toast::ToastsExt as _;
+    ///
+    /// let mut test = ToastTestCtx::new(Toasts::new().consume_context_queue(true));
+    /// let ctx = test.ctx().clone();
+    /// test.toasts().set_group_expanded(&ctx, "uploads", true);
+    /// ctx.toast(Toast::new().text("report.pdf uploaded").group("uploads"));
+    /// test.run_frames(3);
+    /// let [toast] = test.stored_toasts().try_into().unwrap();
+    /// assert!(toast.id().is_some());
+    /// assert!(ctx.read_response(toast.id().unwrap().text_id()).is_some());
+    /// ```
+    fn toast(&self, toast: Toast);
+
+    fn toast_info(&self, text: impl Into<egui::WidgetText>) {
+        self.toast(Toast::new().kind(crate::ToastKind::Info).text(text));
+    }
+
+    fn toast_warning(&self, text: impl Into<egui::WidgetText>) {
+        self.toast(Toast::new().kind(crate::ToastKind::Warning).text(text));
+    }
+
+    fn toast_error(&self, text: impl Into<egui::WidgetText>) {
+        self.toast(Toast::new().kind(crate::ToastKind::Error).text(text));
+    }
+
+    fn toast_success(&self, text: impl Into<egui::WidgetText>) {
+        self.toast(Toast::new().kind(crate::ToastKind::Success).text(text));
+    }
+}
+
+#[cfg(feature = "context-ext")]
+impl ToastsExt for Context {
+    fn toast(&self, mut toast: Toast) {
+        toast.id.get_or_insert_with(crate::ToastId::next);
+        toast.assign_sequence();
+        self.data_mut(|d| {
+            d.get_temp_mut_or_default::<Vec<Toast>>(queue_id())
+                .push(toast)
+        });
+        self.request_repaint();
+    }
+}
+
+/// Take the toasts of the context queue for the instance with `toasts_id`. Each pass
+/// records the instance that took the toasts, so that a second consumer is noticed.
+pub(crate) fn take(ctx: &Context, toasts_id: Id) -> Vec<Toast> {
+    let pass_nr = ctx.cumulative_pass_nr();
+    let consumer_id = queue_id().with("consumer");
+    let (toasts, previous) = ctx.data_mut(|d| {
+        let previous = d.get_temp::<(u64, Id)>(consumer_id);
+        d.insert_temp(consumer_id, (pass_nr, toasts_id));
+        let toasts: Option<Vec<Toast>> = d.remove_temp(queue_id());
+        (toasts.unwrap_or_default(), previous)
+    });
+    if previous.is_some_and(|(previous_pass, previous_id)| {
+        previous_pass == pass_nr && previous_id != toasts_id
+    }) {
+        let warned_id = queue_id().with("warned");
+        if !ctx.data_mut(|d| std::mem::replace(d.get_temp_mut_or_default(warned_id), true)) {
+            log::warn!(
+                "The context queue of the toasts is consumed by several Toasts instances. \
+                Only one of them should use Toasts::consume_context_queue."
+            );
+        }
+    }
+    toasts
+}
@@ -0,0 +1,75 @@
+use egui::menu::{BarState, MenuResponse, MenuRoot};
+use egui::{Id, PointerButton, Rect, Response, Ui};
+
+use crate::{Toast, ToastId, ToastKind, ToastStrings};
+
+pub type ToastMenuExtra = dyn Fn(&mut Ui, &mut Toast) + Send + Sync;
+
+/// Id of the flag that opens the [details](Toast::details) of the toast on the next frame.
+pub(crate) fn open_details_id(toast_id: ToastId) -> Id {
+    toast_id.area_id().with("open_details")
+}
+
+/// Id of the state of the context menus of the toasts.
+fn menu_id() -> Id {
+    Id::new("__egui_toast_context_menu")
+}
+
+/// Show the context menu of the toast when it is right-clicked. The entries act on the toast
+/// right away, except for dismissing the toasts of its kind, which is returned to be done
+/// once all toasts are drawn. Also returns the rect of the open menu.
+///
+/// `Response::context_menu` only opens for a right-click on the area itself, but the
+/// selectable text takes the clicks over it, so the right-click is detected anywhere over
+/// the toast instead.
+pub(crate) fn show(
+    response: &Response,
+    toast: &mut Toast,
+    strings: &ToastStrings,
+) -> (Option<ToastKind>, Option<Rect>) {
+    let ctx = &response.ctx;
+    let mut menu = BarState::load(ctx, menu_id());
+    let mut menu_response = MenuRoot::context_interaction(response, &mut menu);
+    let secondary_clicked = ctx.input(|i| {
+        let pos = i.pointer.interact_pos();
+        let in_menu = menu
+            .as_ref()
+            .zip(pos)
+            .is_some_and(|(root, pos)| root.menu_state.read().area_contains(pos));
+        let clicked = i.pointer.button_clicked(PointerButton::Secondary) && !in_menu;
+        pos.filter(|_| clicked)
+    });
+    if let Some(pos) = secondary_clicked.filter(|_| response.contains_pointer()) {
+        menu_response = MenuResponse::Create(pos, response.id);
+    }
+    MenuRoot::handle_menu_response(&mut menu, menu_response);
+
+    let mut dismiss_kind = None;
+    let inner = menu.show(response, |ui| {
+        if ui.button(&strings.copy_text).clicked() {
+            ui.ctx().copy_text(toast.plain_text());
+            ui.close_menu();
+        }
+        if let (Some(_), Some(toast_id)) = (&toast.details, toast.id) {
+            if ui.button(&strings.open_details).clicked() {
+                ui.data_mut(|d| d.insert_temp(open_details_id(toast_id), true));
+                ui.close_menu();
+            }
+        }
+        ui.separator();
+        if ui.button(&strings.dismiss).clicked() {
+            toast.request_close(ui.ctx());
+            ui.close_menu();
+        }
+        if ui.button(&strings.dismiss_kind).clicked() {
+            dismiss_kind = Some(toast.kind);
+            ui.close_menu();
+        }
+        if let Some(extra) = toast.context_menu_extra.clone() {
+            ui.separator();
+            extra(ui, toast);
+        }
+    });
+    menu.store(ctx, menu_id());
+    (dismiss_kind, inner.map(|inner| inner.response.rect))
+}
@@ -0,0 +1,96 @@
+use egui::{Align, Color32, FontSelection, Rgba, Style, WidgetText};
+
+/// Relative luminance of the color, as defined by WCAG.
+fn luminance(color: Color32) -> f32 {
+    let rgba = Rgba::from(color);
+    0.2126 * rgba.r() + 0.7152 * rgba.g() + 0.0722 * rgba.b()
+}
+
+/// WCAG contrast ratio between two colors, from 1 (no contrast) to 21 (black on white).
+pub(crate) fn contrast_ratio(a: Color32, b: Color32) -> f32 {
+    let (a, b) = (luminance(a), luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// The color, darkened or lightened as little as possible to have at least `min_ratio`
+/// contrast against `background`. If even black or white is not enough, the one of them or
+/// `fallback` with the best contrast is used.
+pub(crate) fn adjust(
+    color: Color32,
+    background: Color32,
+    min_ratio: f32,
+    fallback: Color32,
+) -> Color32 {
+    if contrast_ratio(color, background) >= min_ratio {
+        return color;
+    }
+    let black = Color32::from_black_alpha(color.a());
+    let white = Color32::from_white_alpha(color.a());
+    let target = if contrast_ratio(black, background) > contrast_ratio(white, background) {
+        black
+    } else {
+        white
+    };
+    if contrast_ratio(target, background) < min_ratio {
+        return [target, fallback]
+            .into_iter()
+            .max_by(|a, b| {
+                contrast_ratio(*a, background).total_cmp(&contrast_ratio(*b, background))
+            })
+            .unwrap_or(target);
+    }
+    // The contrast grows steadily towards the target, so look for the smallest step that is
+    // enough, keeping as much of the hue as possible
+    let (mut low, mut high) = (0.0, 1.0);
+    for _ in 0..12 {
+        let mid = (low + high) / 2.0;
+        if contrast_ratio(mix(color, target, mid), background) >= min_ratio {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    mix(color, target, high)
+}
+
+fn mix(from: Color32, to: Color32, t: f32) -> Color32 {
+    let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    Color32::from_rgba_premultiplied(
+        channel(from.r(), to.r()),
+        channel(from.g(), to.g()),
+        channel(from.b(), to.b()),
+        channel(from.a(), to.a()),
+    )
+}
+
+/// Adjust the colors of the text that don't have enough contrast against `background`,
+/// section by section. Galleys are drawn as they are.
+///
+/// The sections without a color follow the text color of the visuals, which is expected to
+/// fit the fill, so they are kept.
+pub(crate) fn enforce(
+    text: WidgetText,
+    style: &Style,
+    background: Color32,
+    min_ratio: f32,
+) -> WidgetText {
+    let fallback = style.visuals.text_color();
+    let needs_adjusting = |color: Color32| {
+        color != Color32::PLACEHOLDER && contrast_ratio(color, background) < min_ratio
+    };
+    if let WidgetText::Galley(_) = text {
+        return text;
+    }
+    let mut job = text
+        .clone()
+        .into_layout_job(style, FontSelection::Default, Align::Center);
+    if !job.sections.iter().any(|s| needs_adjusting(s.format.color)) {
+        return text;
+    }
+    for section in &mut job.sections {
+        if needs_adjusting(section.format.color) {
+            section.format.color = adjust(section.format.color, background, min_ratio, fallback);
+        }
+    }
+    WidgetText::LayoutJob(job)
+}
@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use egui::{Context, Id};
+
+use crate::Toast;
+
+/// Recently added messages, see [`Toasts::dedupe_window`](crate::Toasts::dedupe_window).
+///
+/// Only a hash of each message is kept, with the time it was last added, so that long
+/// messages don't pile up in the egui context's memory.
+#[derive(Clone, Default)]
+pub(crate) struct RecentMessages(HashMap<u64, f64>);
+
+impl RecentMessages {
+    fn id(toasts_id: Id) -> Id {
+        toasts_id.with("recent_messages")
+    }
+
+    pub(crate) fn load(ctx: &Context, toasts_id: Id) -> Self {
+        ctx.data(|d| d.get_temp(Self::id(toasts_id)).unwrap_or_default())
+    }
+
+    pub(crate) fn store(self, ctx: &Context, toasts_id: Id) {
+        ctx.data_mut(|d| {
+            if self.0.is_empty() {
+                d.remove::<Self>(Self::id(toasts_id));
+            } else {
+                d.insert_temp(Self::id(toasts_id), self);
+            }
+        });
+    }
+
+    /// Forget the messages that were last added more than `window` seconds ago.
+    pub(crate) fn prune(&mut self, time: f64, window: f64) {
+        self.0.retain(|_, added_at| time - *added_at <= window);
+    }
+
+    /// Whether a toast with the same message as `toast` was added within the window.
+    pub(crate) fn contains(&self, toast: &Toast) -> bool {
+        self.0.contains_key(&key(toast))
+    }
+
+    pub(crate) fn insert(&mut self, toast: &Toast, time: f64) {
+        self.0.insert(key(toast), time);
+    }
+}
+
+/// Hash of the kind of the toast and the parts compared by [`Toast::has_same_message`].
+///
+/// [`Id`] hashes with fixed seeds, so the keys stay the same across frames and instances.
+fn key(toast: &Toast) -> u64 {
+    Id::new((toast.kind, &toast.group, toast.scope, toast.plain_text())).value()
+}
@@ -0,0 +1,157 @@
+use egui::{Align, Align2, Context, Direction, Id, LayerId, Order, Pos2, Rect, Response, Vec2};
+
+/// Stacks released closer than this to an edge of the screen snap to the edge.
+const SNAP_DISTANCE: f32 = 50.0;
+
+/// Distance of a snapped stack from the edge of the screen.
+const SNAP_MARGIN: f32 = 10.0;
+
+/// Anchor of a stack moved with [`Toasts::draggable`](crate::Toasts::draggable), stored in
+/// the egui context's persisted memory.
+pub(crate) type DraggedAnchor = (Align2, Vec2, Direction);
+
+/// Version of the persisted anchor. Bump it when the meaning of [`DraggedAnchor`] changes,
+/// so that the anchors stored by older versions of the crate are discarded instead of misread.
+const ANCHOR_VERSION: u32 = 1;
+
+/// The [`DraggedAnchor`] as it is persisted, after the version. A tuple of egui types, so
+/// that it can be serialized with the `persistence` feature of egui.
+type PersistedAnchor = (u32, Align2, Vec2, Direction);
+
+fn dragged_anchor_id(toasts_id: Id) -> Id {
+    toasts_id.with("dragged_anchor")
+}
+
+/// The anchor the stack was dragged to. Anchors stored by another version of the crate,
+/// including the unversioned anchors of the older versions, are removed.
+pub(crate) fn load_anchor(ctx: &Context, toasts_id: Id) -> Option<DraggedAnchor> {
+    let id = dragged_anchor_id(toasts_id);
+    ctx.data_mut(|d| {
+        if d.get_persisted::<DraggedAnchor>(id).is_some() {
+            log::debug!("Discarded the unversioned anchor of the toasts {toasts_id:?}");
+            d.remove::<DraggedAnchor>(id);
+        }
+        let (version, align, offset, direction) = d.get_persisted::<PersistedAnchor>(id)?;
+        if version == ANCHOR_VERSION {
+            return Some((align, offset, direction));
+        }
+        log::debug!("Discarded the anchor of the toasts {toasts_id:?} stored by version {version}");
+        d.remove::<PersistedAnchor>(id);
+        None
+    })
+}
+
+pub(crate) fn store_anchor(ctx: &Context, toasts_id: Id, anchor: DraggedAnchor) {
+    let (align, offset, direction) = anchor;
+    let persisted: PersistedAnchor = (ANCHOR_VERSION, align, offset, direction);
+    ctx.data_mut(|d| d.insert_persisted(dragged_anchor_id(toasts_id), persisted));
+}
+
+pub(crate) fn forget_anchor(ctx: &Context, toasts_id: Id) {
+    ctx.data_mut(|d| d.remove::<PersistedAnchor>(dragged_anchor_id(toasts_id)));
+}
+
+/// Drag of the whole stack of toasts, collected from the responses of the toasts.
+#[derive(Default)]
+pub(crate) struct StackDrag {
+    /// Rect of the toast closest to the anchor.
+    first_rect: Option<Rect>,
+    delta: Vec2,
+    dragged: bool,
+    released: bool,
+}
+
+impl StackDrag {
+    pub(crate) fn add(&mut self, response: &Response) {
+        self.first_rect.get_or_insert(response.rect);
+        self.delta += response.drag_delta();
+        self.dragged |= response.dragged();
+        self.released |= response.drag_stopped();
+    }
+
+    /// Update the drag offset, stored in the egui context's memory under `id`.
+    ///
+    /// While dragging, an outline of the position where the stack would end up is drawn.
+    /// Returns the new anchor within `screen_rect`, the rect the toasts are anchored in,
+    /// when the stack is released.
+    pub(crate) fn finish(
+        self,
+        ctx: &Context,
+        id: Id,
+        screen_rect: Rect,
+        align: Align2,
+        offset: Pos2,
+        direction: Direction,
+    ) -> Option<DraggedAnchor> {
+        let delta = ctx.data(|d| d.get_temp::<Vec2>(id).unwrap_or_default());
+        let first_rect = self.first_rect?;
+
+        if self.released {
+            ctx.data_mut(|d| d.remove::<Vec2>(id));
+            return Some(snap(
+                first_rect,
+                screen_rect,
+                align,
+                offset + delta,
+                direction,
+            ));
+        }
+
+        if self.dragged {
+            let delta = delta + self.delta;
+            ctx.data_mut(|d| d.insert_temp(id, delta));
+
+            let (align, offset, _) =
+                snap(first_rect, screen_rect, align, offset + delta, direction);
+            let target = Rect::from_min_size(
+                align
+                    .align_size_within_rect(first_rect.size(), screen_rect)
+                    .min
+                    + offset,
+                first_rect.size(),
+            );
+            let visuals = &ctx.style().visuals;
+            ctx.layer_painter(LayerId::new(Order::Foreground, id))
+                .rect_stroke(
+                    target,
+                    visuals.window_rounding,
+                    visuals.widgets.noninteractive.bg_stroke,
+                );
+        }
+
+        None
+    }
+}
+
+/// Snap a stack whose first toast is at `rect` to the nearest edges of the screen.
+///
+/// The stack is flipped if it would otherwise grow outside of the screen from the new edge.
+fn snap(
+    rect: Rect,
+    screen_rect: Rect,
+    mut align: Align2,
+    offset: Pos2,
+    mut direction: Direction,
+) -> DraggedAnchor {
+    let mut offset = offset.to_vec2();
+    for axis in 0..2 {
+        if rect.min[axis] - screen_rect.min[axis] < SNAP_DISTANCE {
+            align.0[axis] = Align::Min;
+            offset[axis] = SNAP_MARGIN;
+        } else if screen_rect.max[axis] - rect.max[axis] < SNAP_DISTANCE {
+            align.0[axis] = Align::Max;
+            offset[axis] = -SNAP_MARGIN;
+        } else {
+            continue;
+        }
+
+        direction = match (axis, align.0[axis], direction) {
+            (0, Align::Min, Direction::RightToLeft) => Direction::LeftToRight,
+            (0, Align::Max, Direction::LeftToRight) => Direction::RightToLeft,
+            (1, Align::Min, Direction::BottomUp) => Direction::TopDown,
+            (1, Align::Max, Direction::TopDown) => Direction::BottomUp,
+            _ => direction,
+        };
+    }
+    (align, offset, direction)
+}
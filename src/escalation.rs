@@ -0,0 +1,148 @@
+use std::time::Duration;
+
+use crate::state::StoredToast;
+use crate::{format_elapsed, DismissReason, Toast, ToastKind, ToastOptions};
+
+/// Number of occurrences listed in the details of an escalated toast. Only the latest
+/// ones are kept.
+const MAX_LISTED: usize = 20;
+
+/// When a repeating toast escalates, see [`Toasts::escalation`](crate::Toasts::escalation).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct EscalationPolicy {
+    /// The toast escalates when it is added more than this many times within the window.
+    /// Defaults to 3.
+    pub threshold: usize,
+    /// Time within which the toast has to be repeated. Defaults to 10 seconds.
+    pub window: Duration,
+    /// Kind of the escalated toast. Defaults to [`ToastKind::Error`].
+    pub to_kind: ToastKind,
+    /// Whether the escalated toast never expires. Defaults to `true`.
+    pub sticky: bool,
+    /// Whether the toast goes back to its kind and duration once the window passes without
+    /// it being added again. Defaults to `false`.
+    pub de_escalate: bool,
+}
+
+impl Default for EscalationPolicy {
+    fn default() -> Self {
+        Self {
+            threshold: 3,
+            window: Duration::from_secs(10),
+            to_kind: ToastKind::Error,
+            sticky: true,
+            de_escalate: false,
+        }
+    }
+}
+
+/// Times a stored toast was added, kept while an [`EscalationPolicy`] is set.
+#[derive(Clone, Default)]
+pub(crate) struct Occurrences {
+    /// Times of the occurrences within the window, or the latest ones once escalated.
+    times: Vec<f64>,
+    /// Kind and options of the toast before it escalated.
+    escalated_from: Option<(ToastKind, ToastOptions)>,
+}
+
+/// Whether `toast` repeats the message of the stored toast, also after the stored toast
+/// escalated to another kind.
+pub(crate) fn is_repeated_by(stored: &StoredToast, toast: &Toast) -> bool {
+    let kind = stored
+        .occurrences
+        .escalated_from
+        .map_or(stored.toast.kind, |(kind, _)| kind);
+    kind == toast.kind && stored.toast.has_same_message(toast)
+}
+
+/// Remember that the toast was first added at `time`.
+pub(crate) fn record_first(stored: &mut StoredToast, time: f64) {
+    stored.occurrences.times = vec![time];
+}
+
+/// Count a repetition of the stored toast at `time`. Returns `toast` back if it should
+/// replace the stored toast as usual. Otherwise the stored toast escalates, or is already
+/// escalated, and keeps its id, so that its handles stay valid. `toast` is merged into it.
+pub(crate) fn repeat(
+    stored: &mut StoredToast,
+    toast: Toast,
+    time: f64,
+    policy: &EscalationPolicy,
+) -> Option<Toast> {
+    let occurrences = &mut stored.occurrences;
+    if occurrences.escalated_from.is_none() && stored.toast.kind == policy.to_kind {
+        return Some(toast);
+    }
+    let window = policy.window.as_secs_f64();
+    if occurrences.escalated_from.is_none() {
+        occurrences
+            .times
+            .retain(|added_at| time - *added_at <= window);
+    }
+    occurrences.times.push(time);
+    if occurrences.escalated_from.is_none() && occurrences.times.len() <= policy.threshold {
+        return Some(toast);
+    }
+    let excess = occurrences.times.len().saturating_sub(MAX_LISTED);
+    occurrences.times.drain(..excess);
+    toast.notify_dismissed(DismissReason::Merged);
+    if occurrences.escalated_from.is_none() {
+        let kind = std::mem::replace(&mut stored.toast.kind, policy.to_kind);
+        occurrences.escalated_from = Some((kind, stored.toast.options));
+        if policy.sticky {
+            stored.toast.options.duration = None;
+        }
+        // Play the sound of the new kind
+        stored.announced = false;
+        log::debug!(
+            "Toast {:?} escalated to {:?}",
+            stored.toast.id,
+            policy.to_kind
+        );
+    }
+    stored.toast.details = Some(details(&stored.occurrences.times).into());
+    None
+}
+
+/// Return the escalated toasts that were not repeated within the window to their kind and
+/// duration, if the policy allows it.
+pub(crate) fn de_escalate(toasts: &mut [StoredToast], time: f64, policy: &EscalationPolicy) {
+    if !policy.de_escalate {
+        return;
+    }
+    let window = policy.window.as_secs_f64();
+    for stored in toasts {
+        let occurrences = &mut stored.occurrences;
+        let Some((kind, mut options)) = occurrences.escalated_from else {
+            continue;
+        };
+        if occurrences
+            .times
+            .last()
+            .is_some_and(|added_at| time - *added_at <= window)
+        {
+            continue;
+        }
+        // The countdown starts over with the options from before the escalation
+        options.remaining = options.duration.unwrap_or_default();
+        let current = stored.toast.options;
+        (options.dismissed, options.deferred) = (current.dismissed, current.deferred);
+        options.displayed = current.displayed;
+        stored.toast.kind = kind;
+        stored.toast.options = options;
+        stored.toast.details = None;
+        *occurrences = Occurrences::default();
+        log::debug!("Toast {:?} de-escalated to {kind:?}", stored.toast.id);
+    }
+}
+
+/// The times of the occurrences, one per line.
+fn details(times: &[f64]) -> String {
+    times
+        .iter()
+        .map(|time| format_elapsed(Duration::try_from_secs_f64(*time).unwrap_or_default()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
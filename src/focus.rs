@@ -0,0 +1,87 @@
+use egui::{Context, Id, Key, KeyboardShortcut, LayerId, Modifiers, Rect};
+
+use crate::state::StoredToast;
+use crate::ToastId;
+
+/// Handle the keyboard navigation between the toasts of one [`Toasts`](crate::Toasts) instance.
+///
+/// Returns the id of the focused toast. The focus is stored in the egui context's memory
+/// under `id`, so it persists when the [`Toasts`](crate::Toasts) instance is recreated.
+pub(crate) fn update_focus(
+    ctx: &Context,
+    id: Id,
+    shortcut: &KeyboardShortcut,
+    toasts: &mut [StoredToast],
+) -> Option<ToastId> {
+    // Only toasts that are visible can be focused
+    let focusable: Vec<ToastId> = toasts
+        .iter()
+        .filter(|stored| stored.size.is_some() && !stored.toast.options.is_expired())
+        .filter_map(|stored| stored.toast.id)
+        .collect();
+
+    let mut focused = ctx
+        .data(|d| d.get_temp::<ToastId>(id))
+        .filter(|focused| focusable.contains(focused));
+
+    let mut activated = None;
+    ctx.input_mut(|i| {
+        if i.consume_shortcut(shortcut) {
+            focused = match focused {
+                Some(_) => None,
+                None => focusable.first().copied(),
+            };
+        }
+
+        let Some(current) = focused else {
+            return;
+        };
+        let index = focusable.iter().position(|id| *id == current).unwrap_or(0);
+
+        if i.consume_key(Modifiers::NONE, Key::Escape) {
+            focused = None;
+        } else if i.consume_key(Modifiers::NONE, Key::ArrowDown)
+            || i.consume_key(Modifiers::NONE, Key::ArrowRight)
+        {
+            focused = Some(focusable[(index + 1) % focusable.len()]);
+        } else if i.consume_key(Modifiers::NONE, Key::ArrowUp)
+            || i.consume_key(Modifiers::NONE, Key::ArrowLeft)
+        {
+            focused = Some(focusable[(index + focusable.len() - 1) % focusable.len()]);
+        } else if i.consume_key(Modifiers::NONE, Key::Enter) {
+            activated = Some(index);
+        }
+    });
+
+    // Activated outside of the input, since closing the toast may access the context
+    if let Some(index) = activated {
+        let closed = toasts
+            .iter_mut()
+            .find(|s| s.toast.id == Some(focusable[index]))
+            .is_some_and(|stored| stored.toast.activate(ctx));
+        if closed {
+            // Move the focus to the next toast, or to the previous one if the closed toast was the last
+            focused = focusable
+                .get(index + 1)
+                .or_else(|| index.checked_sub(1).map(|i| &focusable[i]))
+                .copied();
+        }
+    }
+
+    ctx.data_mut(|d| match focused {
+        Some(focused) => d.insert_temp(id, focused),
+        None => d.remove::<ToastId>(id),
+    });
+
+    focused
+}
+
+/// Draw a focus ring around the toast drawn in the given layer.
+pub(crate) fn paint_focus_ring(ctx: &Context, layer_id: LayerId, rect: Rect) {
+    let visuals = &ctx.style().visuals;
+    ctx.layer_painter(layer_id).rect_stroke(
+        rect.expand(2.0),
+        visuals.window_rounding,
+        visuals.selection.stroke,
+    );
+}
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use egui::epaint::RectShape;
+use egui::{Id, Response, Shape, Ui, Vec2, WidgetInfo, WidgetType};
+
+use crate::state::StoredToast;
+use crate::{
+    button_galley, default_toast_contents, fixed_id_button, Toast, ToastContents, ToastKind,
+    ToastStyle,
+};
+
+/// Identifies a group of toasts that are shown together.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum GroupKey {
+    /// Toasts with the same [group](crate::Toast::group) key.
+    Explicit(String),
+    /// Older toasts of a kind, collapsed by [`Toasts::auto_group`](crate::Toasts::auto_group).
+    Auto(ToastKind),
+}
+
+/// Find the group of each toast.
+///
+/// With `auto_group`, all but the newest toast of a kind are grouped if there are more
+/// than `auto_group` toasts of that kind outside of explicit groups.
+pub(crate) fn group_keys(
+    toasts: &[StoredToast],
+    auto_group: Option<usize>,
+) -> Vec<Option<GroupKey>> {
+    let ungrouped = |kind: ToastKind| {
+        toasts
+            .iter()
+            .filter(move |stored| stored.toast.group.is_none() && stored.toast.kind == kind)
+    };
+    toasts
+        .iter()
+        .enumerate()
+        .map(|(index, stored)| {
+            let toast = &stored.toast;
+            if let Some(key) = &toast.group {
+                return Some(GroupKey::Explicit(key.clone()));
+            }
+            let threshold = auto_group?;
+            let newest = toasts
+                .iter()
+                .rposition(|other| other.toast.group.is_none() && other.toast.kind == toast.kind);
+            (ungrouped(toast.kind).count() > threshold && newest != Some(index))
+                .then_some(GroupKey::Auto(toast.kind))
+        })
+        .collect()
+}
+
+/// State of a [group](crate::Toast::group) of toasts, stored in the egui context's memory
+/// under [`Toasts::group_id`](crate::Toasts::group_id).
+#[derive(Clone, Copy, Default)]
+pub(crate) struct GroupState {
+    pub(crate) expanded: bool,
+    /// Size of the group measured on the previous frame.
+    pub(crate) size: Option<Vec2>,
+}
+
+/// The toast the header of a group is styled after: the most severe toast of the group.
+pub(crate) fn header<'a>(members: &'a [&mut StoredToast]) -> &'a Toast {
+    members
+        .iter()
+        .map(|stored| &stored.toast)
+        .max_by_key(|toast| toast.kind.severity())
+        .expect("groups have at least one toast")
+}
+
+/// Draw a group of toasts: a header with the given title, and the toasts
+/// themselves if the group is expanded.
+///
+/// The header is styled after the [`header`] toast.
+pub(crate) fn group_contents(
+    ui: &mut Ui,
+    group_id: Id,
+    title: &str,
+    expanded: &mut bool,
+    members: &mut [&mut StoredToast],
+    custom_toast_contents: &HashMap<ToastKind, Arc<ToastContents>>,
+    default_style: &ToastStyle,
+) -> Response {
+    let header = header(members);
+    let kind = header.kind;
+    let style = header
+        .resolve_style(default_style)
+        .scaled(ui.ctx(), header.scale(ui.ctx()));
+    let show_icon = header.options.show_icon;
+    let right_to_left = style.layout_direction.is_right_to_left(ui);
+
+    let mut toggled = false;
+    let mut closed = false;
+    let frame = style.frame(ui.style());
+    let response = frame
+        .show(ui, |ui| {
+            style.apply_text_color(ui);
+            ui.horizontal(|ui| {
+                let icon = |ui: &mut Ui| {
+                    if show_icon {
+                        ui.label(style.icon(ui.ctx(), kind)).widget_info(|| {
+                            WidgetInfo::labeled(
+                                WidgetType::Label,
+                                true,
+                                style.strings.kind_label(kind),
+                            )
+                        });
+                    }
+                };
+                let title = |ui: &mut Ui| {
+                    ui.label(title);
+                };
+                let mut buttons = |ui: &mut Ui| {
+                    let (text, label) = if *expanded {
+                        ("⏶", &style.strings.collapse_group_label)
+                    } else {
+                        ("⏷", &style.strings.expand_group_label)
+                    };
+                    let galley = button_galley(ui, text.into());
+                    toggled = fixed_id_button(ui, group_id.with("toggle"), galley, label).clicked();
+
+                    let galley = button_galley(ui, style.close_button_text.clone());
+                    let label = &style.strings.close_button_label;
+                    closed =
+                        fixed_id_button(ui, group_id.with("close_button"), galley, label).clicked();
+                };
+
+                // See `default_toast_contents`
+                if right_to_left == ui.layout().prefer_right_to_left() {
+                    icon(ui);
+                    title(ui);
+                    buttons(ui);
+                } else {
+                    buttons(ui);
+                    title(ui);
+                    icon(ui);
+                }
+            });
+
+            if *expanded {
+                for stored in members.iter_mut() {
+                    let toast = &mut stored.toast;
+                    if let Some(add_contents) = custom_toast_contents.get(&toast.kind) {
+                        add_contents(ui, toast);
+                    } else {
+                        let style = toast.resolve_style(default_style);
+                        default_toast_contents(ui, toast, &style, Vec2::ZERO, stored.count);
+                    }
+                }
+            }
+        })
+        .response;
+
+    #[cfg(feature = "accesskit")]
+    ui.ctx().accesskit_node_builder(response.id, |builder| {
+        builder.set_role(match kind {
+            ToastKind::Warning | ToastKind::Error => egui::accesskit::Role::Alert,
+            _ => egui::accesskit::Role::Status,
+        });
+    });
+
+    if toggled {
+        *expanded = !*expanded;
+    }
+    if closed {
+        for stored in members.iter_mut() {
+            stored.toast.request_close(ui.ctx());
+        }
+    }
+
+    // Draw the frame's stroke last
+    let frame_shape = Shape::Rect(RectShape::stroke(
+        response.rect,
+        frame.rounding,
+        style.frame_stroke(ui.visuals()),
+    ));
+    ui.painter().add(frame_shape);
+
+    response
+}
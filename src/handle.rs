@@ -0,0 +1,202 @@
+use egui::{Context, Id, WidgetText};
+
+use crate::blip;
+use crate::state::{LastFrame, StoredToast};
+use crate::{DismissReason, ToastId};
+
+/// Handle to a toast added with [`Toasts::add`](crate::Toasts::add), used to control
+/// the toast after it was added.
+///
+/// The changes are stored in the egui context's memory and applied on the next call to
+/// [`Toasts::show`](crate::Toasts::show), so the handle can be kept and used freely,
+/// even when the [`Toasts`](crate::Toasts) instance is recreated every frame.
+///
+/// ```
+/// # use egui_toast::{Toast, ToastOptions, Toasts};
+/// # fn run(ctx: &egui::Context, toasts: &mut Toasts, time: f64) {
+/// #     let input = egui::RawInput { time: Some(time), ..Default::default() };
+/// #     let _ = ctx.run(input, |ctx| toasts.show(ctx));
+/// # }
+/// let ctx = egui::Context::default();
+/// let mut toasts = Toasts::new();
+/// let handle = toasts.add(Toast::new().text("Uploading").options(ToastOptions::default().duration_in_seconds(1.0)));
+/// handle.pause(&ctx);
+/// for i in 0..20 {
+///     run(&ctx, &mut toasts, i as f64 * 0.1);
+/// }
+/// assert!(ctx.read_response(handle.id().text_id()).is_some());
+///
+/// handle.resume(&ctx);
+/// for i in 20..40 {
+///     run(&ctx, &mut toasts, i as f64 * 0.1);
+/// }
+/// assert!(ctx.read_response(handle.id().text_id()).is_none());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ToastHandle {
+    toasts_id: Id,
+    id: ToastId,
+}
+
+/// A change requested through a [`ToastHandle`].
+#[derive(Clone)]
+pub(crate) enum Command {
+    Pause,
+    Resume,
+    SetProgress(Vec<f32>),
+    SetText(WidgetText),
+    SetTextQuiet(WidgetText),
+    Close,
+    /// Dismiss the toast because its copy was dismissed in the lane `from`, see
+    /// [`ToastOptions::broadcast`](crate::ToastOptions::broadcast).
+    Dismiss {
+        reason: DismissReason,
+        from: Id,
+    },
+}
+
+impl ToastHandle {
+    pub(crate) fn new(toasts_id: Id, id: ToastId) -> Self {
+        Self { toasts_id, id }
+    }
+
+    /// Id of the toast.
+    pub fn id(self) -> ToastId {
+        self.id
+    }
+
+    /// Stop the countdown of the toast until [`Self::resume`] is called.
+    pub fn pause(self, ctx: &Context) {
+        self.send(ctx, Command::Pause);
+    }
+
+    /// Continue the countdown of a paused toast from the remaining time.
+    pub fn resume(self, ctx: &Context) {
+        self.send(ctx, Command::Resume);
+    }
+
+    /// Close the toast, e.g. a [stopwatch](crate::ToastOptions::stopwatch) toast when the
+    /// activity it shows ends. The toast is dismissed with
+    /// [`DismissReason::Closed`](crate::DismissReason::Closed).
+    pub fn close(self, ctx: &Context) {
+        self.send(ctx, Command::Close);
+    }
+
+    /// Replace the text of the toast, e.g. to show how far an operation is. The toast keeps
+    /// its place and its remaining time. See [`Toast::reserve_width_for`](crate::Toast::reserve_width_for)
+    /// to keep the width of the toast stable while the text changes.
+    pub fn set_text(self, ctx: &Context, text: impl Into<WidgetText>) {
+        self.send(ctx, Command::SetText(text.into()));
+    }
+
+    /// Like [`Self::set_text`], but without the [update blip](crate::ToastStyle::update_blip),
+    /// e.g. for frequent updates that don't need attention.
+    pub fn set_text_quiet(self, ctx: &Context, text: impl Into<WidgetText>) {
+        self.send(ctx, Command::SetTextQuiet(text.into()));
+    }
+
+    /// Show `progress`, from 0 to 1, in the progress bar instead of the remaining time.
+    ///
+    /// This is the same as [`Self::set_progress_segments`] with a single segment.
+    pub fn set_progress(self, ctx: &Context, progress: f32) {
+        self.set_progress_segments(ctx, &[progress]);
+    }
+
+    /// Split the progress bar into segments that show the progress of each stage of an
+    /// operation, such as download, parse and index. Each segment takes an equal part of the
+    /// bar and fills from 0 to 1 independently. The look of the segments is set with
+    /// [`ToastStyle::progress_segment_gap`](crate::ToastStyle::progress_segment_gap) and
+    /// [`ToastStyle::progress_segment_colors`](crate::ToastStyle::progress_segment_colors).
+    ///
+    /// The toast still expires after its duration. An empty slice shows the remaining
+    /// time again.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new();
+    /// let handle = toasts.add(Toast::new().text("Importing"));
+    /// let mut shapes = || ctx.run(Default::default(), |ctx| toasts.show(ctx)).shapes.len();
+    /// shapes();
+    /// let remaining_time = shapes();
+    /// handle.set_progress_segments(&ctx, &[1.0, 0.5, 0.0]);
+    /// shapes();
+    /// // A track and the filled part for each started stage
+    /// assert_eq!(shapes(), remaining_time + 4);
+    /// ```
+    pub fn set_progress_segments(self, ctx: &Context, segments: &[f32]) {
+        let segments = segments.iter().map(|p| p.clamp(0.0, 1.0)).collect();
+        self.send(ctx, Command::SetProgress(segments));
+    }
+
+    /// Whether the pointer was over the toast on the previous frame.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new();
+    /// let handle = toasts.add(Toast::new().text("Hello"));
+    /// let mut run = |events| {
+    ///     let input = egui::RawInput { events, ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| toasts.show(ctx));
+    /// };
+    /// run(vec![]);
+    /// run(vec![]);
+    /// assert!(!handle.is_hovered(&ctx));
+    ///
+    /// let pos = ctx.read_response(handle.id().text_id()).unwrap().rect.center();
+    /// run(vec![egui::Event::PointerMoved(pos)]);
+    /// assert!(handle.is_hovered(&ctx) && handle.is_paused(&ctx));
+    /// ```
+    pub fn is_hovered(self, ctx: &Context) -> bool {
+        LastFrame::load(ctx, self.id).hovered
+    }
+
+    /// Whether the countdown of the toast was stopped on the previous frame, either because
+    /// the toast was hovered (see [`Toasts::hover_pauses`](crate::Toasts::hover_pauses))
+    /// or because it was [paused](Self::pause).
+    pub fn is_paused(self, ctx: &Context) -> bool {
+        LastFrame::load(ctx, self.id).paused
+    }
+
+    pub(crate) fn send(self, ctx: &Context, command: Command) {
+        ctx.data_mut(|d| {
+            d.get_temp_mut_or_default::<Vec<(ToastId, Command)>>(commands_id(self.toasts_id))
+                .push((self.id, command));
+        });
+    }
+}
+
+fn commands_id(toasts_id: Id) -> Id {
+    toasts_id.with("commands")
+}
+
+/// Apply the changes requested through the handles of the toasts.
+pub(crate) fn apply_commands(ctx: &Context, toasts_id: Id, toasts: &mut [StoredToast]) {
+    let commands: Vec<(ToastId, Command)> = ctx
+        .data_mut(|d| d.remove_temp(commands_id(toasts_id)))
+        .unwrap_or_default();
+    for (id, command) in commands {
+        let Some(stored) = toasts.iter_mut().find(|stored| stored.toast.id == Some(id)) else {
+            continue;
+        };
+        match command {
+            Command::Pause => stored.paused = true,
+            Command::Resume => stored.paused = false,
+            Command::SetProgress(progress) => stored.toast.progress = progress,
+            Command::SetText(text) => stored.toast.text = text,
+            Command::SetTextQuiet(text) => {
+                stored.toast.text = text;
+                blip::acknowledge(ctx, &stored.toast);
+            }
+            Command::Close => stored.toast.close(),
+            Command::Dismiss { reason, from } => {
+                let options = &mut stored.toast.options;
+                if options.dismissed.is_none() {
+                    options.dismissed_in = Some(from);
+                }
+                options.dismiss(reason);
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::ToastKind;
+
+/// Decides whether a text matches a [`KindRule`].
+pub type KindMatcher = dyn Fn(&str) -> bool + Send + Sync;
+
+/// What a [`KindRule`] looks for in the text. Prefixes and substrings are compared
+/// case-insensitively, and the prefixes ignore leading whitespace.
+#[derive(Clone)]
+pub enum KindPattern {
+    Prefix(String),
+    Contains(String),
+    /// Any test of the text, e.g. `Regex::is_match` of the `regex` crate.
+    Matches(Arc<KindMatcher>),
+}
+
+impl fmt::Debug for KindPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Prefix(prefix) => f.debug_tuple("Prefix").field(prefix).finish(),
+            Self::Contains(substring) => f.debug_tuple("Contains").field(substring).finish(),
+            Self::Matches(_) => f.write_str("Matches(..)"),
+        }
+    }
+}
+
+impl KindPattern {
+    fn matches(&self, text: &str) -> bool {
+        match self {
+            Self::Prefix(prefix) => text
+                .trim_start()
+                .to_lowercase()
+                .starts_with(&prefix.to_lowercase()),
+            Self::Contains(substring) => text.to_lowercase().contains(&substring.to_lowercase()),
+            Self::Matches(matches) => matches(text),
+        }
+    }
+}
+
+/// Gives the toasts whose text matches the pattern the kind of the rule.
+#[derive(Debug, Clone)]
+pub struct KindRule {
+    pub pattern: KindPattern,
+    pub kind: ToastKind,
+}
+
+/// Picks the kind of a toast from its text, see [`Toasts::auto`](crate::Toasts::auto).
+///
+/// The first rule that matches decides the kind. The default rules make texts that start
+/// with "error" or "failed", or contain an `Err(` of a debug print, errors, texts that start
+/// with "warn" warnings, and texts that start with "done" or "success" successes.
+///
+/// ```
+/// # use egui_toast::{KindInference, ToastKind};
+/// let inference = KindInference::default().contains("timed out", ToastKind::Warning);
+/// assert_eq!(inference.infer("Failed to save"), ToastKind::Error);
+/// assert_eq!(inference.infer("result: Err(NotFound)"), ToastKind::Error);
+/// assert_eq!(inference.infer("WARNING: low disk space"), ToastKind::Warning);
+/// assert_eq!(inference.infer("Request timed out"), ToastKind::Warning);
+/// assert_eq!(inference.infer("Done in 3 s"), ToastKind::Success);
+/// assert_eq!(inference.infer("3 files copied"), ToastKind::Info);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KindInference {
+    pub rules: Vec<KindRule>,
+    /// Kind of the texts that match no rule. Defaults to [`ToastKind::Info`].
+    pub fallback: ToastKind,
+}
+
+impl Default for KindInference {
+    fn default() -> Self {
+        Self::empty()
+            .prefix("error", ToastKind::Error)
+            .prefix("failed", ToastKind::Error)
+            .contains("Err(", ToastKind::Error)
+            .prefix("warn", ToastKind::Warning)
+            .prefix("done", ToastKind::Success)
+            .prefix("success", ToastKind::Success)
+    }
+}
+
+impl KindInference {
+    /// No rules, so that every text gets the [fallback](Self::fallback) kind.
+    pub fn empty() -> Self {
+        Self {
+            rules: Vec::new(),
+            fallback: ToastKind::Info,
+        }
+    }
+
+    /// Add a rule for the texts that start with `prefix`.
+    #[must_use]
+    pub fn prefix(self, prefix: impl Into<String>, kind: ToastKind) -> Self {
+        self.rule(KindPattern::Prefix(prefix.into()), kind)
+    }
+
+    /// Add a rule for the texts that contain `substring`.
+    #[must_use]
+    pub fn contains(self, substring: impl Into<String>, kind: ToastKind) -> Self {
+        self.rule(KindPattern::Contains(substring.into()), kind)
+    }
+
+    /// Add a rule for the texts that `matches` accepts.
+    #[must_use]
+    pub fn matches(
+        self,
+        matches: impl Fn(&str) -> bool + Send + Sync + 'static,
+        kind: ToastKind,
+    ) -> Self {
+        self.rule(KindPattern::Matches(Arc::new(matches)), kind)
+    }
+
+    #[must_use]
+    pub fn fallback(mut self, fallback: ToastKind) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    #[must_use]
+    fn rule(mut self, pattern: KindPattern, kind: ToastKind) -> Self {
+        self.rules.push(KindRule { pattern, kind });
+        self
+    }
+
+    /// The kind of the first rule that matches `text`, or the fallback kind.
+    pub fn infer(&self, text: &str) -> ToastKind {
+        self.rules
+            .iter()
+            .find(|rule| rule.pattern.matches(text))
+            .map_or(self.fallback, |rule| rule.kind)
+    }
+}
@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use egui::{Context, Event, KeyboardShortcut, Pos2, Rect, Vec2};
+
+use crate::state::StoredToast;
+
+/// Time the toasts are still shown after the input that dismisses them, so that they fade
+/// out instead of vanishing.
+pub(crate) const GRACE_PERIOD: Duration = Duration::from_millis(500);
+
+/// Which input dismisses the toasts, see
+/// [`Toasts::dismiss_on_input`](crate::Toasts::dismiss_on_input).
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct DismissOnInput {
+    /// A key pressed or text entered.
+    pub keyboard: bool,
+    /// A mouse button pressed.
+    pub pointer_click: bool,
+    /// The mouse wheel or the touchpad scrolled.
+    pub scroll: bool,
+}
+
+impl DismissOnInput {
+    /// Any of the input.
+    pub const ALL: Self = Self {
+        keyboard: true,
+        pointer_click: true,
+        scroll: true,
+    };
+
+    fn is_enabled(self) -> bool {
+        self.keyboard || self.pointer_click || self.scroll
+    }
+
+    /// Whether the input of this frame dismisses the toasts. Pointer input over
+    /// `occupied` does not count, nor does keyboard input while the toasts have the
+    /// [focus](crate::Toasts::focusable) or the key that moves the focus to them.
+    pub(crate) fn detected(
+        self,
+        ctx: &Context,
+        occupied: &[Rect],
+        toasts_focused: bool,
+        focus_shortcut: Option<&KeyboardShortcut>,
+    ) -> bool {
+        if !self.is_enabled() {
+            return false;
+        }
+        let over_toasts = |pos: Option<Pos2>| {
+            pos.is_some_and(|pos| occupied.iter().any(|rect| rect.contains(pos)))
+        };
+        ctx.input(|i| {
+            let keyboard = self.keyboard
+                && !toasts_focused
+                && i.events.iter().any(|event| match event {
+                    Event::Key {
+                        key,
+                        pressed: true,
+                        modifiers,
+                        ..
+                    } => !focus_shortcut.is_some_and(|shortcut| {
+                        shortcut.logical_key == *key
+                            && modifiers.matches_logically(shortcut.modifiers)
+                    }),
+                    Event::Text(text) | Event::Paste(text) => !text.is_empty(),
+                    _ => false,
+                });
+            let pointer = &i.pointer;
+            let click =
+                self.pointer_click && pointer.any_pressed() && !over_toasts(pointer.interact_pos());
+            let scroll = self.scroll
+                && i.raw_scroll_delta != Vec2::ZERO
+                && !over_toasts(pointer.hover_pos());
+            keyboard || click || scroll
+        })
+    }
+}
+
+/// Cut the remaining time of the toasts that opt in to the [grace period](GRACE_PERIOD).
+/// Toasts that never expire are kept.
+pub(crate) fn dismiss(toasts: &mut [StoredToast]) {
+    for stored in toasts {
+        let toast = &mut stored.toast;
+        // Toasts that were not shown for their minimum display time fade out at its end
+        let remaining = GRACE_PERIOD.max(toast.options.until_min_display());
+        if toast.dismisses_on_input() && toast.options.cut_to(remaining) {
+            stored.input_dismissed = true;
+        }
+    }
+}
+
+/// Opacity of a toast fading out after the input that dismissed it.
+pub(crate) fn opacity(stored: &StoredToast) -> f32 {
+    if !stored.input_dismissed {
+        return 1.0;
+    }
+    let remaining = stored.toast.options.remaining().unwrap_or_default();
+    (remaining.as_secs_f32() / GRACE_PERIOD.as_secs_f32()).clamp(0.0, 1.0)
+}
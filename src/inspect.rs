@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use egui::{Grid, Id, Ui};
+
+use crate::state::{self, LastFrame, StoredToast};
+use crate::{queue, Toast, ToastId};
+
+/// List the toasts stored under `toasts_id`, followed by the toasts that wait to be added.
+pub(crate) fn debug_ui(ui: &mut Ui, toasts_id: Id, added_toasts: &[Toast]) {
+    let ctx = ui.ctx().clone();
+    let toasts: Vec<StoredToast> = ctx.data(|d| d.get_temp(toasts_id).unwrap_or_default());
+    let queued = queue::peek(&ctx, toasts_id);
+
+    ui.label(format!("Live toasts: {}", toasts.len()));
+    Grid::new(toasts_id.with("debug_ui"))
+        .striped(true)
+        .num_columns(8)
+        .show(ui, |ui| {
+            for header in [
+                "#",
+                "Id",
+                "Kind",
+                "Text",
+                "Remaining",
+                "State",
+                "Paused",
+                "Count",
+            ] {
+                ui.strong(header);
+            }
+            ui.end_row();
+
+            for (position, stored) in toasts.iter().enumerate() {
+                let toast = &stored.toast;
+                let last_frame = toast.id.map(|id| LastFrame::load(&ctx, id));
+                ui.label(position.to_string());
+                ui.label(id_text(toast.id));
+                ui.label(format!("{:?}", toast.kind));
+                ui.label(toast.plain_text());
+                ui.label(remaining_text(toast.options.remaining()));
+                let state = toast.id.map(|id| state::load_state(&ctx, id));
+                ui.label(state.map_or_else(String::new, |state| format!("{state:?}")));
+                let paused = stored.paused || last_frame.is_some_and(|last| last.paused);
+                ui.label(paused.to_string());
+                ui.label(stored.count.to_string());
+                ui.end_row();
+            }
+        });
+
+    ui.label(format!(
+        "Waiting to be added: {}",
+        added_toasts.len() + queued.len()
+    ));
+    for toast in added_toasts.iter().chain(&queued) {
+        ui.label(format!(
+            "{} {:?}: {}",
+            id_text(toast.id),
+            toast.kind,
+            toast.plain_text()
+        ));
+    }
+}
+
+fn id_text(id: Option<ToastId>) -> String {
+    id.map_or_else(|| "-".to_owned(), |id| format!("{:?}", id))
+}
+
+fn remaining_text(remaining: Option<Duration>) -> String {
+    remaining.map_or_else(
+        || "∞".to_owned(),
+        |remaining| format!("{:.1} s", remaining.as_secs_f64()),
+    )
+}
@@ -0,0 +1,43 @@
+use crate::{DismissReason, Toast};
+
+pub type ToastAddInterceptor = dyn Fn(&mut Toast) -> AddDecision + Send + Sync;
+
+/// What happens to a toast passed to [`Toasts::on_add`](crate::Toasts::on_add).
+// Returned once per added toast, so boxing the replacement would only make the API noisier
+#[allow(clippy::large_enum_variant)]
+pub enum AddDecision {
+    /// The toast is added, including the changes the interceptor made to it.
+    Accept,
+    /// The toast is dropped and dismissed with [`DismissReason::Rejected`].
+    Reject,
+    /// The given toast is added instead, and the original toast is dismissed with
+    /// [`DismissReason::Superseded`]. The replacement takes over the id of the original toast,
+    /// so the [handle](crate::ToastHandle) returned when it was added controls the replacement.
+    Replace(Toast),
+}
+
+/// A newly added toast after the interceptor ran.
+pub(crate) enum Intercepted {
+    Add(Toast),
+    Rejected(Toast),
+}
+
+/// Run the interceptor on a newly added toast, returning the toast to add or the
+/// rejected toast.
+pub(crate) fn apply(on_add: Option<&ToastAddInterceptor>, mut toast: Toast) -> Intercepted {
+    let Some(on_add) = on_add else {
+        return Intercepted::Add(toast);
+    };
+    match on_add(&mut toast) {
+        AddDecision::Accept => Intercepted::Add(toast),
+        AddDecision::Reject => {
+            toast.notify_dismissed(DismissReason::Rejected);
+            Intercepted::Rejected(toast)
+        }
+        AddDecision::Replace(mut replacement) => {
+            (replacement.id, replacement.sequence) = (toast.id, toast.sequence);
+            toast.notify_dismissed(DismissReason::Superseded);
+            Intercepted::Add(replacement)
+        }
+    }
+}
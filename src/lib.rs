@@ -17,15 +17,15 @@
 //!     .anchor(Align2::LEFT_TOP, (10.0, 10.0))
 //!     .direction(egui::Direction::TopDown);
 //!
-//! toasts.add(Toast {
-//!     text: "Hello, World".into(),
-//!     kind: ToastKind::Info,
-//!     options: ToastOptions::default()
-//!         .duration_in_seconds(3.0)
-//!         .show_progress(true)
-//!         .show_icon(true),
-//!     ..Default::default()
-//! });
+//! toasts.add(
+//!     Toast::new()
+//!         .text("Hello, World")
+//!         .kind(ToastKind::Info)
+//!         .options(ToastOptions::default()
+//!             .duration_in_seconds(3.0)
+//!             .show_progress(true)
+//!             .show_icon(true)),
+//! );
 
 //!
 //! // Show all toasts
@@ -53,41 +53,247 @@
 //!     .custom_contents(MY_CUSTOM_TOAST, custom_toast_contents);
 //!
 //! // Add a custom toast that never expires
-//! toasts.add(Toast {
-//!     text: "Hello, World".into(),
-//!     kind: ToastKind::Custom(MY_CUSTOM_TOAST),
-//!     options: ToastOptions::default(),
-//!     ..Default::default()
-//! });
+//! toasts.add(
+//!     Toast::new()
+//!         .text("Hello, World")
+//!         .kind(ToastKind::Custom(MY_CUSTOM_TOAST))
+//!         .options(ToastOptions::default()),
+//! );
 //!
 //! # })
 //! ```
 //!
+//! # egui version
+//!
+//! The version of [egui] used by this crate is re-exported as [`egui_toast::egui`](egui),
+//! together with the egui types that appear in the public API. Using them guarantees
+//! that the types match even if your application depends on a different egui version.
+//!
+//! ```
+//! use egui_toast::egui::Align2;
+//! use egui_toast::Toasts;
+//!
+//! let toasts = Toasts::new().anchor(Align2::RIGHT_TOP, (-10.0, 10.0));
+//! ```
+//!
+//! # Features
+//!
+//! No features are enabled by default, and each of them can be enabled on its own:
+//!
+//! - `accesskit`: expose the toasts to screen readers through AccessKit.
+//! - `testing`: a headless test context for the toasts, see the `testing` module.
+//! - `context-ext`: add toasts from anywhere with the egui context, see `ToastsExt`.
+//! - `ansi`: toasts with ANSI-colored text, see `Toast::ansi_text`.
+//! - `serde`: `Serialize` and `Deserialize` for the plain data types, such as [`ToastKind`],
+//!   [`ToastStrings`] and the policies, e.g. to load translated strings from a file.
+//!
+//! ```
+//! # #[cfg(feature = "serde")] {
+//! fn stored_in_settings<T: serde::Serialize + serde::de::DeserializeOwned>() {}
+//! stored_in_settings::<egui_toast::ToastStrings>();
+//! stored_in_settings::<egui_toast::EscalationPolicy>();
+//! # }
+//! ```
+//!
 #![deny(clippy::all)]
 
+#[cfg(feature = "ansi")]
+mod ansi;
+mod blip;
+mod broadcast;
+mod button;
+mod capacity;
+mod chain;
+mod clear_all;
+mod close;
+mod compact;
+mod config;
+mod context_ext;
+mod context_menu;
+mod contrast;
+mod dedupe;
+mod drag;
+mod escalation;
+mod focus;
+mod group;
+mod handle;
+mod infer;
+mod input;
+mod inspect;
+mod intercept;
+mod limit;
+mod motion;
+mod paint_order;
+mod preset;
+mod queue;
+mod reserve;
+mod rich_text;
+mod sanitize;
+mod scale;
+mod selection;
+mod slot;
+mod sound;
+mod state;
+mod summary;
+mod suppressed;
+mod template;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod time;
 mod toast;
+mod undo;
+pub use blip::BlipStyle;
+pub use button::*;
+pub use capacity::{AddResult, CapacityPolicy};
+pub use chain::SequenceHandle;
+pub use close::{CloseDecision, ToastCloseCallback};
+pub use config::ToastsConfig;
+#[cfg(feature = "context-ext")]
+pub use context_ext::ToastsExt;
+pub use context_menu::ToastMenuExtra;
+pub use escalation::EscalationPolicy;
+pub use handle::ToastHandle;
+pub use infer::{KindInference, KindMatcher, KindPattern, KindRule};
+pub use input::DismissOnInput;
+pub use intercept::{AddDecision, ToastAddInterceptor};
+pub use limit::KindLimitPolicy;
+pub use preset::ToastsPreset;
+pub use queue::ToastQueue;
+pub use rich_text::ToastText;
+pub use sanitize::{sanitize_text, TextSanitizer};
+pub use sound::{ToastShowCallback, ToastSound};
+pub use summary::ToastsSummary;
+pub use template::ToastTemplate;
+pub use time::{EguiTime, MockTime, TimeSource};
 pub use toast::*;
 
+pub use egui;
+pub use egui::{Align2, Color32, Direction, Pos2, Vec2, WidgetText};
+
 use std::collections::HashMap;
+use std::fmt;
 use std::sync::Arc;
 use std::time::Duration;
 
+use button::action_button;
+use capacity::DroppedToasts;
+use compact::CompactStack;
+use dedupe::RecentMessages;
+use egui::collapsing_header::CollapsingState;
 use egui::epaint::RectShape;
 use egui::{
-    Align2, Area, Context, Direction, Frame, Id, Order, Pos2, Response, Rounding, Shape, Stroke, Ui,
+    Align, Area, CollapsingHeader, Context, CursorIcon, Galley, Id, Key, KeyboardShortcut, Label,
+    LayerId, Layout, Modifiers, Order, Rect, Response, Rounding, Sense, Shape, Stroke, TextStyle,
+    TextWrapMode, Ui, WidgetInfo, WidgetType,
 };
+use group::{GroupKey, GroupState};
+use intercept::Intercepted;
+use state::{LastFrame, StoredToast};
+use suppressed::SuppressedToasts;
+
+/// Offset of the toasts from their anchor along one axis.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Offset {
+    /// Offset in points.
+    Points(f32),
+    /// Offset as a fraction of the screen size along the axis, usually between -1.0 and 1.0.
+    Fraction(f32),
+}
+
+impl Offset {
+    fn points(pos: Pos2) -> [Self; 2] {
+        [Self::Points(pos.x), Self::Points(pos.y)]
+    }
+
+    fn resolve(self, screen_size: f32) -> f32 {
+        match self {
+            Self::Points(points) => points,
+            Self::Fraction(fraction) => fraction * screen_size,
+        }
+    }
+}
+
+impl From<f32> for Offset {
+    fn from(points: f32) -> Self {
+        Self::Points(points)
+    }
+}
 
 pub type ToastContents = dyn Fn(&mut Ui, &mut Toast) -> Response + Send + Sync;
 
+/// How long it takes for the toasts to move to their new positions after
+/// [`Toasts::set_anchor`] or [`Toasts::set_direction`].
+const LAYOUT_TRANSITION_SECS: f32 = 0.3;
+
+/// Layout stored in the egui context's memory by [`Toasts::set_anchor`] and [`Toasts::set_direction`].
+#[derive(Clone, Copy)]
+struct StoredLayout {
+    align: Align2,
+    offset: [Offset; 2],
+    direction: Direction,
+    /// Time of the latest change, used to animate the toasts to their new positions.
+    changed_at: f64,
+}
+
 pub struct Toasts {
     id: Id,
     align: Align2,
-    offset: Pos2,
+    offset: [Offset; 2],
     direction: Direction,
     custom_toast_contents: HashMap<ToastKind, Arc<ToastContents>>,
+    templates: HashMap<String, ToastTemplate>,
+    sounds: HashMap<ToastKind, Arc<ToastSound>>,
+    on_add: Option<Arc<ToastAddInterceptor>>,
+    on_show: Option<Arc<ToastShowCallback>>,
+    time_source: Arc<dyn TimeSource>,
+    text_sanitizer: Option<TextSanitizer>,
+    focusable: bool,
+    focus_shortcut: KeyboardShortcut,
+    min_size: Vec2,
+    auto_group: Option<usize>,
+    draggable: bool,
+    min_display_time: Duration,
+    order: Order,
+    spacing: f32,
+    deduplicate: bool,
+    dedupe_window: Duration,
+    escalation: Option<EscalationPolicy>,
+    kind_inference: KindInference,
+    consume_context_queue: bool,
+    lane_routes: HashMap<ToastKind, Id>,
+    broadcast_lanes: Vec<Id>,
+    single_slot: bool,
+    reduce_motion: bool,
+    cull_offscreen: bool,
+    pause_when_unfocused: bool,
+    max_visible_per_kind: HashMap<ToastKind, usize>,
+    kind_limit_policy: KindLimitPolicy,
+    capacity: Option<usize>,
+    capacity_policy: CapacityPolicy,
+    report_dropped: bool,
+    clear_all_threshold: Option<usize>,
+    compact_threshold: Option<usize>,
+    suppressed_badge: bool,
+    retain_suppressed: usize,
+    dismiss_on_click_outside: bool,
+    context_menu: bool,
+    dismiss_on_input: DismissOnInput,
+    hover_pauses: bool,
+    hover_margin: f32,
+    scale: f32,
+    time_scale: f32,
+    anchor_window: Option<Id>,
+    show_icons: bool,
+    show_progress_bars: bool,
+    progress_repaint_interval: Duration,
+    advance_sequences_on_dismiss: bool,
+    style: ToastStyle,
     /// Toasts added since the last draw call. These are moved to the
     /// egui context's memory, so you are free to recreate the [`Toasts`] instance every frame.
     added_toasts: Vec<Toast>,
+    /// Sequences added since the last draw call, whose first toast is in `added_toasts`.
+    added_sequences: Vec<chain::Sequence>,
 }
 
 impl Default for Toasts {
@@ -95,10 +301,73 @@ impl Default for Toasts {
         Self {
             id: Id::new("__toasts"),
             align: Align2::LEFT_TOP,
-            offset: Pos2::new(10.0, 10.0),
+            offset: [Offset::Points(10.0), Offset::Points(10.0)],
             direction: Direction::TopDown,
             custom_toast_contents: HashMap::new(),
+            templates: HashMap::new(),
+            sounds: HashMap::new(),
+            on_add: None,
+            on_show: None,
+            time_source: Arc::new(EguiTime),
+            text_sanitizer: None,
+            focusable: false,
+            focus_shortcut: KeyboardShortcut::new(Modifiers::NONE, Key::F6),
+            min_size: Vec2::ZERO,
+            auto_group: None,
+            draggable: false,
+            min_display_time: Duration::ZERO,
+            order: Order::Foreground,
+            spacing: 10.0,
+            deduplicate: false,
+            dedupe_window: Duration::ZERO,
+            escalation: None,
+            kind_inference: KindInference::default(),
+            consume_context_queue: false,
+            lane_routes: HashMap::new(),
+            broadcast_lanes: Vec::new(),
+            single_slot: false,
+            reduce_motion: false,
+            cull_offscreen: true,
+            pause_when_unfocused: false,
+            max_visible_per_kind: HashMap::new(),
+            kind_limit_policy: KindLimitPolicy::Evict,
+            capacity: None,
+            capacity_policy: CapacityPolicy::DropNewest,
+            report_dropped: false,
+            clear_all_threshold: None,
+            compact_threshold: None,
+            suppressed_badge: false,
+            retain_suppressed: 0,
+            dismiss_on_click_outside: false,
+            context_menu: false,
+            dismiss_on_input: DismissOnInput::default(),
+            hover_pauses: true,
+            hover_margin: 0.0,
+            scale: 1.0,
+            time_scale: 1.0,
+            anchor_window: None,
+            show_icons: true,
+            show_progress_bars: true,
+            progress_repaint_interval: Duration::ZERO,
+            advance_sequences_on_dismiss: true,
+            style: ToastStyle::default(),
             added_toasts: Vec::new(),
+            added_sequences: Vec::new(),
+        }
+    }
+}
+
+impl Drop for Toasts {
+    fn drop(&mut self) {
+        // Toasts added to an instance that is never shown are lost silently,
+        // which is easy to miss when the instance is recreated every frame
+        if cfg!(debug_assertions) && !self.added_toasts.is_empty() {
+            log::warn!(
+                "Toasts {:?} was dropped with {} toast(s) that were never shown. \
+                 Call `Toasts::show` after adding toasts.",
+                self.id,
+                self.added_toasts.len()
+            );
         }
     }
 }
@@ -113,18 +382,18 @@ impl Toasts {
     /// This can be useful if you want to have multiple toast groups
     /// in the same UI.
     pub fn with_id(id: Id) -> Self {
-        Self {
-            id,
-            ..Default::default()
-        }
+        let mut toasts = Self::default();
+        toasts.id = id;
+        toasts
     }
 
     /// Position where the toasts show up.
     ///
     /// The toasts will start from this position and stack up
     /// in the direction specified with [`Self::direction`].
+    #[must_use]
     pub fn position(mut self, position: impl Into<Pos2>) -> Self {
-        self.offset = position.into();
+        self.offset = Offset::points(position.into());
         self
     }
 
@@ -132,168 +401,3713 @@ impl Toasts {
     ///
     /// For instance, if you set this to (10.0, 10.0) and [`Align2::LEFT_TOP`],
     /// then (10.0, 10.0) will be the top-left corner of the first toast.
-    pub fn anchor(mut self, anchor: Align2, offset: impl Into<Pos2>) -> Self {
-        self.align = anchor;
-        self.offset = offset.into();
+    #[must_use]
+    pub fn anchor(mut self, anchor: impl Into<Align2>, offset: impl Into<Pos2>) -> Self {
+        self.align = anchor.into();
+        self.offset = Offset::points(offset.into());
         self
     }
 
-    /// Direction where the toasts stack up
-    pub fn direction(mut self, direction: impl Into<Direction>) -> Self {
-        self.direction = direction.into();
+    /// Anchor the toasts in the egui [`Window`](egui::Window) or [`Area`] with the given id
+    /// instead of the screen, e.g. for toasts that belong to a document window. The
+    /// [anchor](Self::anchor) and its offset are then relative to the window, and the toasts
+    /// move with the window, one frame behind if the window is drawn after the toasts.
+    ///
+    /// The toasts are hidden and their countdown stops while the window is closed or
+    /// collapsed. If there is no window with the id at all, e.g. because the id is wrong,
+    /// the toasts are anchored in the screen, and a warning is logged in debug builds.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, Toasts};
+    /// let window_id = egui::Id::new("Document");
+    /// let toasts = Toasts::new()
+    ///     .anchor_to_window(window_id)
+    ///     .anchor(egui::Align2::RIGHT_TOP, (-10.0, 10.0));
+    /// let mut test = ToastTestCtx::new(toasts);
+    /// let handle = test.add(Toast::new().text("Saved"));
+    /// let document = |ctx: &egui::Context, open: bool| {
+    ///     egui::Window::new("Document")
+    ///         .open(&mut open.clone())
+    ///         .fixed_pos((100.0, 100.0))
+    ///         .fixed_size((300.0, 200.0))
+    ///         .show(ctx, |ui| ui.label("Contents"));
+    /// };
+    /// for _ in 0..3 {
+    ///     test.run_with(|ctx| document(ctx, true));
+    /// }
+    /// let window = test.ctx().memory(|m| m.area_rect(window_id)).unwrap();
+    /// let toast = test.rect(handle.id()).unwrap();
+    /// let expected = window.right_top() + egui::vec2(-10.0, 10.0);
+    /// assert!(toast.right_top().distance(expected) < 1.0);
+    ///
+    /// // Closed windows fade out
+    /// for _ in 0..3 {
+    ///     test.advance(Duration::from_millis(100));
+    ///     test.run_with(|ctx| document(ctx, false));
+    /// }
+    /// assert!(!test.is_visible(handle.id()));
+    /// ```
+    #[must_use]
+    pub fn anchor_to_window(mut self, window_id: Id) -> Self {
+        self.anchor_window = Some(window_id);
         self
     }
 
-    /// Can be used to specify a custom rendering function for toasts for given kind
-    pub fn custom_contents(
+    /// The rect the toasts are anchored in: the screen, or the window of
+    /// [`Self::anchor_to_window`]. `None` while the window is closed or collapsed.
+    fn anchor_rect(&self, ctx: &Context) -> Option<Rect> {
+        let Some(window_id) = self.anchor_window else {
+            return Some(ctx.screen_rect());
+        };
+        let (rect, visible) = ctx.memory(|m| {
+            // The orders of windows and areas that can be anchored to
+            let visible = [Order::Background, Order::Middle, Order::Foreground]
+                .into_iter()
+                .any(|order| m.areas().is_visible(&LayerId::new(order, window_id)));
+            (m.area_rect(window_id), visible)
+        });
+        let Some(rect) = rect else {
+            if cfg!(debug_assertions) {
+                let warned_id = self.id.with("missing_window");
+                if !ctx.data_mut(|d| std::mem::replace(d.get_temp_mut_or_default(warned_id), true))
+                {
+                    log::warn!(
+                        "Toasts {:?} are anchored to the window {window_id:?}, which does not \
+                        exist. The toasts are anchored in the screen instead.",
+                        self.id
+                    );
+                }
+            }
+            return Some(ctx.screen_rect());
+        };
+        let collapsed = CollapsingState::load(ctx, window_id.with("collapsing"))
+            .is_some_and(|state| !state.is_open());
+        (visible && !collapsed).then_some(rect)
+    }
+
+    /// Skip drawing the toasts while the window they are anchored to is hidden. The toasts
+    /// don't count down, as nothing resets their countdown flags until they are drawn again.
+    fn hide_while_anchor_hidden(&self, ctx: &Context) {
+        ctx.data_mut(|d| {
+            for stored in d.get_temp_mut_or_default::<Vec<StoredToast>>(self.id) {
+                stored.counting_down = false;
+            }
+            d.remove::<Vec<Rect>>(self.id.with("occupied"));
+        });
+    }
+
+    /// Anchor for the toasts, with the offset given as a fraction of the screen size.
+    ///
+    /// The offset is recomputed every frame, so the toasts stay in place relative to the
+    /// screen when the window is resized. For instance, [`Align2::CENTER_BOTTOM`] with
+    /// `(0.0, -0.1)` centers the toasts horizontally, 10% of the screen height from the bottom.
+    #[must_use]
+    pub fn anchor_fraction(mut self, anchor: impl Into<Align2>, fraction: impl Into<Vec2>) -> Self {
+        let fraction = fraction.into();
+        self.align = anchor.into();
+        self.offset = [Offset::Fraction(fraction.x), Offset::Fraction(fraction.y)];
+        self
+    }
+
+    /// Anchor for the toasts, with separate offsets for both axes.
+    ///
+    /// This can be used to mix absolute and fractional offsets:
+    /// ```
+    /// # use egui_toast::{Align2, Offset, Toasts};
+    /// // Centered horizontally, 20 points from the bottom
+    /// let toasts = Toasts::new().anchor_offset(Align2::CENTER_BOTTOM, Offset::Fraction(0.0), -20.0);
+    /// ```
+    #[must_use]
+    pub fn anchor_offset(
         mut self,
-        kind: impl Into<ToastKind>,
-        add_contents: impl Fn(&mut Ui, &mut Toast) -> Response + Send + Sync + 'static,
+        anchor: impl Into<Align2>,
+        x: impl Into<Offset>,
+        y: impl Into<Offset>,
     ) -> Self {
-        self.custom_toast_contents
-            .insert(kind.into(), Arc::new(add_contents));
+        self.align = anchor.into();
+        self.offset = [x.into(), y.into()];
         self
     }
 
-    /// Add a new toast
-    pub fn add(&mut self, toast: Toast) -> &mut Self {
-        self.added_toasts.push(toast);
+    /// Direction where the toasts stack up
+    ///
+    /// The toasts are laid out using their sizes from the previous frame, so they never
+    /// overlap, even on the frame a new toast is added.
+    /// ```
+    /// # use egui_toast::{Align2, Direction, Toast, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new()
+    ///     .anchor(Align2::LEFT_BOTTOM, (10.0, -10.0))
+    ///     .direction(Direction::BottomUp);
+    /// let first = toasts.add(Toast::new().text("First")).id();
+    /// let second = toasts.add(Toast::new().text("Second")).id();
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// let rect = |id: egui_toast::ToastId| ctx.memory(|m| m.area_rect(id.area_id())).unwrap();
+    /// assert!(rect(second).bottom() < rect(first).top());
+    /// ```
+    #[must_use]
+    pub fn direction(mut self, direction: impl Into<Direction>) -> Self {
+        self.direction = direction.into();
         self
     }
 
-    /// Show and update all toasts
-    pub fn show(&mut self, ctx: &Context) {
-        let Self {
-            id,
+    /// Change the anchor of the toasts at runtime.
+    ///
+    /// Unlike [`Self::anchor`], the new anchor is stored in the egui context's memory, so it also
+    /// applies to instances recreated later with the same id, overriding their builder settings.
+    /// Toasts that are already visible move smoothly to their new positions.
+    pub fn set_anchor(
+        &mut self,
+        ctx: &Context,
+        anchor: impl Into<Align2>,
+        offset: impl Into<Pos2>,
+    ) {
+        self.align = anchor.into();
+        self.offset = Offset::points(offset.into());
+        self.store_layout(ctx);
+    }
+
+    /// Change the direction where the toasts stack up at runtime.
+    ///
+    /// See [`Self::set_anchor`].
+    pub fn set_direction(&mut self, ctx: &Context, direction: impl Into<Direction>) {
+        self.direction = direction.into();
+        self.store_layout(ctx);
+    }
+
+    fn store_layout(&self, ctx: &Context) {
+        self.store_layout_as(ctx, (self.align, self.offset, self.direction));
+    }
+
+    fn store_layout_as(&self, ctx: &Context, layout: (Align2, [Offset; 2], Direction)) {
+        let (align, offset, direction) = layout;
+        let layout = StoredLayout {
             align,
-            mut offset,
+            offset,
             direction,
-            ..
-        } = *self;
+            changed_at: self.time_source.now(ctx),
+        };
+        ctx.data_mut(|d| d.insert_temp(self.id.with("layout"), layout));
+        drag::forget_anchor(ctx, self.id);
+    }
 
-        let dt = ctx.input(|i| i.unstable_dt) as f64;
+    /// Allow the user to move the toasts by dragging any of them from an empty part of the toast.
+    ///
+    /// The whole stack follows the pointer, and snaps to the edges of the screen when released
+    /// near them. The new anchor is stored in the egui context's persisted memory, so with
+    /// egui's `persistence` feature it survives restarts. It overrides the anchor and direction
+    /// set with the builder, until [`Self::set_anchor`] or [`Self::set_direction`] is called.
+    /// An anchor stored by another version of the crate is discarded.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, Toasts};
+    /// # fn run(ctx: &egui::Context, toasts: &mut Toasts, events: Vec<egui::Event>) {
+    /// #     let input = egui::RawInput { events, ..Default::default() };
+    /// #     let _ = ctx.run(input, |ctx| toasts.show(ctx));
+    /// # }
+    /// # fn press(pos: egui::Pos2, pressed: bool) -> egui::Event {
+    /// #     egui::Event::PointerButton {
+    /// #         pos,
+    /// #         button: egui::PointerButton::Primary,
+    /// #         pressed,
+    /// #         modifiers: Default::default(),
+    /// #     }
+    /// # }
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new().draggable(true);
+    /// let id = toasts.add(Toast::new().text("Drag me")).id();
+    /// run(&ctx, &mut toasts, vec![]);
+    /// run(&ctx, &mut toasts, vec![]);
+    ///
+    /// // Drag the toast from its top left corner to the bottom right corner of the screen
+    /// let start = ctx.memory(|m| m.area_rect(id.area_id())).unwrap().min + egui::vec2(2.0, 2.0);
+    /// let end = ctx.screen_rect().max - egui::vec2(5.0, 5.0);
+    /// run(&ctx, &mut toasts, vec![egui::Event::PointerMoved(start), press(start, true)]);
+    /// for i in 1..=10 {
+    ///     let pos = start + (end - start) * i as f32 / 10.0;
+    ///     run(&ctx, &mut toasts, vec![egui::Event::PointerMoved(pos)]);
+    /// }
+    /// run(&ctx, &mut toasts, vec![press(end, false)]);
+    /// for _ in 0..30 {
+    ///     run(&ctx, &mut toasts, vec![]);
+    /// }
+    ///
+    /// let rect = ctx.memory(|m| m.area_rect(id.area_id())).unwrap();
+    /// assert_eq!(rect.max, ctx.screen_rect().max - egui::vec2(10.0, 10.0));
+    /// ```
+    ///
+    /// The anchor of older versions, which was stored without a version, is ignored:
+    /// ```
+    /// # use egui::{Align2, Direction};
+    /// # use egui_toast::{Toast, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let legacy_id = egui::Id::new("__toasts").with("dragged_anchor");
+    /// let legacy = (Align2::RIGHT_BOTTOM, egui::vec2(-10.0, -10.0), Direction::BottomUp);
+    /// ctx.data_mut(|d| d.insert_persisted(legacy_id, legacy));
+    ///
+    /// let mut toasts = Toasts::new().draggable(true);
+    /// let id = toasts.add(Toast::new().text("Hello")).id();
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// let rect = ctx.memory(|m| m.area_rect(id.area_id())).unwrap();
+    /// assert_eq!(rect.min, egui::pos2(10.0, 10.0));
+    /// let stored = ctx.data_mut(|d| d.get_persisted::<(Align2, egui::Vec2, Direction)>(legacy_id));
+    /// assert!(stored.is_none());
+    /// ```
+    #[must_use]
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
 
-        let mut toasts: Vec<Toast> = ctx.data_mut(|d| d.get_temp(id).unwrap_or_default());
-        toasts.extend(std::mem::take(&mut self.added_toasts));
-        toasts.retain(|toast| toast.options.ttl_sec > 0.0);
+    /// Allow moving the keyboard focus to the toasts.
+    ///
+    /// When enabled, pressing the [focus shortcut](Self::focus_shortcut) moves the focus
+    /// to the first visible toast. The arrow keys then move the focus between the toasts,
+    /// Enter clicks the [primary button](ToastButton::primary) of the focused toast or closes it,
+    /// and Escape (or the shortcut again) leaves the toasts.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, Toasts};
+    /// # fn press(ctx: &egui::Context, toasts: &mut Toasts, key: egui::Key) {
+    /// #     let event = egui::Event::Key {
+    /// #         key,
+    /// #         physical_key: None,
+    /// #         pressed: true,
+    /// #         repeat: false,
+    /// #         modifiers: Default::default(),
+    /// #     };
+    /// #     let input = egui::RawInput { events: vec![event], ..Default::default() };
+    /// #     let _ = ctx.run(input, |ctx| toasts.show(ctx));
+    /// # }
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new().focusable(true);
+    /// let first = toasts.add(Toast::new().text("First")).id();
+    /// let second = toasts.add(Toast::new().text("Second")).id();
+    /// for _ in 0..2 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    ///
+    /// press(&ctx, &mut toasts, egui::Key::F6);
+    /// assert_eq!(toasts.focused_toast(&ctx), Some(first));
+    /// press(&ctx, &mut toasts, egui::Key::ArrowDown);
+    /// assert_eq!(toasts.focused_toast(&ctx), Some(second));
+    ///
+    /// // The focus moves to the remaining toast when the focused one is closed
+    /// press(&ctx, &mut toasts, egui::Key::Enter);
+    /// assert_eq!(toasts.focused_toast(&ctx), Some(first));
+    /// press(&ctx, &mut toasts, egui::Key::Escape);
+    /// assert_eq!(toasts.focused_toast(&ctx), None);
+    /// ```
+    #[must_use]
+    pub fn focusable(mut self, focusable: bool) -> Self {
+        self.focusable = focusable;
+        self
+    }
 
-        for (i, toast) in toasts.iter_mut().enumerate() {
-            let response = Area::new(id.with("toast").with(i))
-                .anchor(align, offset.to_vec2())
-                .order(Order::Foreground)
-                .interactable(true)
-                .show(ctx, |ui| {
-                    if let Some(add_contents) = self.custom_toast_contents.get_mut(&toast.kind) {
-                        add_contents(ui, toast)
-                    } else {
-                        default_toast_contents(ui, toast)
-                    };
-                })
-                .response;
-
-            if !response.hovered() {
-                toast.options.ttl_sec -= dt;
-                if toast.options.ttl_sec.is_finite() {
-                    ctx.request_repaint_after(Duration::from_secs_f64(
-                        toast.options.ttl_sec.max(0.0),
-                    ));
-                }
-            }
+    /// Shortcut that moves the keyboard focus to the toasts, and back. Defaults to F6.
+    ///
+    /// Only used if [`Self::focusable`] is enabled.
+    #[must_use]
+    pub fn focus_shortcut(mut self, shortcut: KeyboardShortcut) -> Self {
+        self.focus_shortcut = shortcut;
+        self
+    }
 
-            if toast.options.show_progress {
-                ctx.request_repaint();
-            }
+    /// Screen rects of the toasts drawn by the latest [`Self::show`], including the group
+    /// headers and the [clear all button](Self::clear_all_button). Empty if no toasts are
+    /// visible.
+    ///
+    /// The rects are kept in the egui context's memory, so code that doesn't own this
+    /// instance can read them with an instance that has the same [id](Self::with_id), e.g.
+    /// to move its own floating windows out of the way.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::with_id(egui::Id::new("notifications")));
+    /// for (text, seconds) in [("Saved", 1.0), ("Copied", 2.0)] {
+    ///     let options = ToastOptions::default().duration_in_seconds(seconds);
+    ///     test.add(Toast::new().text(text).options(options));
+    /// }
+    /// test.run_frames(2);
+    ///
+    /// let elsewhere = Toasts::with_id(egui::Id::new("notifications"));
+    /// let rects = elsewhere.occupied_rects(test.ctx());
+    /// assert_eq!(rects.len(), 2);
+    /// assert_eq!(elsewhere.occupied_rect(test.ctx()), Some(rects[0].union(rects[1])));
+    ///
+    /// test.run_for(Duration::from_secs(3), 30);
+    /// assert_eq!(elsewhere.occupied_rect(test.ctx()), None);
+    /// ```
+    pub fn occupied_rects(&self, ctx: &Context) -> Vec<Rect> {
+        ctx.data(|d| d.get_temp(self.id.with("occupied")).unwrap_or_default())
+    }
 
-            match direction {
-                Direction::LeftToRight => {
-                    offset.x += response.rect.width() + 10.0;
-                }
-                Direction::RightToLeft => {
-                    offset.x -= response.rect.width() + 10.0;
-                }
-                Direction::TopDown => {
-                    offset.y += response.rect.height() + 10.0;
-                }
-                Direction::BottomUp => {
-                    offset.y -= response.rect.height() + 10.0;
-                }
-            }
-        }
+    /// Union of the [occupied rects](Self::occupied_rects), or `None` if no toasts are
+    /// visible.
+    pub fn occupied_rect(&self, ctx: &Context) -> Option<Rect> {
+        self.occupied_rects(ctx).into_iter().reduce(Rect::union)
+    }
 
-        ctx.data_mut(|d| d.insert_temp(id, toasts));
+    /// The toast that currently has the keyboard focus, see [`Self::focusable`].
+    pub fn focused_toast(&self, ctx: &Context) -> Option<ToastId> {
+        ctx.data(|d| d.get_temp(self.id.with("focus")))
     }
-}
 
-fn default_toast_contents(ui: &mut Ui, toast: &mut Toast) -> Response {
-    let inner_margin = 10.0;
-    let frame = Frame::window(ui.style());
-    let response = frame
-        .inner_margin(inner_margin)
-        .stroke(Stroke::NONE)
-        .show(ui, |ui| {
-            ui.horizontal(|ui| {
-                let a = |ui: &mut Ui, toast: &mut Toast| {
-                    if toast.options.show_icon {
-                        ui.label(match toast.kind {
-                            ToastKind::Warning => toast.style.warning_icon.clone(),
-                            ToastKind::Error => toast.style.error_icon.clone(),
-                            ToastKind::Success => toast.style.success_icon.clone(),
-                            _ => toast.style.info_icon.clone(),
-                        });
-                    }
-                };
-                let b = |ui: &mut Ui, toast: &mut Toast| ui.label(toast.text.clone());
-                let c = |ui: &mut Ui, toast: &mut Toast| {
-                    if ui.button(toast.style.close_button_text.clone()).clicked() {
-                        toast.close();
-                    }
-                };
+    /// Whether the toasts show the icon of their kind by default. Defaults to `true`.
+    ///
+    /// This applies to the toasts whose options don't call [`ToastOptions::show_icon`],
+    /// including the toasts added by code that doesn't know about the setting.
+    ///
+    /// ```
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::new().show_icons(false));
+    /// test.add(Toast::new().text("Plain"));
+    /// test.add(Toast::new().text("Iconic").options(ToastOptions::default().show_icon(true)));
+    /// test.run();
+    /// let shown: Vec<bool> = test.stored_toasts().iter().map(|t| t.options.show_icon).collect();
+    /// assert_eq!(shown, [false, true]);
+    /// ```
+    #[must_use]
+    pub fn show_icons(mut self, show_icons: bool) -> Self {
+        self.show_icons = show_icons;
+        self
+    }
 
-                // Draw the contents in the reverse order on right-to-left layouts
-                // to keep the same look.
-                if ui.layout().prefer_right_to_left() {
-                    c(ui, toast);
-                    b(ui, toast);
-                    a(ui, toast);
-                } else {
-                    a(ui, toast);
-                    b(ui, toast);
-                    c(ui, toast);
-                }
-            })
-        })
-        .response;
+    /// Whether the toasts show their remaining time by default. Defaults to `true`.
+    ///
+    /// Like [`Self::show_icons`], this applies to the toasts whose options don't call
+    /// [`ToastOptions::show_progress`].
+    #[must_use]
+    pub fn show_progress_bars(mut self, show_progress_bars: bool) -> Self {
+        self.show_progress_bars = show_progress_bars;
+        self
+    }
 
-    if toast.options.show_progress {
-        progress_bar(ui, &response, toast);
+    /// How often egui is asked to repaint while a toast that counts down shows its progress.
+    /// Defaults to zero, which repaints on every frame. E.g. `Duration::from_secs(1) / 30`
+    /// saves energy in apps that otherwise render at a low frame rate.
+    ///
+    /// The progress is worked out from the time of the frame it is painted on, so the bar is
+    /// right on every frame however far apart the frames are. No repaints are requested while
+    /// the toasts are hovered or paused.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new().progress_repaint_interval(Duration::from_millis(50));
+    /// let options = ToastOptions::default().duration_in_seconds(10.0).show_progress(true);
+    /// toasts.add(Toast::new().text("Uploading").options(options));
+    /// let mut delay = Duration::ZERO;
+    /// for frame in 0..4 {
+    ///     let input = egui::RawInput { time: Some(frame as f64 * 0.1), ..Default::default() };
+    ///     let output = ctx.run(input, |ctx| toasts.show(ctx));
+    ///     delay = output.viewport_output[&egui::ViewportId::ROOT].repaint_delay;
+    /// }
+    /// // egui subtracts the expected frame time
+    /// assert!(!delay.is_zero() && delay <= Duration::from_millis(50));
+    /// ```
+    #[must_use]
+    pub fn progress_repaint_interval(mut self, interval: Duration) -> Self {
+        self.progress_repaint_interval = interval;
+        self
     }
 
-    // Draw the frame's stroke last
-    let frame_shape = Shape::Rect(RectShape::stroke(
-        response.rect,
-        frame.rounding,
-        ui.visuals().window_stroke,
-    ));
-    ui.painter().add(frame_shape);
+    /// Minimum size of the toasts drawn with the default contents.
+    ///
+    /// Short contents are centered vertically, and the close button is placed at the
+    /// trailing edge, so toasts with the same minimum width line up neatly.
+    /// Can be overridden per toast with [`ToastOptions::min_size`].
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new().min_size((200.0, 60.0));
+    /// let short = toasts.add(Toast::new().text("Copied")).id();
+    /// let other = toasts.add(Toast::new().text("Saved to disk")).id();
+    /// let small = toasts.add(
+    ///     Toast::new()
+    ///         .text("Small")
+    ///         .options(ToastOptions::default().min_size((0.0, 0.0))),
+    /// ).id();
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// let rect = |id: egui_toast::ToastId| ctx.memory(|m| m.area_rect(id.area_id())).unwrap();
+    /// let close = |id: egui_toast::ToastId| ctx.read_response(id.close_button_id()).unwrap().rect;
+    /// assert!(rect(short).width() >= 200.0 && rect(short).height() >= 60.0);
+    /// assert_eq!(close(short).right(), close(other).right());
+    /// assert_eq!(close(short).center().y, rect(short).center().y);
+    /// assert!(rect(small).width() < 200.0);
+    /// ```
+    #[must_use]
+    pub fn min_size(mut self, min_size: impl Into<Vec2>) -> Self {
+        self.min_size = min_size.into();
+        self
+    }
 
-    response
-}
+    /// Default style of the toasts, used for toasts that don't have a [style](Toast::style)
+    /// of their own.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, ToastStyle, Toasts};
+    /// let brand = ToastStyle {
+    ///     inner_margin: 16.0,
+    ///     ..Default::default()
+    /// };
+    /// let mut toasts = Toasts::new().style(brand);
+    /// // Uses the brand style, but with its own icon
+    /// toasts.add(Toast::new().text("Uploaded").icon("⬆"));
+    /// ```
+    #[must_use]
+    pub fn style(mut self, style: ToastStyle) -> Self {
+        self.style = style;
+        self
+    }
 
-fn progress_bar(ui: &mut Ui, response: &Response, toast: &Toast) {
-    let rounding = Rounding {
-        nw: 0.0,
-        ne: 0.0,
-        ..ui.visuals().window_rounding
-    };
-    let mut clip_rect = response.rect;
-    clip_rect.set_top(clip_rect.bottom() - 2.0);
-    clip_rect.set_right(clip_rect.left() + clip_rect.width() * toast.options.progress() as f32);
-
-    ui.painter().with_clip_rect(clip_rect).rect_filled(
-        response.rect,
-        rounding,
-        ui.visuals().text_color(),
-    );
+    /// Size of the toasts, e.g. from a "notification size" accessibility setting, without
+    /// changing the [`pixels_per_point`](egui::Context::pixels_per_point) of the whole app.
+    /// Defaults to 1.
+    ///
+    /// Multiplies the text sizes, the icons, the margins, the spacing, the progress bar and
+    /// the [minimum size](Self::min_size) of the default contents. The lengths are rounded to
+    /// whole pixels, so that the outlines stay crisp. [Custom contents](Self::custom_contents)
+    /// are drawn with the scaled text sizes and spacing, and can read the scale with
+    /// [`Toast::scale`].
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, Toasts};
+    /// let width = |scale: f32| {
+    ///     let ctx = egui::Context::default();
+    ///     let mut toasts = Toasts::new().scale(scale);
+    ///     let id = toasts.add(Toast::new().text("Saved")).id();
+    ///     for _ in 0..3 {
+    ///         let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    ///     }
+    ///     ctx.memory(|m| m.area_rect(id.area_id())).unwrap().width()
+    /// };
+    /// // Up to the rounding of the text layout
+    /// assert!((width(1.5) / width(1.0) - 1.5).abs() < 0.05);
+    /// ```
+    #[must_use]
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = if scale.is_finite() && scale > 0.0 {
+            scale
+        } else {
+            1.0
+        };
+        self
+    }
+
+    /// How fast the time of the toasts runs, for looking at the animations closely while
+    /// debugging. Defaults to 1.
+    ///
+    /// The countdowns, stopwatches, fades and slides all run at the scale, so at 0.25 a toast
+    /// stays four times as long. At 0 the toasts stand still, like when they are
+    /// [paused while unfocused](Self::pause_when_unfocused). Single toasts can override it
+    /// with [`ToastOptions::time_scale`].
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::new().time_scale(0.25));
+    /// let options = ToastOptions::default().duration_in_seconds(1.0);
+    /// let slow = test.add(Toast::new().text("Slow").options(options));
+    /// let frozen = test.add(Toast::new().text("Frozen").options(options.time_scale(0.0)));
+    /// test.run_frames(2);
+    ///
+    /// test.run_for(Duration::from_secs(3), 30);
+    /// assert!(test.is_visible(slow.id()));
+    /// test.run_for(Duration::from_secs(2), 20);
+    /// assert!(!test.is_visible(slow.id()));
+    /// assert!(test.is_visible(frozen.id()));
+    /// ```
+    #[must_use]
+    pub fn time_scale(mut self, time_scale: f32) -> Self {
+        self.time_scale = time::clamp_scale(time_scale);
+        self
+    }
+
+    /// Whether closing a toast of a [sequence](Self::add_sequence) shows the next toast of
+    /// the sequence. Defaults to `true`.
+    ///
+    /// If `false`, the sequence ends when one of its toasts is dismissed before it expires,
+    /// for example because the user was not interested in the rest.
+    ///
+    /// ```
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::new().advance_sequences_on_dismiss(false));
+    /// let sequence = test.toasts().add_sequence(vec![
+    ///     Toast::new().text("Step 1"),
+    ///     Toast::new().text("Step 2"),
+    /// ]);
+    /// test.run_frames(2);
+    /// sequence.current(test.ctx()).unwrap().close(test.ctx());
+    /// test.run_frames(2);
+    /// assert!(test.stored_toasts().is_empty());
+    /// ```
+    #[must_use]
+    pub fn advance_sequences_on_dismiss(mut self, advance: bool) -> Self {
+        self.advance_sequences_on_dismiss = advance;
+        self
+    }
+
+    /// Space between the toasts in the stacking direction. Defaults to 10 points.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Replace a visible toast with a new toast of the same kind, text and group instead of
+    /// showing both. The new toast takes the place of the old one in the stack, so repeating
+    /// a message such as "Copied" restarts its countdown instead of stacking up copies.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new().deduplicate(true);
+    /// let first = toasts.add(Toast::new().text("Copied")).id();
+    /// let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// let second = toasts.add(Toast::new().text("Copied")).id();
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// assert!(ctx.read_response(first.text_id()).is_none());
+    /// assert!(ctx.read_response(second.text_id()).is_some());
+    /// ```
+    #[must_use]
+    pub fn deduplicate(mut self, deduplicate: bool) -> Self {
+        self.deduplicate = deduplicate;
+        self
+    }
+
+    /// With [`Self::deduplicate`], also drop a toast whose message was added less than `window`
+    /// ago, even if the earlier toast is no longer visible. The dropped toast is dismissed with
+    /// [`DismissReason::Rejected`]. This throttles messages that are repeated more often than
+    /// they expire, e.g. an error reported by every failed request.
+    ///
+    /// Only a hash of each message is kept in the egui context's memory, and the entries older
+    /// than the window are pruned in [`Self::show`], or with [`Self::prune_now`].
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// # fn run(ctx: &egui::Context, toasts: &mut Toasts, time: f64) {
+    /// #     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    /// #     let _ = ctx.run(input, |ctx| toasts.show(ctx));
+    /// # }
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new()
+    ///     .deduplicate(true)
+    ///     .dedupe_window(Duration::from_secs(5));
+    /// let toast = || Toast::new()
+    ///     .text("Connection lost")
+    ///     .options(ToastOptions::default().duration_in_seconds(1.0));
+    /// toasts.add(toast());
+    /// for i in 0..20 {
+    ///     run(&ctx, &mut toasts, i as f64 * 0.1);
+    /// }
+    /// let throttled = toasts.add(toast()).id();
+    /// run(&ctx, &mut toasts, 2.1);
+    /// assert!(ctx.memory(|m| m.area_rect(throttled.area_id())).is_none());
+    ///
+    /// let repeated = toasts.add(toast()).id();
+    /// run(&ctx, &mut toasts, 6.0);
+    /// assert!(ctx.memory(|m| m.area_rect(repeated.area_id())).is_some());
+    /// ```
+    #[must_use]
+    pub fn dedupe_window(mut self, window: Duration) -> Self {
+        self.dedupe_window = window;
+        self
+    }
+
+    /// With [`Self::deduplicate`], escalate a toast that is repeated more often than the
+    /// threshold of the policy within its window, e.g. a warning that keeps coming back. The
+    /// escalated toast changes its kind, can become sticky, and gets [details](Toast::details)
+    /// that list the times it was added, with the clock of the [time source](Self::time_source).
+    ///
+    /// The escalated toast keeps its id, so that its handles stay valid, and the toasts that
+    /// repeat it from then on are merged into it and dismissed with [`DismissReason::Merged`].
+    /// Toasts that already have the kind of the policy don't escalate.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{EscalationPolicy, Toast, ToastKind, ToastOptions, Toasts};
+    /// let policy = EscalationPolicy { threshold: 2, ..Default::default() };
+    /// let mut test = ToastTestCtx::new(Toasts::new().deduplicate(true).escalation(policy));
+    /// let warning = || {
+    ///     Toast::new()
+    ///         .kind(ToastKind::Warning)
+    ///         .text("Disk almost full")
+    ///         .options(ToastOptions::default().duration_in_seconds(5.0))
+    /// };
+    /// let mut handles = Vec::new();
+    /// for _ in 0..3 {
+    ///     handles.push(test.add(warning()));
+    ///     test.run_for(Duration::from_secs(1), 2);
+    /// }
+    /// let [stored] = test.stored_toasts().try_into().unwrap();
+    /// assert_eq!(stored.kind, ToastKind::Error);
+    /// assert_eq!(stored.id(), Some(handles[1].id()));
+    /// assert_eq!(stored.options.initial_duration(), None);
+    /// assert_eq!(stored.details.unwrap().text(), "00:00\n00:01\n00:02");
+    /// ```
+    #[must_use]
+    pub fn escalation(mut self, policy: EscalationPolicy) -> Self {
+        self.escalation = Some(policy);
+        self
+    }
+
+    /// Forget the messages added longer than the [dedupe window](Self::dedupe_window) ago.
+    ///
+    /// This is done in [`Self::show`] as well, so this is only needed if the toasts
+    /// are not shown for a while.
+    pub fn prune_now(&self, ctx: &Context) {
+        let mut recent = RecentMessages::load(ctx, self.id);
+        recent.prune(self.time_source.now(ctx), self.dedupe_window.as_secs_f64());
+        recent.store(ctx, self.id);
+    }
+
+    /// Keep at most `max` toasts, counting both the visible toasts and the toasts that wait
+    /// to be shown, e.g. in a [`ToastQueue`]. What happens to the toasts over the capacity
+    /// depends on the [`Self::capacity_policy`].
+    ///
+    /// This bounds the memory used by the toasts when something adds them faster than they
+    /// expire. Use [`Self::try_add`] to know whether a toast was dropped.
+    ///
+    /// ```
+    /// # use egui_toast::{AddResult, Toast, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new().capacity(2);
+    /// assert!(toasts.try_add(&ctx, Toast::new().text("First")).handle().is_some());
+    /// assert!(toasts.try_add(&ctx, Toast::new().text("Second")).handle().is_some());
+    /// assert_eq!(toasts.try_add(&ctx, Toast::new().text("Third")), AddResult::Dropped);
+    /// # let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// ```
+    ///
+    /// Whatever the capacity, at most 200 toasts are kept. The toasts over that are dropped
+    /// when they are shown and reported with a single [`ToastStrings::suppressed_toasts`]
+    /// toast, so that a bug adding thousands of toasts does not hang the app, see the `flood`
+    /// benchmark.
+    ///
+    /// ```
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::new());
+    /// for i in 0..10_000 {
+    ///     test.add(Toast::new().text(format!("Log line {i}")));
+    /// }
+    /// test.run_frames(2);
+    ///
+    /// let stored = test.stored_toasts();
+    /// assert_eq!(stored.len(), 201);
+    /// assert_eq!(stored[200].plain_text(), "9800 notifications suppressed");
+    /// ```
+    #[must_use]
+    pub fn capacity(mut self, max: usize) -> Self {
+        self.capacity = Some(max);
+        self
+    }
+
+    /// What happens to a new toast when the [capacity](Self::capacity) is reached.
+    /// By default, the new toast is dropped.
+    ///
+    /// With [`CapacityPolicy::DropOldest`], the new toasts are always added, and the oldest
+    /// toasts are dismissed when the new ones are shown.
+    ///
+    /// ```
+    /// # use egui_toast::{CapacityPolicy, DismissReason, Toast, Toasts};
+    /// # use std::sync::{Arc, Mutex};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new()
+    ///     .capacity(1)
+    ///     .capacity_policy(CapacityPolicy::DropOldest);
+    /// let reason = Arc::new(Mutex::new(None));
+    /// let dismissed = reason.clone();
+    /// toasts.add(Toast::new().text("Old").on_dismiss(move |_, reason| {
+    ///     *dismissed.lock().unwrap() = Some(reason);
+    /// }));
+    /// let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    ///
+    /// assert!(toasts.try_add(&ctx, Toast::new().text("New")).handle().is_some());
+    /// let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// assert_eq!(*reason.lock().unwrap(), Some(DismissReason::Evicted));
+    /// ```
+    #[must_use]
+    pub fn capacity_policy(mut self, policy: CapacityPolicy) -> Self {
+        self.capacity_policy = policy;
+        self
+    }
+
+    /// Show a single warning toast with the number of toasts dropped because the
+    /// [capacity](Self::capacity) was reached, e.g. "Too many notifications, 37 dropped".
+    ///
+    /// The toast is updated while more toasts are dropped, and it does not count towards the
+    /// capacity. Its text is set with [`ToastStrings::dropped_toasts`].
+    ///
+    /// ```
+    /// # use std::sync::{Arc, Mutex};
+    /// # use egui_toast::{Toast, ToastKind, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let shown = Arc::new(Mutex::new(Vec::new()));
+    /// let texts = shown.clone();
+    /// let mut toasts = Toasts::new()
+    ///     .capacity(1)
+    ///     .report_dropped(true)
+    ///     .custom_contents(ToastKind::Warning, move |ui, toast| {
+    ///         texts.lock().unwrap().push(toast.plain_text());
+    ///         ui.label(toast.text.clone())
+    ///     });
+    /// for i in 0..4 {
+    ///     toasts.add(Toast::new().text(format!("Toast {i}")));
+    /// }
+    /// let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// assert_eq!(shown.lock().unwrap().as_slice(), ["Too many notifications, 3 dropped"]);
+    /// ```
+    #[must_use]
+    pub fn report_dropped(mut self, report: bool) -> Self {
+        self.report_dropped = report;
+        self
+    }
+
+    /// Show a "Clear all (12)" button at the anchor end of the stack when more than `threshold`
+    /// toasts are visible. The button takes a slot in the stack like a toast.
+    ///
+    /// Clicking the button dismisses every toast that expires with [`DismissReason::ClearedAll`].
+    /// Toasts without a duration are kept. The label is set with [`ToastStrings::clear_all`].
+    ///
+    /// ```
+    /// # use std::sync::{Arc, Mutex};
+    /// # use egui_toast::{DismissReason, Toast, ToastOptions, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new().clear_all_button(2);
+    /// let reasons = Arc::new(Mutex::new(Vec::new()));
+    /// let toast = |text| {
+    ///     let reasons = reasons.clone();
+    ///     Toast::new()
+    ///         .text(text)
+    ///         .options(ToastOptions::default().duration_in_seconds(10.0))
+    ///         .on_dismiss(move |_, reason| reasons.lock().unwrap().push(reason))
+    /// };
+    /// let first = toasts.add(toast("First")).id();
+    /// toasts.add(toast("Second"));
+    /// toasts.add(toast("Third"));
+    /// toasts.add(Toast::new().text("Sticky"));
+    /// let mut run = |events: Vec<egui::Event>| {
+    ///     let input = egui::RawInput { events, ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| toasts.show(ctx));
+    /// };
+    /// for _ in 0..3 {
+    ///     run(vec![]);
+    /// }
+    /// let button = ctx.memory(|m| m.area_rect(egui::Id::new("__toasts").with("clear_all")));
+    /// let button = button.unwrap();
+    /// let first = ctx.memory(|m| m.area_rect(first.area_id())).unwrap();
+    /// assert!(button.bottom() < first.top());
+    ///
+    /// let pos = button.center();
+    /// run(vec![egui::Event::PointerMoved(pos)]);
+    /// for pressed in [true, false] {
+    ///     run(vec![egui::Event::PointerButton {
+    ///         pos,
+    ///         button: egui::PointerButton::Primary,
+    ///         pressed,
+    ///         modifiers: Default::default(),
+    ///     }]);
+    /// }
+    /// run(vec![]);
+    /// assert_eq!(*reasons.lock().unwrap(), [DismissReason::ClearedAll; 3]);
+    /// ```
+    #[must_use]
+    pub fn clear_all_button(mut self, threshold: usize) -> Self {
+        self.clear_all_threshold = Some(threshold);
+        self
+    }
+
+    /// Show a "12 suppressed" badge at the anchor end of the stack while toasts were rejected
+    /// by [`Toasts::on_add`], the [dedupe window](Self::dedupe_window) or the
+    /// [capacity](Self::capacity). Hovering the badge shows the counts per kind, and clicking
+    /// it resets the counts and shows the [retained](Self::retain_suppressed) toasts.
+    ///
+    /// The label is set with [`ToastStrings::suppressed_badge`].
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, ToastKind, Toasts};
+    /// let toasts = Toasts::new()
+    ///     .capacity(1)
+    ///     .suppressed_badge(true)
+    ///     .retain_suppressed(1);
+    /// let mut test = ToastTestCtx::new(toasts);
+    /// test.add(Toast::new().text("Saved"));
+    /// test.add(Toast::new().kind(ToastKind::Error).text("Upload failed"));
+    /// test.add(Toast::new().kind(ToastKind::Error).text("Upload failed again"));
+    /// test.run_frames(3);
+    /// let ctx = test.ctx().clone();
+    /// let counts = test.toasts().suppressed_counts(&ctx);
+    /// assert_eq!(counts, HashMap::from([(ToastKind::Error, 2)]));
+    ///
+    /// let badge = egui::Id::new("__toasts").with("suppressed_badge");
+    /// let badge = ctx.memory(|m| m.area_rect(badge)).unwrap();
+    /// test.click(badge.center());
+    /// test.run_frames(2);
+    /// assert!(test.toasts().suppressed_counts(&ctx).is_empty());
+    /// let texts: Vec<_> = test.stored_toasts().iter().map(|t| t.plain_text()).collect();
+    /// assert_eq!(texts, ["Saved", "Upload failed again"]);
+    /// ```
+    #[must_use]
+    pub fn suppressed_badge(mut self, show: bool) -> Self {
+        self.suppressed_badge = show;
+        self
+    }
+
+    /// Collapse the toasts to small circles with the icon of their kind when more than
+    /// `threshold` toasts are stored. The circles are stacked tightly, with a ring that shows
+    /// the progress of the toast, and the toasts keep counting down.
+    ///
+    /// Resting the pointer over the stack expands all toasts until the pointer leaves it,
+    /// and clicking a circle shows that toast in full until the number of toasts drops to
+    /// the threshold. [Grouped](Toast::group) toasts are not collapsed.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::new().compact_when_over(2));
+    /// let handles: Vec<_> = (0..3)
+    ///     .map(|i| test.add(Toast::new().text(format!("Build {i} finished"))))
+    ///     .collect();
+    /// test.run_frames(2);
+    /// let width = |test: &ToastTestCtx, i: usize| test.rect(handles[i].id()).unwrap().width();
+    /// assert_eq!(width(&test, 0), 32.0);
+    ///
+    /// // Clicking a circle shows only that toast in full
+    /// let circle = test.rect(handles[1].id()).unwrap();
+    /// test.click(circle.center());
+    /// test.run_frames(2);
+    /// assert!(width(&test, 1) > 32.0);
+    /// assert_eq!(width(&test, 0), 32.0);
+    ///
+    /// // Resting the pointer over the stack expands all toasts
+    /// test.run_for(Duration::from_secs(1), 10);
+    /// assert!(width(&test, 0) > 32.0);
+    /// test.leave();
+    /// test.run_for(Duration::from_secs(1), 10);
+    /// assert_eq!(width(&test, 0), 32.0);
+    /// ```
+    #[must_use]
+    pub fn compact_when_over(mut self, threshold: usize) -> Self {
+        self.compact_threshold = Some(threshold);
+        self
+    }
+
+    /// Keep the latest `max` toasts counted by the [suppressed badge](Self::suppressed_badge),
+    /// to be shown when the badge is clicked. Defaults to 0, so the badge only counts them.
+    #[must_use]
+    pub fn retain_suppressed(mut self, max: usize) -> Self {
+        self.retain_suppressed = max;
+        self
+    }
+
+    /// The number of toasts of each kind counted by the
+    /// [suppressed badge](Self::suppressed_badge) since it was last clicked.
+    pub fn suppressed_counts(&self, ctx: &Context) -> HashMap<ToastKind, usize> {
+        SuppressedToasts::load(ctx, self.id).counts
+    }
+
+    /// Dismiss the toasts with [`DismissReason::ClickedOutside`] when the user clicks anywhere
+    /// outside them, like a dropdown menu. Toasts that never expire are kept. The click is not
+    /// consumed, so the widget under the pointer still gets it.
+    ///
+    /// ```
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::new().dismiss_on_click_outside(true));
+    /// let options = ToastOptions::default().duration_in_seconds(5.0);
+    /// let saved = test.add(Toast::new().text("Saved").options(options));
+    /// let sticky = test.add(Toast::new().text("Update available"));
+    /// test.run_frames(2);
+    ///
+    /// // A click on a toast keeps it
+    /// test.click(test.rect(saved.id()).unwrap().center());
+    /// test.run_frames(2);
+    /// assert!(test.is_visible(saved.id()));
+    ///
+    /// let mut clicked = false;
+    /// let mut app = |ctx: &egui::Context| {
+    ///     egui::CentralPanel::default().show(ctx, |ui| {
+    ///         let rect = egui::Rect::from_center_size(egui::pos2(400.0, 400.0), egui::vec2(50.0, 20.0));
+    ///         clicked |= ui.put(rect, egui::Button::new("Background")).clicked();
+    ///     });
+    /// };
+    /// test.run_with(&mut app);
+    /// test.click(egui::pos2(400.0, 400.0));
+    /// test.run_with(&mut app);
+    /// test.run_frames(2);
+    /// assert!(clicked);
+    /// assert!(!test.is_visible(saved.id()));
+    /// assert!(test.is_visible(sticky.id()));
+    /// ```
+    #[must_use]
+    pub fn dismiss_on_click_outside(mut self, dismiss_on_click_outside: bool) -> Self {
+        self.dismiss_on_click_outside = dismiss_on_click_outside;
+        self
+    }
+
+    /// Open a context menu when a toast drawn with the default contents is right-clicked.
+    /// Defaults to `false`, and single toasts can override it with
+    /// [`ToastOptions::context_menu`].
+    ///
+    /// The menu copies the text of the toast, opens its [details](Toast::details), dismisses
+    /// it like the close button does, or dismisses all toasts of its kind with
+    /// [`DismissReason::KindCleared`]. [`Toast::context_menu_extra`] adds entries to it, and
+    /// the entries are labeled with the [strings](ToastStrings::copy_text) of the style.
+    #[must_use]
+    pub fn context_menu(mut self, context_menu: bool) -> Self {
+        self.context_menu = context_menu;
+        self
+    }
+
+    /// Dismiss the toasts when the user goes on with their work, e.g. the "Saved" toasts of
+    /// a text editor once the user types again. Only the toasts that
+    /// [opt in](ToastOptions::dismiss_on_input) are dismissed, by default the info and
+    /// success toasts. They are shown for a short grace period after the input, fading out,
+    /// and are then dismissed with [`DismissReason::Expired`]. Toasts that never expire are
+    /// kept.
+    ///
+    /// Input over the toasts does not count, nor does keyboard input while the toasts have
+    /// the [focus](Self::focusable).
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{DismissOnInput, Toast, ToastKind, ToastOptions, Toasts};
+    /// let input = DismissOnInput { keyboard: true, ..Default::default() };
+    /// let mut test = ToastTestCtx::new(Toasts::new().dismiss_on_input(input));
+    /// let options = ToastOptions::default().duration_in_seconds(10.0);
+    /// let saved = test.add(Toast::new().text("Saved").options(options));
+    /// let failed = test.add(Toast::new().kind(ToastKind::Error).text("Sync failed").options(options));
+    /// test.run_frames(2);
+    ///
+    /// test.press_key(egui::Key::A);
+    /// test.run();
+    /// assert!(test.is_visible(saved.id()));
+    /// test.run_for(Duration::from_secs(1), 10);
+    /// assert!(!test.is_visible(saved.id()));
+    /// assert!(test.is_visible(failed.id()));
+    /// ```
+    #[must_use]
+    pub fn dismiss_on_input(mut self, dismiss_on_input: DismissOnInput) -> Self {
+        self.dismiss_on_input = dismiss_on_input;
+        self
+    }
+
+    /// Whether a primary click happened on this frame outside the toasts drawn on the
+    /// previous frame.
+    fn clicked_outside(&self, ctx: &Context) -> bool {
+        let pos = ctx.input(|i| {
+            let pointer = &i.pointer;
+            pointer
+                .primary_clicked()
+                .then(|| pointer.interact_pos())
+                .flatten()
+        });
+        pos.is_some_and(|pos| {
+            !self
+                .occupied_rects(ctx)
+                .iter()
+                .any(|rect| rect.contains(pos))
+        })
+    }
+
+    /// Show only one toast at a time, like snackbars.
+    ///
+    /// A new toast replaces the visible one: the visible toast fades out and is dismissed
+    /// with [`DismissReason::Superseded`], and then the new toast fades in. If several toasts
+    /// are added in the meantime, only the newest one is kept. [Groups](Toast::group) are not
+    /// formed in this mode.
+    ///
+    /// ```
+    /// # use std::sync::{Arc, Mutex};
+    /// # use egui_toast::{DismissReason, Toast, ToastButton, Toasts};
+    /// # fn run(ctx: &egui::Context, toasts: &mut Toasts, time: f64) {
+    /// #     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    /// #     let _ = ctx.run(input, |ctx| toasts.show(ctx));
+    /// # }
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new().single_slot(true);
+    /// let reason = Arc::new(Mutex::new(None));
+    /// let deleted = toasts.add(
+    ///     Toast::new()
+    ///         .text("Message deleted")
+    ///         .button(ToastButton::new("Undo").primary(true))
+    ///         .on_dismiss({
+    ///             let reason = reason.clone();
+    ///             move |_, r| *reason.lock().unwrap() = Some(r)
+    ///         }),
+    /// ).id();
+    /// for i in 0..3 {
+    ///     run(&ctx, &mut toasts, i as f64 * 0.1);
+    /// }
+    /// let archived = toasts.add(Toast::new().text("Message archived")).id();
+    /// let moved = toasts.add(Toast::new().text("Message moved")).id();
+    /// for i in 3..10 {
+    ///     run(&ctx, &mut toasts, i as f64 * 0.1);
+    /// }
+    /// assert_eq!(*reason.lock().unwrap(), Some(DismissReason::Superseded));
+    /// assert!(ctx.read_response(deleted.text_id()).is_none());
+    /// assert!(ctx.read_response(archived.text_id()).is_none());
+    /// assert!(ctx.read_response(moved.text_id()).is_some());
+    /// ```
+    #[must_use]
+    pub fn single_slot(mut self, single_slot: bool) -> Self {
+        self.single_slot = single_slot;
+        self
+    }
+
+    /// Turn off the animations that move the toasts, for users who are sensitive to motion.
+    ///
+    /// The toasts jump to their new positions instead of sliding there, and toasts that
+    /// appear or disappear only fade. egui does not know about the motion preference of the
+    /// operating system, so it has to be queried by the app, e.g. from
+    /// `prefers-reduced-motion` on the web. Motion is also reduced if the egui animations
+    /// are turned off with an [`animation_time`](egui::Style::animation_time) of zero.
+    ///
+    /// ```
+    /// # use egui_toast::{Align2, Toast, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new().reduce_motion(true);
+    /// let id = toasts.add(Toast::new().text("Hello")).id();
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// toasts.set_anchor(&ctx, Align2::RIGHT_BOTTOM, (-10.0, -10.0));
+    /// let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// let rect = ctx.memory(|m| m.area_rect(id.area_id())).unwrap();
+    /// assert_eq!(rect.max, ctx.screen_rect().max - egui::vec2(10.0, 10.0));
+    /// ```
+    #[must_use]
+    pub fn reduce_motion(mut self, reduce_motion: bool) -> Self {
+        self.reduce_motion = reduce_motion;
+        self
+    }
+
+    /// Skip the toasts that are stacked entirely outside the screen. Their contents are not
+    /// run, so a long stack does not lay out toasts that can't be seen. The skipped toasts
+    /// keep counting down and keep their sizes, so they are in place when they come back
+    /// into view. Enabled by default.
+    ///
+    /// Disable this if the [custom contents](Self::custom_contents) have side effects that
+    /// should happen on every frame.
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use std::sync::{Arc, Mutex};
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, ToastKind, Toasts};
+    /// let calls = Arc::new(Mutex::new(HashMap::new()));
+    /// let counter = calls.clone();
+    /// let toasts = Toasts::new().custom_contents(ToastKind::Info, move |ui, toast| {
+    ///     *counter.lock().unwrap().entry(toast.plain_text()).or_insert(0) += 1;
+    ///     ui.allocate_response(egui::vec2(200.0, 3000.0), egui::Sense::hover())
+    /// });
+    /// let mut test = ToastTestCtx::new(toasts);
+    /// for i in 0..10 {
+    ///     test.add(Toast::new().text(format!("Toast {i}")));
+    /// }
+    /// test.run_frames(3);
+    /// let calls = calls.lock().unwrap();
+    /// assert_eq!(calls["Toast 0"], 3);
+    /// // Only measured on the first frame
+    /// assert_eq!(calls["Toast 9"], 1);
+    /// ```
+    #[must_use]
+    pub fn cull_offscreen(mut self, cull_offscreen: bool) -> Self {
+        self.cull_offscreen = cull_offscreen;
+        self
+    }
+
+    /// The layer order of the toasts. Defaults to [`Order::Foreground`], above all windows.
+    #[must_use]
+    pub fn order(mut self, order: Order) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Show the toasts below egui windows, so that the windows can be moved over them.
+    ///
+    /// The toasts are still drawn above panels and the rest of the background. This sets the
+    /// [order](Self::order) to [`Order::Background`], since toasts in [`Order::Middle`] would be
+    /// raised above the windows when they appear or are clicked.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new().behind_windows(true);
+    /// let handle = toasts.add(Toast::new().text("Ambient status"));
+    /// let mut run = |events| {
+    ///     let input = egui::RawInput { events, ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |_| {});
+    ///         egui::Window::new("Tools")
+    ///             .fixed_pos((0.0, 0.0))
+    ///             .show(ctx, |ui| ui.allocate_space(egui::vec2(200.0, 200.0)));
+    ///         toasts.show(ctx);
+    ///     });
+    /// };
+    /// run(vec![]);
+    /// run(vec![]);
+    ///
+    /// let pos = ctx.memory(|m| m.area_rect(handle.id().area_id())).unwrap().center();
+    /// run(vec![egui::Event::PointerMoved(pos)]);
+    /// run(vec![]);
+    /// let window = egui::LayerId::new(egui::Order::Middle, egui::Id::new("Tools"));
+    /// assert_eq!(ctx.layer_id_at(pos), Some(window));
+    /// assert!(!handle.is_hovered(&ctx));
+    /// ```
+    #[must_use]
+    pub fn behind_windows(self, behind_windows: bool) -> Self {
+        self.order(if behind_windows {
+            Order::Background
+        } else {
+            Order::Foreground
+        })
+    }
+
+    /// Show every added toast for at least this long, even if its duration is shorter or zero.
+    ///
+    /// Without this, a toast that is added with a zero duration is never shown.
+    /// Closing a toast still removes it immediately.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// # fn run(ctx: &egui::Context, toasts: &mut Toasts, time: f64) {
+    /// #     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    /// #     let _ = ctx.run(input, |ctx| toasts.show(ctx));
+    /// # }
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new().min_display_time(Duration::from_secs(1));
+    /// let options = ToastOptions::default().duration_in_seconds(-5.0);
+    /// let id = toasts.add(Toast::new().text("Deadline passed").options(options)).id();
+    /// for i in 0..5 {
+    ///     run(&ctx, &mut toasts, i as f64 * 0.1);
+    /// }
+    /// assert!(ctx.read_response(id.text_id()).is_some());
+    /// for i in 5..15 {
+    ///     run(&ctx, &mut toasts, i as f64 * 0.1);
+    /// }
+    /// assert!(ctx.read_response(id.text_id()).is_none());
+    /// ```
+    #[must_use]
+    pub fn min_display_time(mut self, min_display_time: Duration) -> Self {
+        self.min_display_time = min_display_time;
+        self
+    }
+
+    /// Clock the toasts expire by. Defaults to [`EguiTime`], the time of the egui input.
+    ///
+    /// The time source also times the [dedupe window](Self::dedupe_window), how long the
+    /// layout transitions and the [compact](Self::compact_when_over) stack keep animating, the
+    /// [update blip](ToastStyle::update_blip) and the fades of the
+    /// [single slot](Self::single_slot). The slides and the other fades are eased by egui's
+    /// `animate_*` helpers, which follow the egui time. Use a [`MockTime`] to test the timing
+    /// of the toasts.
+    #[must_use]
+    pub fn time_source(mut self, time_source: impl TimeSource + 'static) -> Self {
+        self.time_source = Arc::new(time_source);
+        self
+    }
+
+    /// Stop the countdown of the toasts while the app window is unfocused or minimized,
+    /// so that the toasts don't expire while the user is away. The toasts are still drawn.
+    ///
+    /// The time between the last unfocused frame and the first focused frame is not counted
+    /// either, even if no frames were drawn in the meantime.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// # fn run(ctx: &egui::Context, toasts: &mut Toasts, time: f64, focused: bool) {
+    /// #     let input = egui::RawInput { time: Some(time), focused, ..Default::default() };
+    /// #     let _ = ctx.run(input, |ctx| toasts.show(ctx));
+    /// # }
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new().pause_when_unfocused(true);
+    /// let options = ToastOptions::default().duration_in_seconds(1.0);
+    /// let id = toasts.add(Toast::new().text("Build finished").options(options)).id();
+    /// for i in 0..10 {
+    ///     run(&ctx, &mut toasts, i as f64 * 0.5, false);
+    /// }
+    /// run(&ctx, &mut toasts, 60.0, true);
+    /// run(&ctx, &mut toasts, 60.1, true);
+    /// assert!(ctx.read_response(id.text_id()).is_some());
+    /// ```
+    #[must_use]
+    pub fn pause_when_unfocused(mut self, pause_when_unfocused: bool) -> Self {
+        self.pause_when_unfocused = pause_when_unfocused;
+        self
+    }
+
+    /// Whether the countdown should be stopped on this frame because the window is unfocused
+    /// or minimized, or was on the previous frame.
+    fn update_unfocused(&self, ctx: &Context) -> bool {
+        let unfocused = ctx.input(|i| !i.focused || i.viewport().minimized.unwrap_or_default());
+        let unfocused_id = self.id.with("unfocused");
+        let was_unfocused = ctx.data_mut(|d| {
+            let was_unfocused = d.get_temp(unfocused_id).unwrap_or_default();
+            d.insert_temp(unfocused_id, unfocused);
+            was_unfocused
+        });
+        unfocused || was_unfocused
+    }
+
+    /// Whether hovering a toast stops its countdown. Defaults to `true`.
+    ///
+    /// Turning this off can be useful on touch screens, where a finger resting on a toast
+    /// would otherwise keep it open. [`Toast::is_hovered`] still reports the hover.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::new().hover_pauses(false));
+    /// let options = ToastOptions::default().duration_in_seconds(1.0);
+    /// let handle = test.add(Toast::new().text("Saved").options(options));
+    /// test.run_frames(2);
+    /// assert!(test.hover_toast(handle.id()));
+    /// test.run_for(Duration::from_millis(200), 2);
+    /// assert!(handle.is_hovered(test.ctx()) && !handle.is_paused(test.ctx()));
+    /// test.run_for(Duration::from_secs(2), 20);
+    /// assert!(!test.is_visible(handle.id()));
+    /// ```
+    #[must_use]
+    pub fn hover_pauses(mut self, hover_pauses: bool) -> Self {
+        self.hover_pauses = hover_pauses;
+        self
+    }
+
+    /// Also pause the countdown while the pointer is within `margin` points of a toast, so
+    /// that a toast does not vanish while the pointer rests beside it.
+    ///
+    /// Only the pausing uses the margin: [`Toast::is_hovered`] and clicks still use the frame
+    /// of the toast, so the margin does not block the widgets around the toasts. The margin
+    /// is not used while the pointer is over another toast.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::new().hover_margin(20.0));
+    /// let options = ToastOptions::default().duration_in_seconds(1.0);
+    /// let handle = test.add(Toast::new().text("Saved").options(options));
+    /// test.run_frames(2);
+    /// let rect = test.rect(handle.id()).unwrap();
+    /// test.hover(egui::pos2(rect.right() + 10.0, rect.center().y));
+    /// test.run_for(Duration::from_secs(2), 20);
+    /// assert!(!handle.is_hovered(test.ctx()) && handle.is_paused(test.ctx()));
+    /// assert!(test.is_visible(handle.id()));
+    /// ```
+    #[must_use]
+    pub fn hover_margin(mut self, margin: f32) -> Self {
+        self.hover_margin = margin;
+        self
+    }
+
+    /// Show at most `max` toasts of the given kind at a time. When a new toast exceeds the
+    /// limit, the oldest toasts of the kind are removed according to [`Self::kind_limit_policy`].
+    ///
+    /// ```
+    /// # use egui_toast::{KindLimitPolicy, Toast, ToastKind, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new()
+    ///     .max_visible_per_kind(ToastKind::Success, 1)
+    ///     .kind_limit_policy(KindLimitPolicy::Merge);
+    /// let first = toasts.add(Toast::new().kind(ToastKind::Success).text("Saved")).id();
+    /// let second = toasts.add(Toast::new().kind(ToastKind::Success).text("Saved")).id();
+    /// let error = toasts.add(Toast::new().kind(ToastKind::Error).text("Failed")).id();
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// assert!(ctx.memory(|m| m.area_rect(first.area_id())).is_none());
+    /// assert!(ctx.read_response(second.text_id()).is_some());
+    /// assert!(ctx.read_response(error.text_id()).is_some());
+    /// ```
+    #[must_use]
+    pub fn max_visible_per_kind(mut self, kind: impl Into<ToastKind>, max: usize) -> Self {
+        self.max_visible_per_kind.insert(kind.into(), max);
+        self
+    }
+
+    /// What happens to the oldest toasts of a kind that exceed [`Self::max_visible_per_kind`].
+    /// Defaults to [`KindLimitPolicy::Evict`].
+    #[must_use]
+    pub fn kind_limit_policy(mut self, policy: KindLimitPolicy) -> Self {
+        self.kind_limit_policy = policy;
+        self
+    }
+
+    /// Id of the [`egui::Area`] the [group](Toast::group) with the given key is drawn in.
+    pub fn group_id(&self, key: &str) -> Id {
+        self.id.with("group").with(key)
+    }
+
+    /// Whether the [group](Toast::group) with the given key is expanded to show all of its toasts.
+    pub fn is_group_expanded(&self, ctx: &Context, key: &str) -> bool {
+        ctx.data(|d| d.get_temp::<GroupState>(self.group_id(key)))
+            .is_some_and(|state| state.expanded)
+    }
+
+    /// Collapse the older toasts of a kind into a single summary toast like "5 more warnings"
+    /// when there are more than `threshold` toasts of that kind.
+    ///
+    /// The newest toast of the kind stays visible. The collapsed toasts do not expire while they
+    /// are hidden, but they can be revealed by expanding the summary toast. Toasts in an explicit
+    /// [group](Toast::group) are not affected.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, ToastKind, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new().auto_group(2);
+    /// let warnings: Vec<_> = (0..4)
+    ///     .map(|i| toasts.add(Toast::new().kind(ToastKind::Warning).text(format!("Warning {i}"))).id())
+    ///     .collect();
+    /// let info = toasts.add(Toast::new().text("Info")).id();
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// let shown = |id: egui_toast::ToastId| ctx.read_response(id.text_id()).is_some();
+    /// assert!(!shown(warnings[0]) && !shown(warnings[2]));
+    /// assert!(shown(warnings[3]) && shown(info));
+    /// assert!(ctx.memory(|m| m.area_rect(toasts.auto_group_id(ToastKind::Warning))).is_some());
+    /// ```
+    #[must_use]
+    pub fn auto_group(mut self, threshold: usize) -> Self {
+        self.auto_group = Some(threshold);
+        self
+    }
+
+    /// Id of the [`egui::Area`] the summary of the [automatically grouped](Self::auto_group)
+    /// toasts of the given kind is drawn in.
+    pub fn auto_group_id(&self, kind: ToastKind) -> Id {
+        self.id.with("auto_group").with(kind)
+    }
+
+    fn group_key_id(&self, key: &GroupKey) -> Id {
+        match key {
+            GroupKey::Explicit(key) => self.group_id(key),
+            GroupKey::Auto(kind) => self.auto_group_id(*kind),
+        }
+    }
+
+    /// Expand or collapse the [group](Toast::group) with the given key.
+    ///
+    /// This is stored in the egui context's memory, just like toggling the group with its button.
+    pub fn set_group_expanded(&self, ctx: &Context, key: &str, expanded: bool) {
+        ctx.data_mut(|d| {
+            d.get_temp_mut_or_default::<GroupState>(self.group_id(key))
+                .expanded = expanded;
+        });
+    }
+
+    /// Dismiss all toasts in the given [scope](Toast::scope) with [`DismissReason::ScopeCleared`],
+    /// including the toasts that were added or [queued](Self::queue) but not shown yet.
+    /// Toasts without a scope are not affected.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new().deduplicate(true);
+    /// let first_tab = toasts.add(Toast::new().text("Missing title").scope("tab 1")).id();
+    /// let second_tab = toasts.add(Toast::new().text("Missing title").scope("tab 2")).id();
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// let queued = toasts.queue().add(&ctx, Toast::new().text("Saved").scope("tab 1")).id();
+    ///
+    /// // The first tab was closed
+    /// toasts.clear_scope(&ctx, "tab 1");
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// let rect = |id: egui_toast::ToastId| ctx.memory(|m| m.area_rect(id.area_id()));
+    /// assert!(rect(second_tab).is_some());
+    /// assert!(rect(queued).is_none());
+    /// assert!(ctx.read_response(first_tab.text_id()).is_none());
+    /// ```
+    pub fn clear_scope(&mut self, ctx: &Context, scope: impl Into<Id>) {
+        let scope = Some(scope.into());
+        ctx.data_mut(|d| {
+            let toasts = d.get_temp_mut_or_default::<Vec<StoredToast>>(self.id);
+            for stored in toasts.iter_mut().filter(|s| s.toast.scope == scope) {
+                stored
+                    .toast
+                    .options
+                    .dismiss_in_bulk(DismissReason::ScopeCleared);
+            }
+        });
+        // The toasts that were not shown yet are dismissed right away
+        let mut queued = queue::take(ctx, self.id);
+        for toasts in [&mut self.added_toasts, &mut queued] {
+            toasts.retain(|toast| {
+                if toast.scope != scope {
+                    return true;
+                }
+                toast.notify_dismissed(DismissReason::ScopeCleared);
+                false
+            });
+        }
+        queue::restore(ctx, self.id, queued);
+    }
+
+    /// Show the toasts of `kind` in another lane, unless their options set a
+    /// [lane](ToastOptions::lane).
+    ///
+    /// A lane is the [`Toasts`] instance created with [`Self::with_id`] and the given id, so
+    /// each lane has its own anchor, [summary](Self::update) and
+    /// [occupied rects](Self::occupied_rects). The toasts are moved to the lane on the next
+    /// call to [`Self::show`], after [`Self::on_add`] ran, so the interceptor can pick the lane
+    /// too. The handles returned by [`Self::add`] control the toasts in their lane, unless the
+    /// interceptor changed it.
+    ///
+    /// ```
+    /// # use egui::Align2;
+    /// # use egui_toast::{Toast, ToastKind, ToastOptions, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new().route_kind(ToastKind::Error, "critical");
+    /// let mut critical = Toasts::with_id("critical".into()).anchor(Align2::CENTER_TOP, (0.0, 10.0));
+    /// toasts.add(Toast::new().kind(ToastKind::Error).text("Disk failure"));
+    /// toasts.add(Toast::new().text("Backup failed").options(ToastOptions::default().lane("critical")));
+    /// toasts.add(Toast::new().text("Saved"));
+    /// let mut run = |toasts: &mut Toasts| {
+    ///     for _ in 0..3 {
+    ///         let _ = ctx.run(Default::default(), |ctx| {
+    ///             toasts.show(ctx);
+    ///             critical.show(ctx);
+    ///         });
+    ///     }
+    /// };
+    /// run(&mut toasts);
+    /// assert_eq!(toasts.lane_count(&ctx, "critical"), 2);
+    /// assert_eq!(toasts.lane_count(&ctx, "__toasts"), 1);
+    ///
+    /// toasts.dismiss_lane(&ctx, "critical");
+    /// run(&mut toasts);
+    /// assert_eq!(toasts.lane_count(&ctx, "critical"), 0);
+    /// assert_eq!(toasts.lane_count(&ctx, "__toasts"), 1);
+    /// ```
+    #[must_use]
+    pub fn route_kind(mut self, kind: ToastKind, lane: impl Into<Id>) -> Self {
+        self.lane_routes.insert(kind, lane.into());
+        self
+    }
+
+    /// Show copies of the [broadcast](ToastOptions::broadcast) toasts of this lane in
+    /// another lane, e.g. the lane of another viewport. Can be called more than once.
+    #[must_use]
+    pub fn broadcast_to(mut self, lane: impl Into<Id>) -> Self {
+        self.broadcast_lanes.push(lane.into());
+        self
+    }
+
+    /// The lane the toast is shown in, see [`Self::route_kind`].
+    fn lane_of(&self, toast: &Toast) -> Id {
+        toast
+            .options
+            .lane
+            .or_else(|| self.lane_routes.get(&toast.kind).copied())
+            .unwrap_or(self.id)
+    }
+
+    /// Number of toasts in the [lane](Self::route_kind) that have not expired, including the
+    /// toasts queued for it.
+    pub fn lane_count(&self, ctx: &Context, lane: impl Into<Id>) -> usize {
+        let lane = lane.into();
+        let stored = ctx.data_mut(|d| {
+            d.get_temp_mut_or_default::<Vec<StoredToast>>(lane)
+                .iter()
+                .filter(|stored| !stored.toast.options.is_expired())
+                .count()
+        });
+        let added = self
+            .added_toasts
+            .iter()
+            .filter(|toast| self.lane_of(toast) == lane)
+            .count();
+        stored + added + queue::len(ctx, lane)
+    }
+
+    /// Dismiss all toasts in the [lane](Self::route_kind) with [`DismissReason::LaneCleared`],
+    /// including the toasts that were added or queued for it but not shown yet.
+    pub fn dismiss_lane(&mut self, ctx: &Context, lane: impl Into<Id>) {
+        let lane = lane.into();
+        ctx.data_mut(|d| {
+            for stored in d.get_temp_mut_or_default::<Vec<StoredToast>>(lane) {
+                stored
+                    .toast
+                    .options
+                    .dismiss_in_bulk(DismissReason::LaneCleared);
+            }
+        });
+        let (added, cleared): (Vec<_>, Vec<_>) = std::mem::take(&mut self.added_toasts)
+            .into_iter()
+            .partition(|toast| self.lane_of(toast) != lane);
+        self.added_toasts = added;
+        for toast in cleared.into_iter().chain(queue::take(ctx, lane)) {
+            toast.notify_dismissed(DismissReason::LaneCleared);
+        }
+        ctx.request_repaint();
+    }
+
+    /// Can be used to specify a custom rendering function for toasts for given kind
+    ///
+    /// The size of the toast is measured from what the function allocates in the `Ui`,
+    /// not from the returned [`Response`]. Toasts that allocate nothing are given a small
+    /// placeholder size, so that they do not overlap the next toasts.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new()
+    ///     .custom_contents(0, |ui, _| ui.allocate_response(egui::Vec2::ZERO, egui::Sense::hover()));
+    /// let first = toasts.add(Toast::new().kind(0.into())).id();
+    /// let second = toasts.add(Toast::new().kind(0.into())).id();
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// let rect = |id: egui_toast::ToastId| ctx.memory(|m| m.area_rect(id.area_id())).unwrap();
+    /// assert!(rect(first).top() < rect(second).top());
+    /// ```
+    #[must_use]
+    pub fn custom_contents(
+        mut self,
+        kind: impl Into<ToastKind>,
+        add_contents: impl Fn(&mut Ui, &mut Toast) -> Response + Send + Sync + 'static,
+    ) -> Self {
+        self.custom_toast_contents
+            .insert(kind.into(), Arc::new(add_contents));
+        self
+    }
+
+    /// Like [`Self::custom_contents`], but takes a shared renderer, so that the same renderer
+    /// can be registered on several instances, or every frame, without cloning it.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use egui_toast::{ToastContents, Toasts};
+    /// let renderer: Arc<ToastContents> = Arc::new(|ui, toast| ui.label(toast.text.clone()));
+    /// let main = Toasts::new().custom_contents_arc(0, renderer.clone());
+    /// let sidebar = Toasts::with_id(egui::Id::new("sidebar")).custom_contents_arc(0, renderer);
+    /// ```
+    #[must_use]
+    pub fn custom_contents_arc(
+        mut self,
+        kind: impl Into<ToastKind>,
+        add_contents: Arc<ToastContents>,
+    ) -> Self {
+        self.custom_toast_contents.insert(kind.into(), add_contents);
+        self
+    }
+
+    /// Register a [`ToastTemplate`] under `name`, replacing any template with the same name.
+    /// Toasts are made from the template with [`Self::from_template`] or
+    /// [`Self::add_from_template`].
+    ///
+    /// The [renderer](ToastTemplate::contents) of the template, if any, is registered for
+    /// the kind of the template.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, ToastButton, ToastKind, ToastOptions, ToastTemplate, Toasts};
+    /// let mut toasts = Toasts::new()
+    ///     .register_template(
+    ///         "destructive action",
+    ///         ToastTemplate::new(ToastKind::Warning)
+    ///             .options(ToastOptions::default().duration_in_seconds(8.0))
+    ///             .button(ToastButton::new("Undo").primary(true)),
+    ///     )
+    ///     .register_template("tip", ToastTemplate::new(ToastKind::Info).icon("💡"));
+    ///
+    /// let toast = toasts.from_template("destructive action", "Project deleted").unwrap();
+    /// toasts.add(toast.button(ToastButton::new("Details")));
+    /// toasts.add_from_template("tip", "Drag the toasts to move them");
+    /// assert!(toasts.add_from_template("unknown", "Hello").is_none());
+    /// ```
+    #[must_use]
+    pub fn register_template(mut self, name: impl Into<String>, template: ToastTemplate) -> Self {
+        if let Some(contents) = &template.contents {
+            self.custom_toast_contents
+                .insert(template.kind, contents.clone());
+        }
+        self.templates.insert(name.into(), template);
+        self
+    }
+
+    /// Make a toast with the [template](Self::register_template) registered under `name`,
+    /// showing `text`. Returns `None` if there is no such template.
+    pub fn from_template(&self, name: &str, text: impl Into<WidgetText>) -> Option<Toast> {
+        self.templates
+            .get(name)
+            .map(|template| template.toast(text))
+    }
+
+    /// Add a toast made with the [template](Self::register_template) registered under `name`.
+    /// Returns `None` without adding a toast if there is no such template.
+    pub fn add_from_template(
+        &mut self,
+        name: &str,
+        text: impl Into<WidgetText>,
+    ) -> Option<ToastHandle> {
+        let toast = self.from_template(name, text)?;
+        Some(self.add(toast))
+    }
+
+    /// Call `sound` when a toast of the given kind is shown, e.g. to play a sound with an
+    /// audio library of your choice.
+    ///
+    /// The sound is played once per toast, on the first frame the toast is visible. A toast that
+    /// replaces a [duplicate](Self::deduplicate) does not play it again.
+    ///
+    /// ```
+    /// # use std::sync::atomic::{AtomicUsize, Ordering};
+    /// # use std::sync::Arc;
+    /// # use egui_toast::{Toast, ToastKind, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let played = Arc::new(AtomicUsize::new(0));
+    /// let mut toasts = Toasts::new().deduplicate(true).sound_for(ToastKind::Error, {
+    ///     let played = played.clone();
+    ///     move || {
+    ///         played.fetch_add(1, Ordering::Relaxed);
+    ///     }
+    /// });
+    /// toasts.add(Toast::new().kind(ToastKind::Error).text("Failed"));
+    /// toasts.add(Toast::new().kind(ToastKind::Info).text("Saved"));
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// toasts.add(Toast::new().kind(ToastKind::Error).text("Failed"));
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// assert_eq!(played.load(Ordering::Relaxed), 1);
+    ///
+    /// toasts.set_muted(&ctx, true);
+    /// toasts.add(Toast::new().kind(ToastKind::Error).text("Failed again"));
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// assert_eq!(played.load(Ordering::Relaxed), 1);
+    /// ```
+    #[must_use]
+    pub fn sound_for(
+        mut self,
+        kind: impl Into<ToastKind>,
+        sound: impl Fn() + Send + Sync + 'static,
+    ) -> Self {
+        self.sounds.insert(kind.into(), Arc::new(sound));
+        self
+    }
+
+    /// Call `on_show` when a toast is shown for the first time, e.g. to rumble a game
+    /// controller with the [urgency](Toast::urgency) of the toast.
+    ///
+    /// Like the [sounds](Self::sound_for), this is called when the toast appears on screen,
+    /// not when it is added, and not again for a [duplicate](Self::deduplicate) that replaces
+    /// a visible toast. Unlike the sounds, this is called while the toasts are muted.
+    ///
+    /// ```
+    /// # use std::sync::{Arc, Mutex};
+    /// # use egui_toast::{Toast, ToastKind, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let rumbles = Arc::new(Mutex::new(Vec::new()));
+    /// let mut toasts = Toasts::new().on_show({
+    ///     let rumbles = rumbles.clone();
+    ///     move |toast| rumbles.lock().unwrap().push(toast.urgency())
+    /// });
+    /// toasts.add(Toast::new().kind(ToastKind::Error).text("Disconnected"));
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// assert_eq!(*rumbles.lock().unwrap(), [0.9]);
+    /// ```
+    #[must_use]
+    pub fn on_show(mut self, on_show: impl Fn(&Toast) + Send + Sync + 'static) -> Self {
+        self.on_show = Some(Arc::new(on_show));
+        self
+    }
+
+    /// Mute the [sounds](Self::sound_for) of the toasts.
+    ///
+    /// The setting is stored in the egui context's persisted memory, so it also applies to
+    /// instances recreated later with the same id.
+    pub fn set_muted(&self, ctx: &Context, muted: bool) {
+        ctx.data_mut(|d| d.insert_persisted(sound::muted_id(self.id), muted));
+    }
+
+    /// Whether the [sounds](Self::sound_for) of the toasts are muted, see [`Self::set_muted`].
+    pub fn is_muted(&self, ctx: &Context) -> bool {
+        sound::is_muted(ctx, self.id)
+    }
+
+    /// Call `on_add` for every toast added to this instance, before the toast is shown.
+    ///
+    /// This is a single place to enforce conventions for all toasts, whether they were added with
+    /// [`Self::add`] or through a [`ToastQueue`]: the interceptor can change the toast, reject it,
+    /// or replace it with another toast, see [`AddDecision`]. The toasts are intercepted on the
+    /// next call to [`Self::show`]. Rejected toasts are dismissed with [`DismissReason::Rejected`],
+    /// which can be observed with [`Toast::on_dismiss`].
+    ///
+    /// ```
+    /// # use std::sync::atomic::{AtomicUsize, Ordering};
+    /// # use std::sync::Arc;
+    /// # use egui_toast::{AddDecision, DismissReason, Toast, ToastKind, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new().on_add(|toast| {
+    ///     if toast.text.text().is_empty() {
+    ///         return AddDecision::Reject;
+    ///     }
+    ///     if toast.kind == ToastKind::Error {
+    ///         toast.text = format!("E100: {}", toast.text.text()).into();
+    ///     }
+    ///     AddDecision::Accept
+    /// });
+    /// let rejected = Arc::new(AtomicUsize::new(0));
+    /// let on_dismiss = {
+    ///     let rejected = rejected.clone();
+    ///     move |_: &Toast, reason| {
+    ///         if reason == DismissReason::Rejected {
+    ///             rejected.fetch_add(1, Ordering::Relaxed);
+    ///         }
+    ///     }
+    /// };
+    /// let shown = toasts.add(Toast::new().text("Saved").on_dismiss(on_dismiss.clone())).id();
+    /// toasts.add(Toast::new().on_dismiss(on_dismiss.clone()));
+    /// toasts.queue().add(&ctx, Toast::new().on_dismiss(on_dismiss));
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// assert_eq!(rejected.load(Ordering::Relaxed), 2);
+    /// assert!(ctx.memory(|m| m.area_rect(shown.area_id())).is_some());
+    /// ```
+    #[must_use]
+    pub fn on_add(
+        mut self,
+        on_add: impl Fn(&mut Toast) -> AddDecision + Send + Sync + 'static,
+    ) -> Self {
+        self.on_add = Some(Arc::new(on_add));
+        self
+    }
+
+    /// Clean up the text of the added toasts with the default [`TextSanitizer`], e.g. when the
+    /// text comes from the output of another process: ANSI escape sequences and other control
+    /// characters are removed, line endings are normalized, runs of blank lines are collapsed
+    /// and long texts are cut short with an ellipsis.
+    ///
+    /// Only plain and [rich](egui::RichText) text is sanitized. The formatting of rich text
+    /// is lost if the sanitizer changes it. The text is sanitized before it is passed to
+    /// [`Self::on_add`].
+    ///
+    /// ```
+    /// # use std::sync::{Arc, Mutex};
+    /// # use egui_toast::{AddDecision, Toast, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let added = Arc::new(Mutex::new(Vec::new()));
+    /// let mut toasts = Toasts::new().sanitize_text(true).on_add({
+    ///     let added = added.clone();
+    ///     move |toast| {
+    ///         added.lock().unwrap().push(toast.text.text().to_owned());
+    ///         AddDecision::Accept
+    ///     }
+    /// });
+    /// toasts.add(Toast::new().text("\x1b[1mwarning\x1b[0m: unused variable\r\n\r\n\r\n"));
+    /// let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// assert_eq!(*added.lock().unwrap(), ["warning: unused variable\n"]);
+    /// ```
+    #[must_use]
+    pub fn sanitize_text(mut self, sanitize_text: bool) -> Self {
+        self.text_sanitizer = sanitize_text.then(TextSanitizer::default);
+        self
+    }
+
+    /// Like [`Self::sanitize_text`], but with a custom sanitizer.
+    #[must_use]
+    pub fn text_sanitizer(mut self, sanitizer: TextSanitizer) -> Self {
+        self.text_sanitizer = Some(sanitizer);
+        self
+    }
+
+    /// A queue for adding toasts to this instance where it can't be borrowed, e.g. from
+    /// [custom contents](Self::custom_contents) while the toasts are shown.
+    pub fn queue(&self) -> ToastQueue {
+        ToastQueue::new(self.id)
+    }
+
+    /// Keep the settings to show the toasts with on every frame, see [`ToastsConfig`].
+    pub fn into_config(self) -> ToastsConfig {
+        ToastsConfig::new(self)
+    }
+
+    /// Show an inspector of the toasts of this instance, for debugging: the live toasts in
+    /// the order they are stacked with their ids, kinds, texts, remaining time and state,
+    /// followed by the toasts that will be added on the next call to [`Self::show`].
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, Toasts};
+    /// # egui_toast::__run_test_ui(|ui, _ctx| {
+    /// let mut toasts = Toasts::new();
+    /// toasts.add(Toast::new().text("Hello"));
+    /// egui::CollapsingHeader::new("Toasts").show(ui, |ui| toasts.debug_ui(ui));
+    /// # })
+    /// ```
+    pub fn debug_ui(&self, ui: &mut Ui) {
+        inspect::debug_ui(ui, self.id, &self.added_toasts);
+    }
+
+    /// Add a new toast
+    ///
+    /// Returns a handle that can be used to control the toast later. Since the handle is
+    /// returned instead of `&mut Self`, adding toasts can't be chained: call `add` once per
+    /// toast, e.g. `toasts.add(a); toasts.add(b);` instead of `toasts.add(a).add(b)`.
+    ///
+    /// Adding a toast with the [id](Toast::with_id) of another toast is an error. If that
+    /// toast was added to this instance since the last call to [`Self::show`], the new toast
+    /// is dropped with a warning right away and the handle of that toast is returned. A toast
+    /// that is already shown isn't known until then, so the new toast is dropped with a
+    /// warning when it would be shown. Use [`Self::try_add`] to have both cases reported.
+    ///
+    /// ```
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, ToastId, ToastKind, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::new().route_kind(ToastKind::Error, "alerts"));
+    /// let id = ToastId::new_unique();
+    /// let first = test.add(Toast::new().text("Uploading").with_id(id));
+    /// // Not shown in the alerts lane, with the handle of the first toast
+    /// let second = test.add(Toast::new().kind(ToastKind::Error).text("Upload failed").with_id(id));
+    /// assert_eq!(first, second);
+    /// test.run();
+    /// let [toast] = test.stored_toasts().try_into().unwrap();
+    /// assert_eq!(toast.plain_text(), "Uploading");
+    /// ```
+    pub fn add(&mut self, mut toast: Toast) -> ToastHandle {
+        if let Some(id) = toast.id {
+            if let Some(added) = self.added_toasts.iter().find(|added| added.id == Some(id)) {
+                log::warn!(
+                    "Toast {id:?} was added twice before Toasts::show, so the second toast \
+                     was dropped. Use Toasts::try_add to detect this."
+                );
+                return ToastHandle::new(self.lane_of(added), id);
+            }
+        }
+        let id = *toast.id.get_or_insert_with(ToastId::next);
+        toast.assign_sequence();
+        let lane = self.lane_of(&toast);
+        self.added_toasts.push(toast);
+        ToastHandle::new(lane, id)
+    }
+
+    /// Add a success toast with `ok_text` if `result` is `Ok`, or an error toast with the
+    /// error message if it is `Err`.
+    ///
+    /// Returns a handle to the added toast, for example to close it later.
+    ///
+    /// ```
+    /// # use egui_toast::Toasts;
+    /// let mut toasts = Toasts::new();
+    /// let result: Result<(), std::io::Error> = std::fs::write("/nonexistent/file", "data");
+    /// toasts.notify_result(&result, "Saved");
+    /// ```
+    pub fn notify_result<T, E: fmt::Display>(
+        &mut self,
+        result: &Result<T, E>,
+        ok_text: impl Into<WidgetText>,
+    ) -> ToastHandle {
+        self.notify_result_with(
+            result,
+            |_| Toast::new().kind(ToastKind::Success).text(ok_text),
+            |err| Toast::new().kind(ToastKind::Error).text(err.to_string()),
+        )
+    }
+
+    /// Like [`Self::notify_result`], but builds the toasts with `ok` and `err`, e.g. to show
+    /// the value or to set the options of the toasts.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, ToastKind, ToastOptions, Toasts};
+    /// let mut toasts = Toasts::new();
+    /// let result = "42".parse::<u32>();
+    /// toasts.notify_result_with(
+    ///     &result,
+    ///     |value| Toast::new().kind(ToastKind::Success).text(format!("Parsed {value}")),
+    ///     |err| Toast::new()
+    ///         .kind(ToastKind::Error)
+    ///         .text(format!("Invalid number: {err}"))
+    ///         .options(ToastOptions::default().duration_in_seconds(10.0)),
+    /// );
+    /// ```
+    pub fn notify_result_with<T, E>(
+        &mut self,
+        result: &Result<T, E>,
+        ok: impl FnOnce(&T) -> Toast,
+        err: impl FnOnce(&E) -> Toast,
+    ) -> ToastHandle {
+        let toast = match result {
+            Ok(value) => ok(value),
+            Err(error) => err(error),
+        };
+        self.add(toast)
+    }
+
+    /// Add a toast whose kind is picked from its text by the
+    /// [kind inference](Self::kind_inference), for quick messages such as log lines.
+    ///
+    /// Returns the picked kind with the handle of the toast.
+    ///
+    /// ```
+    /// # use egui_toast::{ToastKind, ToastOptions, Toasts};
+    /// let mut toasts = Toasts::new();
+    /// let (kind, _handle) = toasts.auto("Failed to connect", ToastOptions::default());
+    /// assert_eq!(kind, ToastKind::Error);
+    /// ```
+    pub fn auto(
+        &mut self,
+        text: impl Into<WidgetText>,
+        options: ToastOptions,
+    ) -> (ToastKind, ToastHandle) {
+        let text = text.into();
+        let kind = self.kind_inference.infer(text.text());
+        let handle = self.add(Toast::new().kind(kind).text(text).options(options));
+        (kind, handle)
+    }
+
+    /// Show the toasts added with the `ToastsExt` trait of the `context-ext` feature, e.g.
+    /// `ctx.toast_error("Not found")`. The toasts are shared by every instance of the
+    /// context, so only one instance should consume them.
+    ///
+    /// ```
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::Toasts;
+    /// use egui_toast::ToastsExt as _;
+    ///
+    /// let mut test = ToastTestCtx::new(Toasts::new().consume_context_queue(true));
+    /// let mut other = Toasts::with_id(egui::Id::new("other")).consume_context_queue(true);
+    /// test.ctx().toast_info("Saved");
+    /// // The other instance is shown first on this frame and takes the toast
+    /// test.run_with(|ctx| other.show(ctx));
+    /// test.run();
+    /// assert!(test.stored_toasts().is_empty());
+    /// ```
+    #[must_use]
+    pub fn consume_context_queue(mut self, consume: bool) -> Self {
+        self.consume_context_queue = consume;
+        self
+    }
+
+    /// The rules that [`Self::auto`] picks the kinds of the toasts with. Defaults to
+    /// [`KindInference::default`].
+    #[must_use]
+    pub fn kind_inference(mut self, kind_inference: KindInference) -> Self {
+        self.kind_inference = kind_inference;
+        self
+    }
+
+    /// Add a new toast, unless the [capacity](Self::capacity) is reached.
+    ///
+    /// With the default [`CapacityPolicy::DropNewest`], the toast is dismissed with
+    /// [`DismissReason::Dropped`] and [`AddResult::Dropped`] is returned when there are
+    /// already as many toasts as the capacity allows. [`Self::add`] can't see the toasts
+    /// shown in earlier frames, so those toasts are only dropped when they are shown.
+    ///
+    /// The toasts are counted in the [lane](Self::route_kind) the toast is shown in, against
+    /// the capacity of this instance.
+    ///
+    /// ```
+    /// # use egui_toast::{AddResult, Toast, ToastKind, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new().capacity(1).route_kind(ToastKind::Error, "alerts");
+    /// let error = || Toast::new().kind(ToastKind::Error).text("Backup failed");
+    /// assert!(toasts.try_add(&ctx, Toast::new().text("Saved")).handle().is_some());
+    /// // The alerts lane still has room
+    /// assert!(toasts.try_add(&ctx, error()).handle().is_some());
+    /// assert_eq!(toasts.try_add(&ctx, error()), AddResult::Dropped);
+    /// # let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// ```
+    ///
+    /// A toast with the [id](Toast::with_id) of a toast that is shown or about to be is
+    /// not added, and [`AddResult::DuplicateId`] is returned.
+    pub fn try_add(&mut self, ctx: &Context, toast: Toast) -> AddResult {
+        let lane = self.lane_of(&toast);
+        if let Some(toast_id) = toast.id {
+            let stored = ctx.data(|d| {
+                d.get_temp::<Vec<StoredToast>>(lane)
+                    .is_some_and(|toasts| toasts.iter().any(|s| s.toast.id == Some(toast_id)))
+            });
+            let pending = self
+                .added_toasts
+                .iter()
+                .chain(&queue::peek(ctx, lane))
+                .any(|t| t.id == Some(toast_id));
+            if stored || pending {
+                return AddResult::DuplicateId(ToastHandle::new(lane, toast_id));
+            }
+        }
+        if let Some(capacity) = self.capacity {
+            if self.capacity_policy == CapacityPolicy::DropNewest {
+                let mut dropped = DroppedToasts::load(ctx, lane);
+                let live = ctx.data_mut(|d| {
+                    let toasts = d.get_temp_mut_or_default::<Vec<StoredToast>>(lane);
+                    capacity::live_count(toasts, &dropped)
+                });
+                let added = self
+                    .added_toasts
+                    .iter()
+                    .filter(|added| self.lane_of(added) == lane)
+                    .count();
+                let count = live + added + queue::len(ctx, lane);
+                if count >= capacity {
+                    toast.notify_dismissed(DismissReason::Dropped);
+                    dropped.pending += 1;
+                    dropped.store(ctx, lane);
+                    // Show the report even if nothing else is added
+                    state::set_active(ctx, lane, true);
+                    return AddResult::Dropped;
+                }
+            }
+        }
+        AddResult::Added(self.add(toast))
+    }
+
+    /// Add a toast with an undo button for an action that is only done when the toast goes
+    /// away, like deleting a message: `on_commit` is called in the [`Self::show`] call that
+    /// removes the toast after it expired or was closed, but not if the undo button was clicked.
+    ///
+    /// The action is not done if the toasts are dropped before that, e.g. when the app quits
+    /// while the toast is visible.
+    ///
+    /// ```
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// # use std::sync::Arc;
+    /// # use std::time::Duration;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{ToastOptions, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::new());
+    /// let deleted = Arc::new(AtomicBool::new(false));
+    /// let delete = deleted.clone();
+    /// test.toasts().undoable(
+    ///     "Message deleted",
+    ///     "Undo",
+    ///     move || delete.store(true, Ordering::Relaxed),
+    ///     ToastOptions::default().duration_in_seconds(5.0),
+    /// );
+    /// test.run_for(Duration::from_secs(4), 10);
+    /// assert!(!deleted.load(Ordering::Relaxed));
+    /// test.run_for(Duration::from_secs(2), 10);
+    /// assert!(deleted.load(Ordering::Relaxed));
+    /// ```
+    pub fn undoable(
+        &mut self,
+        text: impl Into<WidgetText>,
+        undo_label: impl Into<WidgetText>,
+        on_commit: impl FnOnce() + Send + 'static,
+        options: ToastOptions,
+    ) -> ToastHandle {
+        self.add(undo::toast(
+            text.into(),
+            undo_label.into(),
+            on_commit,
+            options,
+        ))
+    }
+
+    /// Show the toasts one after another, e.g. for onboarding: each toast is added when the
+    /// previous one expired or was dismissed. Only the toast that is shown counts towards
+    /// the [capacity](Self::capacity) and the other limits.
+    ///
+    /// Returns a handle for cancelling the rest of the sequence or skipping to another toast
+    /// of it. See [`Self::advance_sequences_on_dismiss`] for what happens when the user
+    /// closes a toast of the sequence.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::new());
+    /// let options = ToastOptions::default().duration_in_seconds(4.0);
+    /// let sequence = test.toasts().add_sequence(vec![
+    ///     Toast::new().text("Welcome").options(options),
+    ///     Toast::new().text("Drag files here").options(options),
+    ///     Toast::new().text("Press F1 for help").options(options),
+    /// ]);
+    /// test.run_frames(2);
+    /// let text = |test: &ToastTestCtx| test.stored_toasts()[0].plain_text();
+    /// assert_eq!(test.stored_toasts().len(), 1);
+    /// assert_eq!(text(&test), "Welcome");
+    ///
+    /// test.run_for(Duration::from_secs(5), 10);
+    /// assert_eq!(text(&test), "Drag files here");
+    ///
+    /// // Closing the toast shows the next one
+    /// sequence.current(test.ctx()).unwrap().close(test.ctx());
+    /// test.run_frames(2);
+    /// assert_eq!(text(&test), "Press F1 for help");
+    /// assert_eq!(sequence.current_index(test.ctx()), Some(2));
+    ///
+    /// sequence.skip_to(test.ctx(), 3);
+    /// test.run_frames(2);
+    /// assert!(test.stored_toasts().is_empty());
+    /// assert_eq!(sequence.current(test.ctx()), None);
+    /// ```
+    pub fn add_sequence(&mut self, toasts: Vec<Toast>) -> SequenceHandle {
+        let Some(mut first) = toasts.first().cloned() else {
+            // A handle to a sequence that has already ended
+            return SequenceHandle::new(self.id, ToastId::next());
+        };
+        first.id = None;
+        let first = self.add(first).id();
+        self.added_sequences
+            .push(chain::Sequence::new(toasts, first));
+        SequenceHandle::new(self.id, first)
+    }
+
+    /// Play the sound of a toast and call the show hook, when the toast is shown for the
+    /// first time.
+    fn announce(&self, ctx: &Context, stored: &mut StoredToast) {
+        if std::mem::replace(&mut stored.announced, true) {
+            return;
+        }
+        if let Some(on_show) = &self.on_show {
+            on_show(&stored.toast);
+        }
+        sound::play(ctx, self.id, &self.sounds, stored.toast.kind);
+    }
+
+    /// Whether the countdown of the toast drawn in the area of `response` is paused by the
+    /// pointer, see [`Self::hover_margin`].
+    fn is_hover_paused(&self, ctx: &Context, response: &Response) -> bool {
+        if !self.hover_pauses {
+            return false;
+        }
+        if response.contains_pointer() {
+            return true;
+        }
+        let Some(pos) = ctx.pointer_hover_pos() else {
+            return false;
+        };
+        // The rect is taken from this frame, so the margin follows the toast as it moves
+        let near = self.hover_margin > 0.0 && response.rect.expand(self.hover_margin).contains(pos);
+        let over_other_toast = ctx
+            .layer_id_at(pos)
+            .is_some_and(|layer| layer.order == self.order && layer != response.layer_id);
+        near && !over_other_toast
+    }
+
+    /// Show and update all toasts
+    ///
+    /// This can be called unconditionally on every frame: when there are no toasts, it returns
+    /// right away after reading a single flag, without allocating or requesting a repaint.
+    ///
+    /// ```
+    /// # use egui_toast::Toasts;
+    /// let ctx = egui::Context::default();
+    /// let output = ctx.run(Default::default(), |ctx| {
+    ///     let entries = ctx.data(|d| d.len());
+    ///     Toasts::new().show(ctx);
+    ///     assert_eq!(ctx.data(|d| d.len()), entries);
+    /// });
+    /// assert!(output.shapes.is_empty());
+    /// ```
+    ///
+    /// Toasts drawn with the default contents only take the pointer inside their rounded frame,
+    /// so clicks in the transparent corners and on the shadow reach the widgets behind the toasts.
+    /// The countdown is not paused when the pointer is there either.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new();
+    /// let id = toasts.add(Toast::new().text("Hello")).id();
+    /// let canvas = egui::Id::new("canvas");
+    /// let mut run = |events: Vec<egui::Event>| {
+    ///     let input = egui::RawInput { events, ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             ui.interact(ui.max_rect(), canvas, egui::Sense::click());
+    ///         });
+    ///         toasts.show(ctx);
+    ///     });
+    /// };
+    /// run(vec![]);
+    /// run(vec![]);
+    ///
+    /// let rect = ctx.memory(|m| m.area_rect(id.area_id())).unwrap();
+    /// for _ in 0..3 {
+    ///     run(vec![egui::Event::PointerMoved(rect.center())]);
+    /// }
+    /// assert!(!ctx.read_response(canvas).unwrap().hovered());
+    /// for _ in 0..3 {
+    ///     run(vec![egui::Event::PointerMoved(rect.min + egui::vec2(0.5, 0.5))]);
+    /// }
+    /// assert!(ctx.read_response(canvas).unwrap().hovered());
+    /// ```
+    pub fn show(&mut self, ctx: &Context) {
+        self.take_context_queue(ctx);
+        // Skip the work on idle frames, even the round trips to the egui context's memory
+        if self.added_toasts.is_empty() && !state::is_active(ctx, self.id) {
+            return;
+        }
+        self.update_toasts(ctx, None);
+        self.draw(ctx);
+    }
+
+    /// Count down the toasts by `dt` and add the new toasts, without drawing anything.
+    /// Together with [`Self::draw`], this is [`Self::show`] split in two, e.g. for running
+    /// the timing of the toasts in the fixed time step of a simulation. The
+    /// [time source](Self::time_source) is not used for counting down, but it is still used
+    /// for the [dedupe window](Self::dedupe_window) and the transitions it times.
+    ///
+    /// The toasts only count down while they were not hovered on the latest draw, so `update`
+    /// can be called any number of times between the draws.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new();
+    /// let options = ToastOptions::default().duration_in_seconds(1.0);
+    /// let id = toasts.add(Toast::new().text("Saved").options(options)).id();
+    /// let summary = toasts.update(&ctx, Duration::ZERO);
+    /// assert_eq!(summary.toasts, [id]);
+    /// // Measured on the first frame and drawn on the second
+    /// for _ in 0..2 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.draw(ctx));
+    /// }
+    ///
+    /// // Ten simulation steps between two frames
+    /// let mut dismissed = Vec::new();
+    /// for _ in 0..10 {
+    ///     dismissed.extend(toasts.update(&ctx, Duration::from_millis(110)).dismissed);
+    /// }
+    /// assert_eq!(dismissed, [(id, egui_toast::DismissReason::Expired)]);
+    /// ```
+    pub fn update(&mut self, ctx: &Context, dt: Duration) -> ToastsSummary {
+        self.take_context_queue(ctx);
+        if self.added_toasts.is_empty() && !state::is_active(ctx, self.id) {
+            return ToastsSummary::default();
+        }
+        self.update_toasts(ctx, Some(dt.as_secs_f64()))
+    }
+
+    fn take_context_queue(&mut self, ctx: &Context) {
+        if self.consume_context_queue {
+            self.added_toasts.extend(context_ext::take(ctx, self.id));
+        }
+    }
+
+    /// The timing part of [`Self::show`]. Counts down by the time since the previous update,
+    /// or by `dt` if it is set.
+    fn update_toasts(&mut self, ctx: &Context, dt: Option<f64>) -> ToastsSummary {
+        let added = std::mem::take(&mut self.added_toasts);
+        let sequences = std::mem::take(&mut self.added_sequences);
+        self.update_with(ctx, dt, added, sequences)
+    }
+
+    /// Like [`Self::update_toasts`], with the toasts and the sequences added since the
+    /// previous update passed in, so that the settings are only borrowed.
+    fn update_with(
+        &self,
+        ctx: &Context,
+        dt: Option<f64>,
+        mut added: Vec<Toast>,
+        sequences: Vec<chain::Sequence>,
+    ) -> ToastsSummary {
+        let id = self.id;
+
+        // The time since the previous call rather than the frame time, so that the toasts
+        // expire on time even if they are not shown on every frame
+        let time = self.time_source.now(ctx);
+        let time_id = id.with("time");
+        let previous_time = ctx.data_mut(|d| {
+            let previous_time = d.get_temp::<f64>(time_id);
+            d.insert_temp(time_id, time);
+            previous_time
+        });
+        let mut elapsed =
+            dt.unwrap_or_else(|| previous_time.map_or(0.0, |previous| (time - previous).max(0.0)));
+        if self.pause_when_unfocused && self.update_unfocused(ctx) {
+            elapsed = 0.0;
+        }
+        let time_scale = self.time_scale;
+        // The clock of the fades, which runs at the time scale
+        let scaled_time = ctx.data_mut(|d| {
+            let scaled_time = d.get_temp_mut_or_default::<f64>(id.with("scaled_time"));
+            *scaled_time += elapsed * time_scale as f64;
+            *scaled_time
+        });
+        let mut toasts: Vec<StoredToast> = ctx.data_mut(|d| d.get_temp(id).unwrap_or_default());
+        // Before the new toasts are added, so that a click that adds a toast does not
+        // dismiss it right away
+        if self.dismiss_on_click_outside && self.clicked_outside(ctx) {
+            clear_all::clear(&mut toasts, DismissReason::ClickedOutside);
+        }
+        // Keys such as the copy shortcut don't dismiss the toasts while their text is selected
+        let toasts_focused = self.focusable && self.focused_toast(ctx).is_some()
+            || selection::any_selecting(ctx, &toasts);
+        let focus_shortcut = self.focusable.then_some(&self.focus_shortcut);
+        if self.dismiss_on_input.detected(
+            ctx,
+            &self.occupied_rects(ctx),
+            toasts_focused,
+            focus_shortcut,
+        ) {
+            input::dismiss(&mut toasts);
+        }
+        let dedupe_window = self.dedupe_window.as_secs_f64();
+        let mut recent = (self.deduplicate && dedupe_window > 0.0).then(|| {
+            let mut recent = RecentMessages::load(ctx, id);
+            recent.prune(time, dedupe_window);
+            recent
+        });
+        let mut dropped = DroppedToasts::load(ctx, id);
+        let mut live = capacity::live_count(&toasts, &dropped);
+        let mut dropped_now = 0;
+        let mut suppressed = false;
+        // The toasts rejected or dropped on this frame, for the suppressed badge
+        let mut filtered = self
+            .suppressed_badge
+            .then(|| SuppressedToasts::load(ctx, id));
+        added.extend(queue::take(ctx, id));
+        // The toasts are drawn in the order they were added, whether they were added
+        // directly or through a queue
+        added.sort_by_key(|toast| toast.sequence);
+        for mut toast in added {
+            if let Some(sanitizer) = &self.text_sanitizer {
+                sanitize::apply(sanitizer, &mut toast);
+            }
+            let mut toast = match intercept::apply(self.on_add.as_deref(), toast) {
+                Intercepted::Add(toast) => toast,
+                Intercepted::Rejected(toast) => {
+                    suppressed::record(&mut filtered, toast, self.retain_suppressed);
+                    continue;
+                }
+            };
+            let lane = self.lane_of(&toast);
+            if lane != id {
+                // Kept in the lane, even if it routes the kind elsewhere
+                toast.options.lane = Some(lane);
+                ToastQueue::new(lane).add(ctx, toast);
+                ctx.request_repaint();
+                continue;
+            }
+            if let Some(toast_id) = toast.id {
+                if toasts
+                    .iter()
+                    .any(|stored| stored.toast.id == Some(toast_id))
+                {
+                    log::warn!(
+                        "Toast {toast_id:?} was added while a toast with the same id is shown, \
+                         so it was dropped. Use Toasts::try_add to detect this."
+                    );
+                    continue;
+                }
+                reserve::reset(ctx, toast_id);
+            }
+            log::debug!("Toast {:?} was added: {:?}", toast.id, toast.text.text());
+            let options = &mut toast.options;
+            options.apply_defaults(self.show_icons, self.show_progress_bars);
+            options.extend_to(self.min_display_time.max(options.min_display));
+            if options.is_expired() {
+                log::debug!("A toast was added with a zero duration and is never shown");
+            }
+            let duplicate = self.deduplicate.then(|| {
+                toasts.iter_mut().find(|stored| {
+                    !stored.toast.options.is_expired() && escalation::is_repeated_by(stored, &toast)
+                })
+            });
+            let duplicate = duplicate.flatten();
+            if let Some(recent) = &mut recent {
+                if duplicate.is_none() && recent.contains(&toast) {
+                    toast.notify_dismissed(DismissReason::Rejected);
+                    suppressed::record(&mut filtered, toast, self.retain_suppressed);
+                    continue;
+                }
+                recent.insert(&toast, time);
+            }
+            match duplicate {
+                Some(stored) => {
+                    let toast = match &self.escalation {
+                        Some(policy) => match escalation::repeat(stored, toast, time, policy) {
+                            Some(toast) => toast,
+                            None => continue,
+                        },
+                        None => toast,
+                    };
+                    stored.toast.notify_dismissed(DismissReason::Superseded);
+                    // Keep the measured size so the stack does not jump, and don't
+                    // play the sound again
+                    let (size, announced, sequence) =
+                        (stored.size, stored.announced, stored.sequence);
+                    let occurrences = std::mem::take(&mut stored.occurrences);
+                    *stored = StoredToast::new(toast);
+                    stored.size = size;
+                    stored.announced = announced;
+                    stored.sequence = sequence;
+                    stored.occurrences = occurrences;
+                }
+                None => {
+                    if live >= capacity::MAX_LIVE_TOASTS {
+                        toast.notify_dismissed(DismissReason::Dropped);
+                        dropped_now += 1;
+                        suppressed = true;
+                        suppressed::record(&mut filtered, toast, self.retain_suppressed);
+                        continue;
+                    }
+                    if self.capacity.is_some_and(|capacity| live >= capacity) {
+                        match self.capacity_policy {
+                            CapacityPolicy::DropNewest => {
+                                toast.notify_dismissed(DismissReason::Dropped);
+                                dropped_now += 1;
+                                suppressed::record(&mut filtered, toast, self.retain_suppressed);
+                                continue;
+                            }
+                            CapacityPolicy::DropOldest => {
+                                let oldest = toasts.iter_mut().find(|stored| {
+                                    capacity::is_live(stored) && !dropped.is_report(&stored.toast)
+                                });
+                                if let Some(oldest) = oldest {
+                                    oldest.toast.options.dismiss(DismissReason::Evicted);
+                                    live -= 1;
+                                }
+                            }
+                        }
+                    }
+                    live += 1;
+                    let mut stored = StoredToast::new(toast);
+                    if self.escalation.is_some() {
+                        escalation::record_first(&mut stored, time);
+                    }
+                    toasts.push(stored);
+                }
+            }
+        }
+        if let Some(recent) = recent {
+            recent.store(ctx, id);
+        }
+        if let Some(filtered) = filtered {
+            filtered.store(ctx, id);
+        }
+        if let Some(policy) = &self.escalation {
+            escalation::de_escalate(&mut toasts, time, policy);
+        }
+        let dropped_now = std::mem::take(&mut dropped.pending) + dropped_now;
+        // The suppressed toasts are always reported, as the app did not ask for them to be
+        // dropped
+        if (self.report_dropped || suppressed) && dropped_now > 0 {
+            let strings = &self.style.strings;
+            let text = if suppressed {
+                &strings.suppressed_toasts
+            } else {
+                &strings.dropped_toasts
+            };
+            dropped.report(&mut toasts, dropped_now, text);
+        }
+        dropped.store(ctx, id);
+        broadcast::sync(ctx, id, &mut toasts);
+        // Everything after this relies on the toasts being stacked in the order they were added
+        toasts.sort_by_key(|stored| stored.sequence);
+        // Count down before drawing, so that a toast whose time ran out while the app was
+        // not repainting is removed right away instead of being shown for one more frame
+        // The copies of the broadcast toasts follow the toasts instead
+        for stored in toasts.iter_mut().filter(|stored| stored.mirror.is_none()) {
+            if stored.size.is_some() {
+                let options = &mut stored.toast.options;
+                options.count_displayed(elapsed);
+                if options.deferred.is_some() {
+                    ctx.request_repaint_after(options.until_min_display());
+                }
+            }
+            let elapsed = elapsed * stored.toast.options.time_scale_or(time_scale) as f64;
+            if stored.counting_down {
+                stored.toast.options.count_down(elapsed);
+            }
+            // Stopwatches keep counting while they are hovered, but not while they are
+            // paused with a handle. They start on the frame after they were first shown.
+            let options = &mut stored.toast.options;
+            if options.is_stopwatch() && stored.size.is_some() && !stored.paused {
+                options.count_up(elapsed);
+                let scale = options.time_scale_or(time_scale);
+                if let Some(delay) = time::unscaled(options.until_next_second(), scale) {
+                    ctx.request_repaint_after(delay);
+                }
+            }
+        }
+        handle::apply_commands(ctx, id, &mut toasts);
+        limit::apply(
+            &mut toasts,
+            &self.max_visible_per_kind,
+            self.kind_limit_policy,
+        );
+        if self.single_slot {
+            slot::update(&mut toasts, scaled_time);
+        }
+
+        if self.focusable {
+            focus::update_focus(ctx, id.with("focus"), &self.focus_shortcut, &mut toasts);
+        }
+
+        chain::advance(
+            ctx,
+            id,
+            sequences,
+            &mut toasts,
+            self.advance_sequences_on_dismiss,
+        );
+        broadcast::forward_dismissed(ctx, id, &mut toasts);
+        let dismissed = state::remove_dismissed(&mut toasts);
+        if !self.broadcast_lanes.is_empty() {
+            broadcast::publish(ctx, id, &self.broadcast_lanes, &toasts);
+        }
+
+        let summary = ToastsSummary {
+            toasts: toasts
+                .iter()
+                .filter(|stored| stored.mirror.is_none())
+                .filter_map(|stored| stored.toast.id)
+                .collect(),
+            dismissed,
+        };
+        ctx.data_mut(|d| d.insert_temp(id, toasts));
+        // Cleared by the draw once there is nothing left to draw
+        state::set_active(ctx, id, true);
+        summary
+    }
+
+    /// Draw the toasts as they were left by the latest [`Self::update`], see there.
+    ///
+    /// Drawing does not count the toasts down, so a frame that only draws shows the toasts
+    /// as they were. Clicking a close button still closes the toast, which is removed on
+    /// the next update.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new();
+    /// let options = ToastOptions::default().duration_in_seconds(1.0);
+    /// let id = toasts.add(Toast::new().text("Saved").options(options)).id();
+    /// let _ = toasts.update(&ctx, Duration::ZERO);
+    /// for time in 0..5 {
+    ///     let input = egui::RawInput { time: Some(time as f64), ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| toasts.draw(ctx));
+    /// }
+    /// assert!(ctx.memory(|m| m.area_rect(id.area_id())).is_some());
+    /// let summary = toasts.update(&ctx, Duration::ZERO);
+    /// assert_eq!(summary.toasts, [id]);
+    /// ```
+    pub fn draw(&mut self, ctx: &Context) {
+        if let Some((align, offset, direction)) = self.draw_toasts(ctx) {
+            (self.align, self.offset, self.direction) = (align, offset, direction);
+        }
+    }
+
+    /// [`Self::draw`] with the settings only borrowed. Returns the anchor the user dragged
+    /// the toasts to, if they were dropped on this frame.
+    fn draw_toasts(&self, ctx: &Context) -> Option<(Align2, [Offset; 2], Direction)> {
+        if !state::is_active(ctx, self.id) {
+            return None;
+        }
+
+        let Self {
+            id,
+            mut align,
+            mut offset,
+            mut direction,
+            ..
+        } = *self;
+
+        let time = self.time_source.now(ctx);
+        let time_scale = self.time_scale;
+        let scaled_time = ctx.data(|d| {
+            d.get_temp::<f64>(id.with("scaled_time"))
+                .unwrap_or_default()
+        });
+        let motion = motion::Motion::new(ctx, self.reduce_motion);
+        let scale = self.scale;
+        let spacing = scale::length(ctx, self.spacing, scale);
+
+        if let Some((a, o, d)) = drag::load_anchor(ctx, id) {
+            (align, offset, direction) = (a, Offset::points(o.to_pos2()), d);
+        }
+
+        let mut transition_time = 0.0;
+        if let Some(layout) = ctx.data(|d| d.get_temp::<StoredLayout>(id.with("layout"))) {
+            StoredLayout {
+                align,
+                offset,
+                direction,
+                ..
+            } = layout;
+            // Infinitely long at a time scale of 0
+            let slowed = LAYOUT_TRANSITION_SECS / time_scale;
+            if time - layout.changed_at < slowed as f64 {
+                transition_time = slowed;
+            }
+        }
+        // The screen, or the window the toasts are anchored to
+        let Some(screen_rect) = self.anchor_rect(ctx) else {
+            self.hide_while_anchor_hidden(ctx);
+            return None;
+        };
+        let mut offset = Pos2::new(
+            offset[0].resolve(screen_rect.width()),
+            offset[1].resolve(screen_rect.height()),
+        );
+        let anchor_offset = offset;
+        let drag_id = id.with("drag");
+        let mut stack_drag = drag::StackDrag::default();
+        // Areas drawn on this frame, for `Self::occupied_rects`
+        let mut occupied = Vec::new();
+        if self.draggable {
+            offset += ctx.data(|d| d.get_temp::<Vec2>(drag_id).unwrap_or_default());
+        }
+
+        let mut toasts: Vec<StoredToast> = ctx.data_mut(|d| d.get_temp(id).unwrap_or_default());
+        // Set again below for the toasts that count down until the next draw
+        for stored in &mut toasts {
+            stored.counting_down = false;
+        }
+        let mut compact = self
+            .compact_threshold
+            .filter(|&threshold| toasts.len() > threshold)
+            .map(|_| CompactStack::load(ctx, id, time, spacing));
+        if compact.is_none() && self.compact_threshold.is_some() {
+            CompactStack::clear(ctx, id);
+        }
+        if compact
+            .as_ref()
+            .is_some_and(|stack| stack.is_transitioning(time))
+        {
+            transition_time = transition_time.max(LAYOUT_TRANSITION_SECS);
+        }
+        let focused = if self.focusable {
+            self.focused_toast(ctx)
+        } else {
+            None
+        };
+
+        // The toasts of a group are shown together in place of the first toast of the group
+        let sense = if self.draggable {
+            Sense::click_and_drag()
+        } else {
+            Sense::click()
+        };
+        let group_keys = if self.single_slot {
+            vec![None; toasts.len()]
+        } else {
+            group::group_keys(&toasts, self.auto_group)
+        };
+        let fading_out = slot::is_fading_out(&toasts);
+        let mut shown_groups: Vec<GroupKey> = Vec::new();
+        // Priorities of the toasts drawn on this frame, in the order they were added
+        let mut painted: Vec<(i32, u64, ToastId)> = Vec::new();
+        // Kind picked in a context menu, whose toasts are dismissed after the loop
+        let mut dismiss_kind = None;
+
+        // The suppressed badge and the clear all button are pills at the anchor end of the
+        // stack, which take a slot like the toasts
+        let strings = &self.style.strings;
+        let badge_id = id.with("suppressed_badge");
+        let mut filtered = self
+            .suppressed_badge
+            .then(|| SuppressedToasts::load(ctx, id))
+            .filter(|filtered| filtered.total() > 0);
+        let badge = filtered.as_ref().map(|filtered| {
+            let label = strings
+                .suppressed_badge
+                .replace("{count}", &filtered.total().to_string());
+            (label, Some(filtered.tooltip(strings)))
+        });
+        let clear_all_id = id.with("clear_all");
+        let clearable = clear_all::clearable_count(&toasts);
+        let clear_all = self
+            .clear_all_threshold
+            .is_some_and(|max| clearable > max)
+            .then(|| {
+                let label = strings.clear_all.replace("{count}", &clearable.to_string());
+                (label, None)
+            });
+        let mut clicked_pill = None;
+        for (pill_id, pill) in [(badge_id, badge), (clear_all_id, clear_all)] {
+            let pill_size = pill.map(|(label, tooltip)| {
+                let measured = clear_all::load_size(ctx, pill_id);
+                let size = measured.unwrap_or(Vec2::ZERO);
+                let target = align.align_size_within_rect(size, screen_rect).min + offset.to_vec2();
+                let pos = motion.slide(ctx, pill_id, target, transition_time);
+                let inner = Area::new(pill_id)
+                    .fixed_pos(pos)
+                    .order(self.order)
+                    .interactable(true)
+                    .show(ctx, |ui| {
+                        if measured.is_none() {
+                            ui.set_invisible();
+                        }
+                        scale::apply(ui, scale);
+                        let response = clear_all::pill(ui, &label, &self.style.scaled(ctx, scale));
+                        match tooltip {
+                            Some(tooltip) => response.on_hover_text(tooltip),
+                            None => response,
+                        }
+                    });
+                if inner.inner.clicked() {
+                    clicked_pill = Some(pill_id);
+                    ctx.request_repaint();
+                }
+                if measured.is_some() {
+                    stack_drag.add(&inner.response);
+                    occupied.push(inner.response.rect);
+                    advance(&mut offset, direction, size, spacing);
+                } else {
+                    ctx.request_repaint();
+                }
+                layout_size(ctx, inner.response.rect.size(), true, pill_id)
+            });
+            clear_all::store_size(ctx, pill_id, pill_size);
+        }
+        if clicked_pill == Some(clear_all_id) {
+            clear_all::clear(&mut toasts, DismissReason::ClearedAll);
+        }
+        // Shown as new toasts after the other toasts are drawn
+        let mut revealed = Vec::new();
+        if let Some(filtered) = filtered.as_mut().filter(|_| clicked_pill == Some(badge_id)) {
+            revealed = filtered.take();
+        }
+
+        for index in 0..toasts.len() {
+            if let Some(key) = group_keys[index].clone() {
+                if shown_groups.contains(&key) {
+                    continue;
+                }
+                let group_id = self.group_key_id(&key);
+                let mut state =
+                    ctx.data(|d| d.get_temp::<GroupState>(group_id).unwrap_or_default());
+                let mut members: Vec<&mut StoredToast> = toasts
+                    .iter_mut()
+                    .zip(&group_keys)
+                    .filter(|(_, member_key)| member_key.as_ref() == Some(&key))
+                    .map(|(stored, _)| stored)
+                    .collect();
+                for stored in members.iter_mut() {
+                    // Measured again if the toast is shown on its own later
+                    stored.size = None;
+                }
+
+                let measured = state.size.is_some();
+                let size = state.size.unwrap_or(Vec2::ZERO);
+                for stored in members.iter() {
+                    if let Some(toast_id) = stored.toast.id {
+                        state::store_state(ctx, toast_id, stored.state(ctx, !measured));
+                        scale::store(ctx, toast_id, scale);
+                    }
+                }
+                let target = align.align_size_within_rect(size, screen_rect).min + offset.to_vec2();
+                let shake = members
+                    .iter()
+                    .filter_map(|stored| stored.toast.id)
+                    .map(|toast_id| motion.shake(ctx, toast_id.area_id()))
+                    .find(|&shake| shake != 0.0);
+                let pos = motion.slide(ctx, group_id, target, transition_time)
+                    + Vec2::X * shake.unwrap_or(0.0);
+
+                let title = match &key {
+                    GroupKey::Explicit(key) => format!("{key} ({})", members.len()),
+                    GroupKey::Auto(kind) => {
+                        let style = members[0].toast.resolve_style(&self.style);
+                        style.strings.more_toasts(*kind, members.len())
+                    }
+                };
+                let response = Area::new(group_id)
+                    .fixed_pos(pos)
+                    .order(self.order)
+                    .interactable(true)
+                    .sense(sense)
+                    .show(ctx, |ui| {
+                        if !measured {
+                            ui.set_invisible();
+                        }
+                        scale::apply(ui, scale);
+                        let header_style = group::header(&members).resolve_style(&self.style);
+                        let rounding = header_style.frame(ui.style()).rounding;
+                        let in_corner = pointer_in_corner(ctx, group_id, rounding);
+                        let contents = group::group_contents(
+                            ui,
+                            group_id,
+                            &title,
+                            &mut state.expanded,
+                            &mut members,
+                            &self.custom_toast_contents,
+                            &self.style,
+                        );
+                        if in_corner {
+                            pass_pointer_through(ui, group_id, &contents);
+                        }
+                    })
+                    .response;
+
+                state.size = Some(layout_size(ctx, response.rect.size(), measured, group_id));
+                ctx.data_mut(|d| d.insert_temp(group_id, state));
+                shown_groups.push(key.clone());
+                if !measured {
+                    ctx.request_repaint();
+                    continue;
+                }
+
+                stack_drag.add(&response);
+                occupied.push(response.rect);
+                let hovered = self.is_hover_paused(ctx, &response);
+                let mut hover_paused = hovered;
+                for stored in members.iter() {
+                    broadcast::report_hover(ctx, stored.mirror, id, hovered);
+                    hover_paused |= stored.toast.id.is_some_and(|toast_id| {
+                        broadcast::is_hovered_elsewhere(ctx, toast_id)
+                            || selection::is_selecting(ctx, toast_id)
+                    });
+                }
+                for stored in members.iter_mut() {
+                    self.announce(ctx, stored);
+                    let last_frame = LastFrame {
+                        hovered: response.contains_pointer(),
+                        paused: stored.paused || hover_paused,
+                    };
+                    if let Some(toast_id) = stored.toast.id {
+                        last_frame.store(ctx, toast_id);
+                    }
+                }
+
+                // Automatically grouped toasts only expire while they are revealed
+                let hidden = matches!(key, GroupKey::Auto(_)) && !state.expanded;
+                if !hover_paused && !hidden {
+                    for stored in members.into_iter().filter(|stored| !stored.paused) {
+                        count_down(ctx, stored, time_scale, self.progress_repaint_interval);
+                    }
+                }
+
+                advance(&mut offset, direction, size, spacing);
+                continue;
+            }
+
+            let stored = &mut toasts[index];
+            let mut opacity = 1.0;
+            if self.single_slot {
+                match slot::opacity(stored, fading_out, scaled_time, motion) {
+                    Some(slot_opacity) => opacity = slot_opacity,
+                    None => continue,
+                }
+                if opacity < 1.0 {
+                    ctx.request_repaint();
+                }
+            }
+            let count = stored.count;
+            let toast_id = *stored.toast.id.get_or_insert_with(ToastId::next);
+            let appearing = stored.size.is_none() || opacity < 1.0;
+            state::store_state(ctx, toast_id, stored.state(ctx, appearing));
+            opacity *= input::opacity(stored);
+            scale::store(ctx, toast_id, scale);
+            let toast = &mut stored.toast;
+            let area_id = toast_id.area_id();
+
+            // The toasts are laid out using the sizes measured on the previous frame,
+            // so that a new toast with an unknown size does not cause the stack to jump.
+            // New toasts are drawn invisibly on their first frame to measure them.
+            let measured = stored.size.is_some();
+            // Toasts are measured in full before they are collapsed
+            let is_compact = measured
+                && compact
+                    .as_ref()
+                    .is_some_and(|stack| stack.is_compact(toast_id));
+            let (size, spacing) = if is_compact {
+                (compact::size(ctx, scale), spacing / 2.0)
+            } else {
+                (stored.size.unwrap_or(Vec2::ZERO), spacing)
+            };
+            let target = align.align_size_within_rect(size, screen_rect).min + offset.to_vec2();
+            if self.cull_offscreen
+                && measured
+                && !screen_rect.intersects(Rect::from_min_size(target, size))
+            {
+                let last_frame = LastFrame {
+                    hovered: false,
+                    paused: stored.paused,
+                };
+                last_frame.store(ctx, toast_id);
+                if !stored.paused && stored.superseded_at.is_none() {
+                    count_down(ctx, stored, time_scale, self.progress_repaint_interval);
+                }
+                advance(&mut offset, direction, size, spacing);
+                continue;
+            }
+            let pos = motion.slide(ctx, area_id, target, transition_time)
+                + Vec2::X * motion.shake(ctx, area_id);
+
+            let min_size = toast.options.min_size.unwrap_or(self.min_size) * scale;
+            let style = toast.resolve_style(&self.style);
+            let expansion = ctx.animate_bool_with_time(
+                area_id.with("compact"),
+                !is_compact,
+                LAYOUT_TRANSITION_SECS,
+            );
+            let mut opened = false;
+            let response = Area::new(area_id)
+                .fixed_pos(pos)
+                .order(self.order)
+                .interactable(true)
+                .sense(sense)
+                .show(ctx, |ui| {
+                    if !measured {
+                        ui.set_invisible();
+                    }
+                    scale::apply(ui, scale);
+                    if stored.paused {
+                        ui.multiply_opacity(style.paused_opacity);
+                    }
+                    ui.multiply_opacity(opacity);
+                    if is_compact {
+                        ui.multiply_opacity(1.0 - expansion);
+                        opened = compact::contents(ui, toast, &style, count).clicked();
+                        return false;
+                    }
+                    ui.multiply_opacity(expansion);
+                    if let Some(add_contents) = self.custom_toast_contents.get(&toast.kind) {
+                        add_contents(ui, toast);
+                        false
+                    } else {
+                        let rounding = style.frame(ui.style()).rounding;
+                        let in_corner = pointer_in_corner(ctx, area_id, rounding);
+                        let contents = default_toast_contents(ui, toast, &style, min_size, count);
+                        if in_corner {
+                            pass_pointer_through(ui, area_id, &contents);
+                        }
+                        true
+                    }
+                });
+            let (default_contents, response) = (response.inner, response.response);
+            painted.push((toast.priority, stored.sequence, toast_id));
+
+            if let Some(stack) = compact.as_mut().filter(|_| opened) {
+                stack.open(toast_id);
+                ctx.request_repaint();
+            }
+            if !is_compact {
+                stored.size = Some(layout_size(ctx, response.rect.size(), measured, area_id));
+            }
+            if !measured {
+                ctx.request_repaint();
+                continue;
+            }
+
+            self.announce(ctx, stored);
+            stack_drag.add(&response);
+            occupied.push(response.rect);
+            let toast = &mut stored.toast;
+            if default_contents && toast.options.context_menu.unwrap_or(self.context_menu) {
+                let (kind, menu_rect) = context_menu::show(&response, toast, &style.strings);
+                dismiss_kind = dismiss_kind.or(kind);
+                // Clicks on the menu are not outside the toasts
+                occupied.extend(menu_rect);
+            }
+            // Child widgets such as the selectable label take the hover from the area,
+            // so check whether the pointer is anywhere over the toast instead.
+            let hovered = self.is_hover_paused(ctx, &response);
+            broadcast::report_hover(ctx, stored.mirror, id, hovered);
+            // Also paused while the text is selected, so that it does not vanish mid-selection
+            let hover_paused = hovered
+                || broadcast::is_hovered_elsewhere(ctx, toast_id)
+                || selection::is_selecting(ctx, toast_id);
+            let last_frame = LastFrame {
+                hovered: response.contains_pointer(),
+                paused: stored.paused || hover_paused,
+            };
+            last_frame.store(ctx, toast_id);
+
+            if let Some(blip) = &style.update_blip {
+                if let Some(progress) = blip::progress(ctx, toast, time, blip) {
+                    let rounding = style.frame(&ctx.style()).rounding;
+                    let accent = style.accent_color(toast.kind);
+                    blip::paint(&response, rounding, accent, blip, progress, motion);
+                }
+            }
+
+            if focused == Some(toast_id) {
+                focus::paint_focus_ring(ctx, response.layer_id, response.rect);
+            }
+
+            let superseded = stored.superseded_at.is_some();
+            if !hover_paused && !stored.paused && !superseded {
+                count_down(ctx, stored, time_scale, self.progress_repaint_interval);
+            }
+
+            advance(&mut offset, direction, size, spacing);
+        }
+
+        paint_order::update(ctx, self.order, painted);
+        if let Some(compact) = compact {
+            let toast_ids: Vec<ToastId> = toasts.iter().filter_map(|s| s.toast.id).collect();
+            compact.store(ctx, id, &occupied, &toast_ids);
+        }
+
+        if let Some(kind) = dismiss_kind {
+            for stored in toasts.iter_mut().filter(|stored| stored.toast.kind == kind) {
+                stored
+                    .toast
+                    .options
+                    .dismiss_in_bulk(DismissReason::KindCleared);
+            }
+            ctx.request_repaint();
+        }
+
+        toasts.extend(revealed.into_iter().map(StoredToast::new));
+        let badge_shown = filtered.is_some_and(|filtered| {
+            let shown = filtered.total() > 0;
+            filtered.store(ctx, id);
+            shown
+        });
+
+        let mut dragged = None;
+        if self.draggable {
+            let anchor =
+                stack_drag.finish(ctx, drag_id, screen_rect, align, anchor_offset, direction);
+            if let Some(anchor) = anchor {
+                let layout = (anchor.0, Offset::points(anchor.1.to_pos2()), anchor.2);
+                self.store_layout_as(ctx, layout);
+                drag::store_anchor(ctx, id, anchor);
+                dragged = Some(layout);
+            }
+        }
+
+        // Automatic groups collapse again when they are formed the next time
+        let auto_groups_id = id.with("auto_groups");
+        let previous_groups: Vec<GroupKey> =
+            ctx.data(|d| d.get_temp(auto_groups_id).unwrap_or_default());
+        shown_groups.retain(|key| matches!(key, GroupKey::Auto(_)));
+        for key in previous_groups
+            .iter()
+            .filter(|key| !shown_groups.contains(key))
+        {
+            let group_id = self.group_key_id(key);
+            ctx.data_mut(|d| d.remove::<GroupState>(group_id));
+        }
+        ctx.data_mut(|d| d.insert_temp(auto_groups_id, shown_groups));
+
+        let active = !toasts.is_empty() || !queue::is_empty(ctx, id) || badge_shown;
+        ctx.data_mut(|d| {
+            d.insert_temp(id, toasts);
+            if occupied.is_empty() {
+                d.remove::<Vec<Rect>>(id.with("occupied"));
+            } else {
+                d.insert_temp(id.with("occupied"), occupied);
+            }
+        });
+        state::set_active(ctx, id, active);
+        dragged
+    }
+}
+
+/// Whether the pointer is over the transparent corners of the rounded toast drawn in the area.
+fn pointer_in_corner(ctx: &Context, area_id: Id, rounding: Rounding) -> bool {
+    let rect = ctx.memory(|m| m.area_rect(area_id));
+    let pos = ctx.pointer_latest_pos();
+    match (rect, pos) {
+        (Some(rect), Some(pos)) => {
+            rect.contains(pos) && !rounded_rect_contains(rect, rounding, pos)
+        }
+        _ => false,
+    }
+}
+
+/// Let the pointer through a toast to the widgets behind it.
+///
+/// The area of the toast, its `Ui`, and the frame of the contents sense the pointer in their
+/// whole rects, so their interactions are removed. egui finds the hovered widgets using the
+/// widgets of the previous frame, so this takes effect on the next frame.
+fn pass_pointer_through(ui: &mut Ui, area_id: Id, contents: &Response) {
+    // The rect of a widget is replaced when it is registered again with the same id
+    for id in [area_id.with("move"), contents.id] {
+        ui.interact(Rect::NOTHING, id, Sense::hover());
+    }
+    // The interaction of the `Ui` itself is clipped when it is dropped. Everything has
+    // been painted by now, so this does not affect the looks of the toast.
+    ui.set_clip_rect(Rect::NOTHING);
+}
+
+fn rounded_rect_contains(rect: Rect, rounding: Rounding, pos: Pos2) -> bool {
+    let corners = [
+        (rect.left_top(), rounding.nw, Vec2::new(1.0, 1.0)),
+        (rect.right_top(), rounding.ne, Vec2::new(-1.0, 1.0)),
+        (rect.left_bottom(), rounding.sw, Vec2::new(1.0, -1.0)),
+        (rect.right_bottom(), rounding.se, Vec2::new(-1.0, -1.0)),
+    ];
+    rect.contains(pos)
+        && corners.iter().all(|&(corner, radius, inwards)| {
+            let center = corner + inwards * radius;
+            let from_center = pos - center;
+            // Only the square of the corner is affected by the rounding
+            let in_corner = from_center.x * inwards.x < 0.0 && from_center.y * inwards.y < 0.0;
+            !in_corner || from_center.length() <= radius
+        })
+}
+
+/// Size of a toast used for stacking the toasts.
+///
+/// A custom renderer that does not allocate any space would make the following toasts draw
+/// on top of it, so a placeholder size is used for empty toasts instead.
+fn layout_size(ctx: &Context, size: Vec2, measured: bool, area_id: Id) -> Vec2 {
+    if size.is_finite() && size.x > 0.0 && size.y > 0.0 {
+        return size;
+    }
+    if !measured {
+        log::warn!(
+            "The toast in area {area_id:?} has an invalid size of {size:?}. \
+            Make sure that custom toast contents allocate space for what they draw."
+        );
+    }
+    ctx.style().spacing.interact_size
+}
+
+/// Move the offset past a toast of the given size in the stacking direction.
+fn advance(offset: &mut Pos2, direction: Direction, size: Vec2, spacing: f32) {
+    match direction {
+        Direction::LeftToRight => {
+            offset.x += size.x + spacing;
+        }
+        Direction::RightToLeft => {
+            offset.x -= size.x + spacing;
+        }
+        Direction::TopDown => {
+            offset.y += size.y + spacing;
+        }
+        Direction::BottomUp => {
+            offset.y -= size.y + spacing;
+        }
+    }
+}
+
+/// Keep counting down the remaining time of a toast that is not hovered, and make sure
+/// that egui repaints when the toast expires, and every `progress_interval` while the
+/// progress is shown.
+fn count_down(
+    ctx: &Context,
+    stored: &mut StoredToast,
+    time_scale: f32,
+    progress_interval: Duration,
+) {
+    stored.counting_down = true;
+    let options = stored.toast.options;
+    let time_scale = options.time_scale_or(time_scale);
+    // Repaint when the toast expires even if nothing else happens
+    if let Some(delay) = options
+        .remaining()
+        .and_then(|remaining| time::unscaled(remaining, time_scale))
+    {
+        ctx.request_repaint_after(delay);
+    }
+    if options.show_progress && options.duration.is_some() {
+        ctx.request_repaint_after(progress_interval);
+    }
+}
+
+/// Draw a toast with the default look. `count` is the number of toasts
+/// [merged](KindLimitPolicy::Merge) into the toast, including itself.
+fn default_toast_contents(
+    ui: &mut Ui,
+    toast: &mut Toast,
+    style: &ToastStyle,
+    min_size: Vec2,
+    count: usize,
+) -> Response {
+    let toast_id = toast.id.expect("toasts are assigned an id when added");
+    let style = &style.scaled(ui.ctx(), toast.scale(ui.ctx()));
+    let right_to_left = style.layout_direction.is_right_to_left(ui);
+    let slots = style.slots();
+    // The progress bar spans the toast, the other parts are placed in a row
+    let progress_slot = slots.iter().position(|&slot| slot == ToastSlot::Progress);
+    let progress_at_top = progress_slot == Some(0);
+    let mut row: Vec<ToastSlot> = slots
+        .into_iter()
+        .filter(|&slot| slot != ToastSlot::Progress)
+        .collect();
+    let mut frame = style.frame(ui.style());
+    let mut stroke = style.frame_stroke(ui.visuals());
+    if style.urgency_outline {
+        stroke = Stroke::new(2.0 * toast.urgency(), style.accent_color(toast.kind));
+    }
+    // The hover of the whole area is used, so that the highlight stays on while the pointer
+    // is over the buttons. Toasts without buttons don't react to clicks, so they are not
+    // highlighted.
+    let interactive = toast.options.show_close_button || !toast.buttons.is_empty();
+    if interactive && toast.is_hovered(ui.ctx()) {
+        style.highlight(&mut frame, &mut stroke, toast.kind);
+    }
+    // Keep the space between the accent edge and the contents the same as on the other edge
+    let edge_width = style.accent_edge.map_or(0.0, |edge| edge.width);
+    if right_to_left {
+        frame.inner_margin.right += edge_width;
+    } else {
+        frame.inner_margin.left += edge_width;
+    }
+    let min_size = (min_size - frame.inner_margin.sum()).max(Vec2::ZERO);
+    let fill = frame.fill;
+    let response = frame
+        .show(ui, |ui| {
+            style.apply_text_color(ui);
+            // Same as `ui.horizontal`, but tall enough to center the contents vertically
+            let row_size = Vec2::new(
+                ui.available_width(),
+                min_size.y.max(ui.spacing().interact_size.y),
+            );
+            let layout = if ui.layout().prefer_right_to_left() {
+                Layout::right_to_left(Align::Center)
+            } else {
+                Layout::left_to_right(Align::Center)
+            };
+            ui.allocate_ui_with_layout(row_size, layout, |ui| {
+                let close_galley = toast
+                    .options
+                    .show_close_button
+                    .then(|| button_galley(ui, style.close_button_text.clone()));
+                let close_width = close_galley.as_ref().map_or(0.0, |galley| {
+                    galley.size().x + 2.0 * ui.spacing().button_padding.x
+                });
+
+                let a = |ui: &mut Ui, toast: &mut Toast| {
+                    if toast.options.show_icon && style.accent_edge.is_none() {
+                        ui.label(style.icon(ui.ctx(), toast.kind)).widget_info(|| {
+                            let label = style.strings.kind_label(toast.kind);
+                            WidgetInfo::labeled(WidgetType::Label, true, label)
+                        });
+                    }
+                };
+                let b = |ui: &mut Ui, toast: &mut Toast| {
+                    let text = style.contrasting_text(ui, toast.display_text(ui, style), fill);
+                    let selectable = toast
+                        .options
+                        .selectable_text
+                        .unwrap_or(ui.style().interaction.selectable_labels);
+                    let label = ui.add(Label::new(text).selectable(selectable));
+                    if selectable {
+                        selection::update(ui.ctx(), toast_id, &label);
+                    }
+                    let rect = label.rect;
+                    let reserved = reserve::text_width(ui, toast, toast_id, rect.width());
+                    if reserved > rect.width() {
+                        ui.add_space(reserved - rect.width());
+                    }
+                    ui.interact(rect, toast_id.text_id(), Sense::hover())
+                        .widget_info(|| {
+                            WidgetInfo::labeled(WidgetType::Label, true, toast.announcement(style))
+                        });
+                    if count > 1 {
+                        let strings = &style.strings;
+                        ui.weak(strings.merged_count.replace("{count}", &count.to_string()));
+                    }
+                    if toast.options.is_stopwatch() {
+                        stopwatch(ui, toast, style);
+                    }
+                };
+                let d = |ui: &mut Ui, toast: &mut Toast| {
+                    if toast.buttons.is_empty() {
+                        return;
+                    }
+                    let mut clicked = None;
+                    // Wrap the buttons to multiple rows if the width is constrained
+                    ui.horizontal_wrapped(|ui| {
+                        let mut buttons: Vec<_> = toast.buttons.iter().enumerate().collect();
+                        if right_to_left != ui.layout().prefer_right_to_left() {
+                            buttons.reverse();
+                        }
+                        for (index, button) in buttons {
+                            let style = if button.primary {
+                                &style.primary_button
+                            } else {
+                                &style.secondary_button
+                            };
+                            if action_button(ui, button, style).clicked() {
+                                clicked = Some(index);
+                            }
+                        }
+                    });
+                    if let Some(index) = clicked {
+                        toast.click_button(index);
+                    }
+                };
+                let c = |ui: &mut Ui, toast: &mut Toast| {
+                    let Some(galley) = close_galley.clone() else {
+                        return;
+                    };
+                    let label = &style.strings.close_button_label;
+                    let mut response =
+                        fixed_id_button(ui, toast_id.close_button_id(), galley, label);
+                    if shows_progress(toast)
+                        && style.progress_style == ProgressStyle::CircleAroundClose
+                    {
+                        progress_arc(ui, response.rect, toast_id, toast, style);
+                    }
+                    let tooltip = &style.strings.close_button_tooltip;
+                    if !tooltip.is_empty() {
+                        response = response.on_hover_text(tooltip);
+                    }
+                    if response.clicked() {
+                        toast.request_close(ui.ctx());
+                    }
+                };
+
+                // The horizontal layout itself may be right-to-left, in which case the
+                // contents are drawn in the reverse order to get the intended look.
+                if right_to_left != ui.layout().prefer_right_to_left() {
+                    row.reverse();
+                }
+                let trailing_id = toast_id.area_id().with("trailing_width");
+                let mut trailing_start = None;
+                for (index, &slot) in row.iter().enumerate() {
+                    match slot {
+                        ToastSlot::Icon => a(ui, toast),
+                        ToastSlot::Text => b(ui, toast),
+                        ToastSlot::Buttons => d(ui, toast),
+                        ToastSlot::Close => c(ui, toast),
+                        ToastSlot::Progress => {}
+                        ToastSlot::Spacer => {
+                            // The width of the parts after the spacer is measured on the
+                            // previous frame, except for a lone close button
+                            let trailing = &row[index + 1..];
+                            let trailing_width = ui
+                                .data(|d| d.get_temp::<f32>(trailing_id))
+                                .or((trailing == [ToastSlot::Close]).then_some(close_width));
+                            let used = ui.min_rect().width() + ui.spacing().item_spacing.x;
+                            let mut extra = 0.0;
+                            if let Some(trailing_width) = trailing_width {
+                                extra = (min_size.x - used - trailing_width).max(0.0);
+                                if extra > 0.0 {
+                                    ui.add_space(extra);
+                                }
+                            }
+                            trailing_start = Some(used + extra);
+                        }
+                    }
+                }
+                if let Some(start) = trailing_start {
+                    let width = (ui.min_rect().width() - start).max(0.0);
+                    ui.data_mut(|d| d.insert_temp(trailing_id, width));
+                }
+
+                ui.set_min_size(min_size);
+            });
+            if let Some(details) = &toast.details {
+                let open_details_id = context_menu::open_details_id(toast_id);
+                let open = ui.data_mut(|d| d.remove_temp::<bool>(open_details_id));
+                CollapsingHeader::new(&style.strings.details)
+                    .id_salt(toast_id.area_id().with("details"))
+                    .open(open)
+                    .show(ui, |ui| ui.label(details.clone()));
+            }
+        })
+        .response;
+
+    #[cfg(feature = "accesskit")]
+    ui.ctx().accesskit_node_builder(response.id, |builder| {
+        builder.set_role(match toast.kind {
+            ToastKind::Warning | ToastKind::Error => egui::accesskit::Role::Alert,
+            _ => egui::accesskit::Role::Status,
+        });
+    });
+
+    let progress_arc_shown =
+        style.progress_style == ProgressStyle::CircleAroundClose && toast.options.show_close_button;
+    let progress_bar_shown =
+        shows_progress(toast) && !progress_arc_shown && progress_slot.is_some();
+    let bar_rect = progress_bar_shown.then(|| {
+        let mut rect = response.rect;
+        let height = progress_bar_height(ui, toast);
+        if progress_at_top {
+            rect.set_bottom(rect.top() + height);
+        } else {
+            rect.set_top(rect.bottom() - height);
+        }
+        rect
+    });
+    if let Some(edge) = style.accent_edge {
+        let color = style.accent_color(toast.kind);
+        accent_edge(
+            ui,
+            &response,
+            frame.rounding,
+            edge,
+            color,
+            bar_rect,
+            right_to_left,
+        );
+    }
+    if let Some(bar_rect) = bar_rect {
+        progress_bar(
+            ui,
+            &response,
+            bar_rect,
+            toast_id,
+            toast,
+            style,
+            right_to_left,
+        );
+    }
+
+    // Draw the frame's stroke last
+    let frame_shape = Shape::Rect(RectShape::stroke(response.rect, frame.rounding, stroke));
+    ui.painter().add(frame_shape);
+
+    response
+}
+
+/// Lay out the text of a [`fixed_id_button`].
+fn button_galley(ui: &Ui, text: WidgetText) -> Arc<Galley> {
+    text.into_galley(
+        ui,
+        Some(TextWrapMode::Extend),
+        f32::INFINITY,
+        TextStyle::Button,
+    )
+}
+
+/// A button with a fixed id, so that it can be found e.g. by [`ToastId::close_button_id`].
+///
+/// `label` is the accessible name of the button.
+fn fixed_id_button(ui: &mut Ui, id: Id, galley: Arc<Galley>, label: &str) -> Response {
+    let padding = ui.spacing().button_padding;
+    let (_, rect) = ui.allocate_space(galley.size() + 2.0 * padding);
+    let response = ui.interact(rect, id, Sense::click());
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, true, label));
+
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.style().interact(&response);
+        ui.painter().rect(
+            rect.expand(visuals.expansion),
+            visuals.rounding,
+            visuals.weak_bg_fill,
+            visuals.bg_stroke,
+        );
+        ui.painter()
+            .galley(rect.min + padding, galley, visuals.text_color());
+    }
+
+    response
+}
+
+/// Whether the default contents show the progress of the toast. Stopwatches have no
+/// remaining time to show.
+fn shows_progress(toast: &Toast) -> bool {
+    toast.options.show_progress && (!toast.options.is_stopwatch() || !toast.progress.is_empty())
+}
+
+/// Draw the elapsed time of a [stopwatch](ToastOptions::stopwatch) toast, after a dot
+/// that blinks with the seconds.
+fn stopwatch(ui: &mut Ui, toast: &Toast, style: &ToastStyle) {
+    let elapsed = toast.elapsed();
+    let size = Vec2::splat(ui.spacing().interact_size.y / 3.0);
+    let (rect, _) = ui.allocate_exact_size(size, Sense::hover());
+    let mut color = style.accent_color(toast.kind);
+    if elapsed.as_secs() % 2 == 1 {
+        color = color.gamma_multiply(0.3);
+    }
+    ui.painter()
+        .circle_filled(rect.center(), size.x / 2.0, color);
+    // Monospace digits, so that the toast does not change its width every second
+    ui.label(egui::RichText::new(format_elapsed(elapsed)).monospace());
+}
+
+fn progress_bar_height(ui: &Ui, toast: &Toast) -> f32 {
+    scale::length(ui.ctx(), 2.0, toast.scale(ui.ctx()))
+}
+
+/// Draw the [accent edge](ToastStyle::accent_edge) of a toast drawn in `response`, leaving
+/// out the progress bar at `bar_rect`.
+fn accent_edge(
+    ui: &Ui,
+    response: &Response,
+    rounding: Rounding,
+    edge: AccentEdge,
+    color: Color32,
+    bar_rect: Option<Rect>,
+    right_to_left: bool,
+) {
+    let frame_rect = response.rect;
+    let mut rect = frame_rect;
+    match bar_rect {
+        Some(bar) if bar.top() <= frame_rect.top() => rect.set_top(bar.bottom()),
+        Some(bar) => rect.set_bottom(bar.top()),
+        None => {}
+    }
+    if right_to_left {
+        rect.set_left(frame_rect.right() - edge.width);
+    } else {
+        rect.set_right(frame_rect.left() + edge.width);
+    }
+    if edge.rounding_aware {
+        // Fill the whole frame shape, clipped to the edge, to get the rounded corners
+        ui.painter()
+            .with_clip_rect(rect)
+            .rect_filled(frame_rect, rounding, color);
+    } else {
+        ui.painter().rect_filled(rect, Rounding::ZERO, color);
+    }
+}
+
+/// Draw the progress bar across the top or the bottom of a toast drawn in `response`, in
+/// `clip_rect`.
+fn progress_bar(
+    ui: &mut Ui,
+    response: &Response,
+    clip_rect: Rect,
+    toast_id: ToastId,
+    toast: &mut Toast,
+    style: &ToastStyle,
+    right_to_left: bool,
+) {
+    let at_top = clip_rect.top() <= response.rect.top();
+    // Only the corners on the edge of the toast are rounded
+    let mut rounding = style.frame(ui.style()).rounding;
+    if at_top {
+        (rounding.sw, rounding.se) = (0.0, 0.0);
+    } else {
+        (rounding.nw, rounding.ne) = (0.0, 0.0);
+    }
+    let corner = if at_top {
+        rounding.nw.max(rounding.ne)
+    } else {
+        rounding.sw.max(rounding.se)
+    };
+    let height = clip_rect.height();
+    // Keep the interaction out of the transparent corners
+    let mut interact_rect = clip_rect.shrink2(Vec2::new(corner, 0.0));
+    let seekable = toast.options.progress_interactive
+        && toast.progress.is_empty()
+        && toast.options.initial_duration().is_some();
+    let sense = if seekable {
+        // The bar is too thin to hit otherwise
+        let grab = scale::length(ui.ctx(), 6.0, toast.scale(ui.ctx())).max(height);
+        if at_top {
+            interact_rect.set_bottom(interact_rect.top() + grab);
+        } else {
+            interact_rect.set_top(interact_rect.bottom() - grab);
+        }
+        Sense::click_and_drag()
+    } else {
+        Sense::hover()
+    };
+    let bar = ui.interact(interact_rect, toast_id.progress_bar_id(), sense);
+    bar.widget_info(|| WidgetInfo::new(WidgetType::ProgressIndicator));
+    if seekable {
+        if bar.hovered() || bar.dragged() {
+            ui.ctx().set_cursor_icon(CursorIcon::ResizeHorizontal);
+        }
+        if let Some(pos) = bar
+            .interact_pointer_pos()
+            .filter(|_| bar.rect.width() > 0.0)
+        {
+            // The bar fills from the leading edge, and the ends of the bar are full and empty
+            let from_leading = if right_to_left {
+                bar.rect.right() - pos.x
+            } else {
+                pos.x - bar.rect.left()
+            };
+            toast.options.seek(from_leading / bar.rect.width());
+        }
+    }
+    let paused = toast.is_paused(ui.ctx());
+    let color = style.progress_color(style.text_color(ui.visuals()), paused);
+    // Fill the part of the bar between `start` and `start + width` from the leading edge
+    let fill = |start: f32, width: f32, color: Color32| {
+        let mut rect = clip_rect;
+        if right_to_left {
+            rect.set_right(clip_rect.right() - start);
+            rect.set_left(rect.right() - width);
+        } else {
+            rect.set_left(clip_rect.left() + start);
+            rect.set_right(rect.left() + width);
+        }
+        ui.painter()
+            .with_clip_rect(rect)
+            .rect_filled(response.rect, rounding, color);
+    };
+
+    if toast.progress.is_empty() {
+        // The bar shrinks towards the leading edge
+        fill(
+            0.0,
+            clip_rect.width() * toast.options.progress() as f32,
+            color,
+        );
+        return;
+    }
+    let segments = toast.progress.len() as f32;
+    let gap = style.progress_segment_gap;
+    let segment_width = ((clip_rect.width() - gap * (segments - 1.0)) / segments).max(0.0);
+    for (index, &progress) in toast.progress.iter().enumerate() {
+        let color = match style.progress_segment_colors.get(index) {
+            Some(&segment_color) => style.progress_color(segment_color, paused),
+            None => color,
+        };
+        let start = index as f32 * (segment_width + gap);
+        fill(start, segment_width, color.gamma_multiply(0.25));
+        if progress > 0.0 {
+            fill(start, segment_width * progress, color);
+        }
+    }
+}
+
+/// Draw the remaining time as an arc around the close button.
+fn progress_arc(
+    ui: &mut Ui,
+    button_rect: Rect,
+    toast_id: ToastId,
+    toast: &Toast,
+    style: &ToastStyle,
+) {
+    let width = scale::length(ui.ctx(), 2.0, toast.scale(ui.ctx()));
+    let center = button_rect.center();
+    let radius = button_rect.size().max_elem() / 2.0 + width;
+    ui.interact(
+        Rect::from_center_size(center, Vec2::splat(2.0 * radius)),
+        toast_id.progress_bar_id(),
+        Sense::hover(),
+    )
+    .widget_info(|| WidgetInfo::new(WidgetType::ProgressIndicator));
+
+    let sweep = toast.progress_fraction() * std::f32::consts::TAU;
+    if sweep <= 0.0 {
+        return;
+    }
+
+    // Use enough segments for the arc to look smooth at the current scale
+    let segments = (sweep * radius * ui.ctx().pixels_per_point() / 4.0)
+        .ceil()
+        .max(2.0) as usize;
+    let points: Vec<Pos2> = (0..=segments)
+        .map(|i| {
+            // Start from the top and go clockwise
+            let angle = -std::f32::consts::FRAC_PI_2 + sweep * i as f32 / segments as f32;
+            center + radius * Vec2::angled(angle)
+        })
+        .collect();
+
+    let color = style.progress_color(style.accent_color(toast.kind), toast.is_paused(ui.ctx()));
+    let stroke = Stroke::new(width, color);
+    ui.painter().add(Shape::line(points, stroke));
 }
 
 pub fn __run_test_ui(mut add_contents: impl FnMut(&mut Ui, &Context)) {
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use crate::state::StoredToast;
+use crate::{DismissReason, ToastKind};
+
+/// What happens to the oldest toasts of a kind when there are more of them than
+/// [`Toasts::max_visible_per_kind`](crate::Toasts::max_visible_per_kind) allows.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum KindLimitPolicy {
+    /// The oldest toasts are dismissed with [`DismissReason::Evicted`].
+    #[default]
+    Evict,
+    /// The oldest toasts are dismissed with [`DismissReason::Merged`], and the newest toast
+    /// of the kind shows how many toasts were merged into it, e.g. "Saved ×3".
+    Merge,
+}
+
+/// Dismiss the oldest toasts of the kinds that have too many toasts.
+pub(crate) fn apply(
+    toasts: &mut [StoredToast],
+    limits: &HashMap<ToastKind, usize>,
+    policy: KindLimitPolicy,
+) {
+    for (&kind, &limit) in limits {
+        let live: Vec<usize> = (0..toasts.len())
+            .filter(|&index| {
+                let stored = &toasts[index];
+                stored.toast.kind == kind
+                    && !stored.toast.options.is_expired()
+                    && stored.superseded_at.is_none()
+            })
+            .collect();
+        let Some(excess) = live.len().checked_sub(limit).filter(|&excess| excess > 0) else {
+            continue;
+        };
+        let newest = live[live.len() - 1];
+        // There is nothing to merge into if no toasts of the kind are allowed
+        let merge = policy == KindLimitPolicy::Merge && limit > 0;
+        for &index in &live[..excess] {
+            if merge {
+                let count = toasts[index].count;
+                toasts[newest].count += count;
+                toasts[index].toast.options.dismiss(DismissReason::Merged);
+            } else {
+                toasts[index].toast.options.dismiss(DismissReason::Evicted);
+            }
+        }
+    }
+}
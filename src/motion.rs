@@ -0,0 +1,68 @@
+use egui::{Context, Id, Pos2};
+
+use crate::close;
+
+/// Animations of the toasts.
+///
+/// Every animation goes through here, so that [`Toasts::reduce_motion`](crate::Toasts::reduce_motion)
+/// applies to all of them: with reduced motion, the toasts don't move around, and only
+/// opacity fades are kept.
+#[derive(Clone, Copy)]
+pub(crate) struct Motion {
+    reduced: bool,
+}
+
+impl Motion {
+    pub(crate) fn new(ctx: &Context, reduce_motion: bool) -> Self {
+        // Apps that turn off the egui animations don't want animated toasts either
+        let reduced = reduce_motion || ctx.style().animation_time <= 0.0;
+        Self { reduced }
+    }
+
+    /// Position a toast the same way as `Area::anchor` would, but animate the
+    /// position while transitioning to a new layout.
+    pub(crate) fn slide(
+        self,
+        ctx: &Context,
+        area_id: Id,
+        target: Pos2,
+        transition_time: f32,
+    ) -> Pos2 {
+        let transition_time = if self.reduced { 0.0 } else { transition_time };
+        let animated = Pos2::new(
+            ctx.animate_value_with_time(area_id.with("x"), target.x, transition_time),
+            ctx.animate_value_with_time(area_id.with("y"), target.y, transition_time),
+        );
+        if transition_time > 0.0 {
+            animated
+        } else {
+            target
+        }
+    }
+
+    /// Opacity of a fade in that started `elapsed` seconds ago. Fades are kept with
+    /// reduced motion.
+    pub(crate) fn fade_in(self, elapsed: f64, duration: f64) -> f32 {
+        (elapsed / duration).clamp(0.0, 1.0) as f32
+    }
+
+    /// Scale of a toast `progress` of the way through its [blip](crate::BlipStyle). The
+    /// toast keeps its size with reduced motion.
+    pub(crate) fn blip_scale(self, scale: f32, progress: f32) -> f32 {
+        if self.reduced {
+            1.0
+        } else {
+            1.0 + (scale - 1.0) * progress
+        }
+    }
+
+    /// Horizontal offset of a toast that was [denied closing](crate::CloseDecision::Deny).
+    /// The toast stays in place with reduced motion.
+    pub(crate) fn shake(self, ctx: &Context, area_id: Id) -> f32 {
+        if self.reduced {
+            0.0
+        } else {
+            close::shake_offset(ctx, area_id)
+        }
+    }
+}
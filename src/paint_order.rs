@@ -0,0 +1,37 @@
+use egui::{Context, LayerId, Order};
+
+use crate::ToastId;
+
+/// Stack the areas of the toasts by [priority](crate::Toast::priority), and the newer toasts
+/// above the older ones, so that the most important toast stays visible when toasts overlap
+/// during animations.
+///
+/// egui only moves areas to the top while keeping the order of the moved areas, so the areas
+/// are sorted over a few frames: each frame puts one more toast in place.
+pub(crate) fn update(ctx: &Context, order: Order, mut ranked: Vec<(i32, u64, ToastId)>) {
+    // Toasts with the same priority are ordered by their sequence numbers
+    ranked.sort_by_key(|&(priority, sequence, _)| (priority, sequence));
+    let ranked: Vec<LayerId> = ranked
+        .into_iter()
+        .map(|(_, _, id)| LayerId::new(order, id.area_id()))
+        .collect();
+
+    // The areas shown for the first time are not in the order yet, egui puts them on top
+    let mut current: Vec<LayerId> = ctx.memory(|m| {
+        m.layer_ids()
+            .filter(|layer| ranked.contains(layer))
+            .collect()
+    });
+    for &layer in &ranked {
+        if !current.contains(&layer) {
+            current.push(layer);
+        }
+    }
+
+    if let Some(first) = (0..ranked.len()).find(|&index| current[index] != ranked[index]) {
+        // Moving the toasts after the first misplaced one to the top puts it in place
+        for &layer in &ranked[first + 1..] {
+            ctx.move_to_top(layer);
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use egui::{Color32, Id, Rounding, Shadow, Stroke};
+
+use crate::{
+    Align2, Direction, Toast, ToastButtonStyle, ToastOptions, ToastStyle, Toasts, WidgetText,
+};
+
+/// Pre-configured [`Toasts`] for common kinds of notifications.
+///
+/// The presets only combine the regular builder options, so they can be customized further
+/// with the same options.
+pub struct ToastsPreset;
+
+impl ToastsPreset {
+    /// Snackbars at the bottom center of the screen, meant for [chips](Toast::chip).
+    ///
+    /// The toasts stack upwards with a small spacing, and repeated messages are
+    /// [deduplicated](Toasts::deduplicate). The toasts are stored under their own id,
+    /// so they don't mix with the toasts of a default [`Toasts`] instance.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, ToastsPreset};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = ToastsPreset::snackbar_bottom_center();
+    /// let id = toasts.add(Toast::chip("Copied")).id();
+    /// toasts.add(Toast::chip("Copied"));
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// let rect = ctx.memory(|m| m.area_rect(id.area_id()));
+    /// assert!(rect.is_none(), "the first chip was replaced by the second one");
+    /// ```
+    pub fn snackbar_bottom_center() -> Toasts {
+        Toasts::with_id(Id::new("__snackbar_toasts"))
+            .anchor(Align2::CENTER_BOTTOM, (0.0, -20.0))
+            .direction(Direction::BottomUp)
+            .spacing(4.0)
+            .deduplicate(true)
+    }
+
+    /// Material-style snackbars at the bottom center of the screen: only one toast is shown
+    /// at a time, and a new toast [replaces](Toasts::single_slot) the visible one.
+    pub fn material_snackbar() -> Toasts {
+        Toasts::with_id(Id::new("__material_snackbar_toasts"))
+            .anchor(Align2::CENTER_BOTTOM, (0.0, -20.0))
+            .direction(Direction::BottomUp)
+            .single_slot(true)
+    }
+}
+
+impl Toast {
+    /// A compact single-line toast for short status messages such as "Copied" or "Zoom 150%".
+    ///
+    /// The chip has no icon, close button or progress bar, and expires after two seconds.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new();
+    /// let chip = toasts.add(Toast::chip("Saved")).id();
+    /// let toast = toasts.add(Toast::new().text("Saved")).id();
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// let rect = |id: egui_toast::ToastId| ctx.memory(|m| m.area_rect(id.area_id())).unwrap();
+    /// assert!(rect(chip).height() < rect(toast).height());
+    /// assert!(ctx.read_response(chip.close_button_id()).is_none());
+    /// ```
+    pub fn chip(text: impl Into<WidgetText>) -> Self {
+        let options = ToastOptions::default()
+            .show_icon(false)
+            .show_close_button(false)
+            .show_progress(false)
+            .duration_in_seconds(2.0);
+        Self::new()
+            .text(text)
+            .options(options)
+            .style(ToastStyle::chip())
+    }
+}
+
+impl ToastStyle {
+    /// Style of a [chip](Toast::chip): a small margin and fully rounded ends.
+    pub fn chip() -> Self {
+        Self {
+            inner_margin: 6.0,
+            rounding: Some(Rounding::same(15.0)),
+            ..Default::default()
+        }
+    }
+
+    /// Dark Material Design snackbars: a dark gray fill, light text and colored action buttons
+    /// without a frame.
+    ///
+    /// The colors don't depend on the egui visuals, so the toasts look the same with
+    /// light and dark visuals.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, Toasts, ToastStyle};
+    /// # egui_toast::__run_test_ui(|ui, ctx| {
+    /// let mut toasts = Toasts::new().style(ToastStyle::material_dark());
+    /// toasts.add(Toast::new().text("Message archived"));
+    /// toasts.show(ctx);
+    /// # })
+    /// ```
+    pub fn material_dark() -> Self {
+        Self {
+            inner_margin: 12.0,
+            rounding: Some(Rounding::same(4.0)),
+            fill: Some(Color32::from_rgb(0x32, 0x32, 0x32)),
+            stroke: Some(Stroke::NONE),
+            text_color: Some(Color32::from_rgb(0xE0, 0xE0, 0xE0)),
+            primary_button: flat_button(Color32::from_rgb(0xBB, 0x86, 0xFC)),
+            secondary_button: flat_button(Color32::from_rgb(0xB0, 0xB0, 0xB0)),
+            ..Default::default()
+        }
+        .kind_colors([
+            Color32::from_rgb(0x64, 0xB5, 0xF6),
+            Color32::from_rgb(0xFF, 0xD5, 0x4F),
+            Color32::from_rgb(0xCF, 0x66, 0x79),
+            Color32::from_rgb(0x81, 0xC7, 0x84),
+        ])
+    }
+
+    /// Light Material Design snackbars: a white fill with a thin outline, dark text and
+    /// colored action buttons without a frame.
+    ///
+    /// Like [`Self::material_dark`], the colors don't depend on the egui visuals.
+    pub fn material_light() -> Self {
+        Self {
+            inner_margin: 12.0,
+            rounding: Some(Rounding::same(4.0)),
+            fill: Some(Color32::from_rgb(0xFA, 0xFA, 0xFA)),
+            stroke: Some(Stroke::new(1.0, Color32::from_rgb(0xE0, 0xE0, 0xE0))),
+            text_color: Some(Color32::from_rgb(0x21, 0x21, 0x21)),
+            primary_button: flat_button(Color32::from_rgb(0x62, 0x00, 0xEE)),
+            secondary_button: flat_button(Color32::from_rgb(0x61, 0x61, 0x61)),
+            ..Default::default()
+        }
+        .kind_colors([
+            Color32::from_rgb(0x19, 0x76, 0xD2),
+            Color32::from_rgb(0xF5, 0x7C, 0x00),
+            Color32::from_rgb(0xB0, 0x00, 0x20),
+            Color32::from_rgb(0x38, 0x8E, 0x3C),
+        ])
+    }
+
+    /// Just the text with a dot in the color of the kind, without a frame or a shadow.
+    ///
+    /// The text follows the egui visuals, so this works with light and dark visuals,
+    /// as long as the toasts are shown over a calm background.
+    pub fn minimal() -> Self {
+        let dot = |color| WidgetText::from("•").color(color);
+        let style = Self {
+            inner_margin: 4.0,
+            fill: Some(Color32::TRANSPARENT),
+            stroke: Some(Stroke::NONE),
+            shadow: Some(Shadow::NONE),
+            ..Default::default()
+        };
+        Self {
+            info_icon: dot(style.info_color),
+            warning_icon: dot(style.warning_color),
+            error_icon: dot(style.error_color),
+            success_icon: dot(style.success_color),
+            ..style
+        }
+    }
+
+    /// White text on black with a thick yellow outline and bright, saturated kind colors,
+    /// for users who need a high contrast. Paused toasts are not dimmed.
+    ///
+    /// Like [`Self::material_dark`], the colors don't depend on the egui visuals.
+    pub fn high_contrast() -> Self {
+        let yellow = Color32::from_rgb(0xFF, 0xFF, 0x00);
+        Self {
+            fill: Some(Color32::BLACK),
+            stroke: Some(Stroke::new(2.0, yellow)),
+            text_color: Some(Color32::WHITE),
+            progress_paused_color: Some(Color32::from_gray(0xA0)),
+            paused_opacity: 1.0,
+            primary_button: ToastButtonStyle {
+                fill: Some(yellow),
+                stroke: Some(Stroke::NONE),
+                text_color: Some(Color32::BLACK),
+            },
+            secondary_button: ToastButtonStyle {
+                fill: Some(Color32::BLACK),
+                stroke: Some(Stroke::new(1.0, Color32::WHITE)),
+                text_color: Some(Color32::WHITE),
+            },
+            ..Default::default()
+        }
+        .kind_colors([
+            Color32::from_rgb(0x00, 0xFF, 0xFF),
+            yellow,
+            Color32::from_rgb(0xFF, 0x50, 0x50),
+            Color32::from_rgb(0x00, 0xFF, 0x00),
+        ])
+    }
+
+    /// Set the info, warning, error and success colors, and color the icons with them.
+    fn kind_colors(self, [info, warning, error, success]: [Color32; 4]) -> Self {
+        Self {
+            info_icon: self.info_icon.color(info),
+            warning_icon: self.warning_icon.color(warning),
+            error_icon: self.error_icon.color(error),
+            success_icon: self.success_icon.color(success),
+            info_color: info,
+            warning_color: warning,
+            error_color: error,
+            success_color: success,
+            ..self
+        }
+    }
+}
+
+/// Action button that is only colored text, like the actions of Material Design snackbars.
+fn flat_button(text_color: Color32) -> ToastButtonStyle {
+    ToastButtonStyle {
+        fill: Some(Color32::TRANSPARENT),
+        stroke: Some(Stroke::NONE),
+        text_color: Some(text_color),
+    }
+}
@@ -0,0 +1,102 @@
+use egui::{Context, Id};
+
+use crate::state;
+use crate::{Toast, ToastHandle, ToastId};
+
+/// Adds toasts to a [`Toasts`](crate::Toasts) instance from places that can't borrow it,
+/// such as [custom contents](crate::Toasts::custom_contents) or the callbacks of action buttons.
+///
+/// The toasts are stored in the egui context's memory and added on the next call to
+/// [`Toasts::show`](crate::Toasts::show).
+///
+/// ```
+/// # use std::sync::atomic::{AtomicBool, Ordering};
+/// # use egui_toast::{Toast, ToastKind, Toasts};
+/// let ctx = egui::Context::default();
+/// let mut toasts = Toasts::new();
+/// let queue = toasts.queue();
+/// let retried = AtomicBool::new(false);
+/// let mut toasts = toasts.custom_contents(0, move |ui, toast| {
+///     // A "Retry" button that was clicked on the first frame
+///     if !retried.swap(true, Ordering::Relaxed) {
+///         queue.add(ui.ctx(), Toast::new().text("Retrying…"));
+///     }
+///     ui.label(toast.text.clone())
+/// });
+/// toasts.add(Toast::new().kind(ToastKind::Custom(0)).text("Upload failed"));
+///
+/// let visible_layers = || ctx.memory(|m| m.areas().visible_layer_ids().len());
+/// let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+/// let before = visible_layers();
+/// // The queued toast is added on the next frame, and only once
+/// for _ in 0..3 {
+///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+///     assert_eq!(visible_layers(), before + 1);
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ToastQueue {
+    toasts_id: Id,
+}
+
+impl ToastQueue {
+    pub(crate) fn new(toasts_id: Id) -> Self {
+        Self { toasts_id }
+    }
+
+    /// Add a new toast on the next call to [`Toasts::show`](crate::Toasts::show).
+    ///
+    /// Returns a handle that can be used to control the toast later.
+    pub fn add(self, ctx: &Context, mut toast: Toast) -> ToastHandle {
+        let id = *toast.id.get_or_insert_with(ToastId::next);
+        toast.assign_sequence();
+        ctx.data_mut(|d| {
+            d.get_temp_mut_or_default::<Vec<Toast>>(queue_id(self.toasts_id))
+                .push(toast);
+        });
+        state::set_active(ctx, self.toasts_id, true);
+        ToastHandle::new(self.toasts_id, id)
+    }
+}
+
+fn queue_id(toasts_id: Id) -> Id {
+    toasts_id.with("queue")
+}
+
+/// The toasts added through the [`ToastQueue`] that were not taken yet.
+pub(crate) fn peek(ctx: &Context, toasts_id: Id) -> Vec<Toast> {
+    ctx.data(|d| d.get_temp(queue_id(toasts_id)))
+        .unwrap_or_default()
+}
+
+/// Whether toasts were added through the [`ToastQueue`] and not taken yet.
+pub(crate) fn is_empty(ctx: &Context, toasts_id: Id) -> bool {
+    // `get_temp` would clone the queue
+    ctx.data_mut(|d| {
+        d.get_temp_mut_or_default::<Vec<Toast>>(queue_id(toasts_id))
+            .is_empty()
+    })
+}
+
+/// Number of toasts added through the [`ToastQueue`] and not taken yet.
+pub(crate) fn len(ctx: &Context, toasts_id: Id) -> usize {
+    ctx.data_mut(|d| {
+        d.get_temp_mut_or_default::<Vec<Toast>>(queue_id(toasts_id))
+            .len()
+    })
+}
+
+/// Put back toasts taken with [`take`], before the toasts queued since then.
+pub(crate) fn restore(ctx: &Context, toasts_id: Id, mut toasts: Vec<Toast>) {
+    ctx.data_mut(|d| {
+        let queue = d.get_temp_mut_or_default::<Vec<Toast>>(queue_id(toasts_id));
+        toasts.append(queue);
+        *queue = toasts;
+    });
+}
+
+/// Take the toasts added through the [`ToastQueue`].
+pub(crate) fn take(ctx: &Context, toasts_id: Id) -> Vec<Toast> {
+    ctx.data_mut(|d| d.remove_temp(queue_id(toasts_id)))
+        .unwrap_or_default()
+}
@@ -0,0 +1,37 @@
+use egui::{Context, Id, TextStyle, TextWrapMode, Ui};
+
+use crate::{Toast, ToastId};
+
+fn widest_id(toast_id: ToastId) -> Id {
+    toast_id.text_id().with("widest")
+}
+
+/// Forget the widest text of a toast, so that a toast added with the id of an earlier toast
+/// does not keep its width.
+pub(crate) fn reset(ctx: &Context, toast_id: ToastId) {
+    ctx.data_mut(|d| d.remove::<f32>(widest_id(toast_id)));
+}
+
+/// Width kept for the text of the toast: the width of the
+/// [sample](Toast::reserve_width_for), and the widest text seen so far if the toast
+/// [never shrinks](crate::ToastOptions::never_shrink).
+pub(crate) fn text_width(ui: &Ui, toast: &Toast, text_id: ToastId, width: f32) -> f32 {
+    let mut reserved = width;
+    if let Some(sample) = &toast.width_sample {
+        let galley = sample.clone().into_galley(
+            ui,
+            Some(TextWrapMode::Extend),
+            f32::INFINITY,
+            TextStyle::Body,
+        );
+        reserved = reserved.max(galley.size().x);
+    }
+    if toast.options.never_shrink {
+        reserved = ui.data_mut(|d| {
+            let widest = d.get_temp_mut_or_default::<f32>(widest_id(text_id));
+            *widest = widest.max(reserved);
+            *widest
+        });
+    }
+    reserved
+}
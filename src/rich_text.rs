@@ -0,0 +1,120 @@
+use egui::text::LayoutJob;
+use egui::{Align, Color32, FontSelection, RichText, Style, WidgetText};
+
+/// Text of a toast with emphasized parts, see [`Toast::rich_text`](crate::Toast::rich_text).
+///
+/// Unlike a hand-built [`LayoutJob`], the colors are picked when the toast is drawn, so they
+/// follow the current visuals, and the text wraps like any other toast text.
+///
+/// ```
+/// # use egui_toast::{Toast, ToastText};
+/// let text = ToastText::new()
+///     .plain("Deleted ")
+///     .strong("project.rs")
+///     .weak(" (42 KB)");
+/// let toast = Toast::new().rich_text(text);
+/// assert_eq!(toast.plain_text(), "Deleted project.rs (42 KB)");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ToastText {
+    fragments: Vec<(Emphasis, String)>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Emphasis {
+    Plain,
+    Strong,
+    Weak,
+    Accent,
+    Code,
+    /// Text colored by ANSI escape sequences, see [`Toast::ansi_text`](crate::Toast::ansi_text).
+    #[cfg(feature = "ansi")]
+    Ansi {
+        bold: bool,
+        color: Option<Color32>,
+    },
+}
+
+impl ToastText {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn push(mut self, emphasis: Emphasis, text: impl Into<String>) -> Self {
+        self.fragments.push((emphasis, text.into()));
+        self
+    }
+
+    /// Text in the normal text color.
+    #[must_use]
+    pub fn plain(self, text: impl Into<String>) -> Self {
+        self.push(Emphasis::Plain, text)
+    }
+
+    /// Text in the strong text color of the visuals.
+    #[must_use]
+    pub fn strong(self, text: impl Into<String>) -> Self {
+        self.push(Emphasis::Strong, text)
+    }
+
+    /// Text in the weak text color of the visuals.
+    #[must_use]
+    pub fn weak(self, text: impl Into<String>) -> Self {
+        self.push(Emphasis::Weak, text)
+    }
+
+    /// Text in the [accent color](crate::ToastStyle::accent_color) of the kind of the toast.
+    #[must_use]
+    pub fn accent(self, text: impl Into<String>) -> Self {
+        self.push(Emphasis::Accent, text)
+    }
+
+    /// Text in a monospace font, e.g. for file names or commands.
+    #[must_use]
+    pub fn code(self, text: impl Into<String>) -> Self {
+        self.push(Emphasis::Code, text)
+    }
+
+    /// Text with the formatting of ANSI escape sequences. Bold text is shown as strong text,
+    /// unless it is also colored.
+    #[cfg(feature = "ansi")]
+    #[must_use]
+    pub(crate) fn ansi(self, text: impl Into<String>, bold: bool, color: Option<Color32>) -> Self {
+        self.push(Emphasis::Ansi { bold, color }, text)
+    }
+
+    /// The text without the emphasis.
+    pub fn plain_text(&self) -> String {
+        self.fragments
+            .iter()
+            .map(|(_, text)| text.as_str())
+            .collect()
+    }
+
+    /// Lay out the text with the colors of `style`, and `accent` for the accented parts.
+    ///
+    /// The default toast contents do this already, this is for custom contents.
+    pub fn to_widget_text(&self, style: &Style, accent: Color32) -> WidgetText {
+        let mut job = LayoutJob::default();
+        for (emphasis, text) in &self.fragments {
+            let text = RichText::new(text);
+            let text = match emphasis {
+                Emphasis::Plain => text,
+                Emphasis::Strong => text.strong(),
+                Emphasis::Weak => text.weak(),
+                Emphasis::Accent => text.color(accent),
+                Emphasis::Code => text.code(),
+                #[cfg(feature = "ansi")]
+                Emphasis::Ansi { bold, color } => {
+                    let text = if *bold { text.strong() } else { text };
+                    match color {
+                        Some(color) => text.color(*color),
+                        None => text,
+                    }
+                }
+            };
+            text.append_to(&mut job, style, FontSelection::Default, Align::Center);
+        }
+        WidgetText::LayoutJob(job)
+    }
+}
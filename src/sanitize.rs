@@ -0,0 +1,127 @@
+use std::iter::Peekable;
+use std::str::Chars;
+
+use crate::{Toast, WidgetText};
+
+/// Cleans up text from external sources, such as the output of another process, before it is
+/// shown in a toast. See [`Toasts::sanitize_text`](crate::Toasts::sanitize_text).
+///
+/// The sanitizer strips ANSI escape sequences and other control characters except tabs,
+/// normalizes CRLF and CR line endings to LF, collapses runs of blank lines and caps
+/// the length of the text.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TextSanitizer {
+    /// Number of consecutive blank lines kept, the rest are removed.
+    pub max_blank_lines: usize,
+    /// Number of characters kept, including the ellipsis that replaces the rest of a longer
+    /// text. The length is not limited if `None`.
+    pub max_chars: Option<usize>,
+}
+
+impl Default for TextSanitizer {
+    fn default() -> Self {
+        Self {
+            max_blank_lines: 1,
+            max_chars: Some(500),
+        }
+    }
+}
+
+impl TextSanitizer {
+    pub fn sanitize(&self, text: &str) -> String {
+        let mut cleaned = String::with_capacity(text.len());
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\u{1b}' => skip_escape_sequence(&mut chars),
+                '\r' => {
+                    chars.next_if_eq(&'\n');
+                    cleaned.push('\n');
+                }
+                '\n' | '\t' => cleaned.push(c),
+                c if c.is_control() => {}
+                c => cleaned.push(c),
+            }
+        }
+
+        let mut lines = Vec::new();
+        let mut blank_lines = 0;
+        for line in cleaned.split('\n') {
+            if line.trim().is_empty() {
+                blank_lines += 1;
+                if blank_lines > self.max_blank_lines {
+                    continue;
+                }
+            } else {
+                blank_lines = 0;
+            }
+            lines.push(line);
+        }
+        let mut text = lines.join("\n");
+
+        if let Some(max_chars) = self.max_chars {
+            if text.chars().nth(max_chars).is_some() {
+                let end = text
+                    .char_indices()
+                    .nth(max_chars.saturating_sub(1))
+                    .map_or(text.len(), |(index, _)| index);
+                text.truncate(end);
+                if max_chars > 0 {
+                    text.push('…');
+                }
+            }
+        }
+        text
+    }
+}
+
+/// Clean up `text` with the default [`TextSanitizer`].
+///
+/// ```
+/// let text = "\x1b[31merror:\x1b[0m build failed\r\n\r\n\r\n\r\nsee log";
+/// assert_eq!(egui_toast::sanitize_text(text), "error: build failed\n\nsee log");
+/// ```
+pub fn sanitize_text(text: &str) -> String {
+    TextSanitizer::default().sanitize(text)
+}
+
+/// Skip the rest of an escape sequence after the escape character.
+pub(crate) fn skip_escape_sequence(chars: &mut Peekable<Chars>) {
+    match chars.next() {
+        // Control sequence: parameters and intermediate bytes, ended by a final byte
+        Some('[') => {
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) {
+                    break;
+                }
+            }
+        }
+        // Operating system command: ended by BEL or ESC \
+        Some(']') => {
+            while let Some(c) = chars.next() {
+                if c == '\u{7}' {
+                    break;
+                }
+                if c == '\u{1b}' {
+                    chars.next_if_eq(&'\\');
+                    break;
+                }
+            }
+        }
+        // The other sequences are two characters long
+        _ => {}
+    }
+}
+
+/// Sanitize the text of the toast. Only plain and rich text is sanitized, and the formatting
+/// of rich text is lost if its text changes.
+pub(crate) fn apply(sanitizer: &TextSanitizer, toast: &mut Toast) {
+    if let WidgetText::RichText(text) = &toast.text {
+        let sanitized = sanitizer.sanitize(text.text());
+        if sanitized != text.text() {
+            toast.text = sanitized.into();
+        }
+    }
+}
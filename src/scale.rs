@@ -0,0 +1,47 @@
+use egui::{Context, Id, Style, Ui, Vec2};
+
+use crate::ToastId;
+
+fn scale_id(toast_id: ToastId) -> Id {
+    toast_id.area_id().with("scale")
+}
+
+/// Store the [scale](crate::Toasts::scale) of a toast for [`Toast::scale`](crate::Toast::scale)
+/// before the toast is drawn.
+pub(crate) fn store(ctx: &Context, toast_id: ToastId, scale: f32) {
+    ctx.data_mut(|d| d.insert_temp(scale_id(toast_id), scale));
+}
+
+pub(crate) fn load(ctx: &Context, toast_id: ToastId) -> f32 {
+    ctx.data(|d| d.get_temp(scale_id(toast_id)).unwrap_or(1.0))
+}
+
+/// Scale a length, rounded to whole physical pixels so that the edges stay crisp.
+pub(crate) fn length(ctx: &Context, length: f32, scale: f32) -> f32 {
+    let pixels_per_point = ctx.pixels_per_point();
+    (length * scale * pixels_per_point).round() / pixels_per_point
+}
+
+/// Scale the text sizes and the spacing of the egui style of a toast, so that the contents of
+/// the toast, including custom contents, are drawn at the scale.
+pub(crate) fn apply(ui: &mut Ui, scale: f32) {
+    if scale == 1.0 {
+        return;
+    }
+    let ctx = ui.ctx().clone();
+    let vec2 = |v: Vec2| Vec2::new(length(&ctx, v.x, scale), length(&ctx, v.y, scale));
+    let style: &mut Style = ui.style_mut();
+    for font_id in style.text_styles.values_mut() {
+        font_id.size *= scale;
+    }
+    if let Some(font_id) = &mut style.override_font_id {
+        font_id.size *= scale;
+    }
+    let spacing = &mut style.spacing;
+    spacing.item_spacing = vec2(spacing.item_spacing);
+    spacing.button_padding = vec2(spacing.button_padding);
+    spacing.interact_size = vec2(spacing.interact_size);
+    spacing.icon_width = length(&ctx, spacing.icon_width, scale);
+    spacing.icon_width_inner = length(&ctx, spacing.icon_width_inner, scale);
+    spacing.icon_spacing = length(&ctx, spacing.icon_spacing, scale);
+}
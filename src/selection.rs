@@ -0,0 +1,54 @@
+use egui::text_selection::LabelSelectionState;
+use egui::{Context, Id, Response};
+
+use crate::state::StoredToast;
+use crate::ToastId;
+
+/// Id of the flag that is set while the text of the toast is being selected or has a selection.
+fn selecting_id(toast_id: ToastId) -> Id {
+    toast_id.text_id().with("selecting")
+}
+
+/// Follow the selection in the [selectable text](crate::ToastOptions::selectable_text) of the
+/// toast, drawn with `response`. egui keeps a single selection across all labels, so the
+/// selection counts as the toast's from the drag or click that started it in the text until
+/// egui clears it or the pointer is pressed somewhere else.
+pub(crate) fn update(ctx: &Context, toast_id: ToastId, response: &Response) {
+    let id = selecting_id(toast_id);
+    let started = response.drag_started()
+        || response.dragged()
+        || response.double_clicked()
+        || response.triple_clicked();
+    let selecting = started
+        || ctx.data(|d| d.get_temp::<bool>(id).unwrap_or(false)) && {
+            let pressed_elsewhere = ctx.input(|i| {
+                i.pointer.any_pressed()
+                    && !i
+                        .pointer
+                        .interact_pos()
+                        .is_some_and(|pos| response.rect.contains(pos))
+            });
+            !pressed_elsewhere && LabelSelectionState::load(ctx).has_selection()
+        };
+    ctx.data_mut(|d| {
+        if selecting {
+            d.insert_temp(id, true);
+        } else {
+            d.remove::<bool>(id);
+        }
+    });
+}
+
+/// Whether the text of the toast is being selected or has a selection, which pauses the toast.
+pub(crate) fn is_selecting(ctx: &Context, toast_id: ToastId) -> bool {
+    ctx.data(|d| d.get_temp::<bool>(selecting_id(toast_id)).unwrap_or(false))
+}
+
+/// Whether the text of any of the toasts is being selected, e.g. to be copied with the
+/// keyboard.
+pub(crate) fn any_selecting(ctx: &Context, toasts: &[StoredToast]) -> bool {
+    toasts
+        .iter()
+        .filter_map(|stored| stored.toast.id)
+        .any(|toast_id| is_selecting(ctx, toast_id))
+}
@@ -0,0 +1,57 @@
+use crate::motion::Motion;
+use crate::state::StoredToast;
+use crate::DismissReason;
+
+/// How long it takes for a toast to fade out when it is superseded, and to fade in after that.
+const FADE_SECS: f64 = 0.15;
+
+/// Supersede all but the newest toast, see [`Toasts::single_slot`](crate::Toasts::single_slot).
+///
+/// A toast that is already shown fades out before it is removed, while the toasts that are
+/// still waiting for their turn are removed immediately.
+pub(crate) fn update(toasts: &mut [StoredToast], time: f64) {
+    let live =
+        |stored: &StoredToast| !stored.toast.options.is_expired() && stored.superseded_at.is_none();
+    if let Some(newest) = toasts.iter().rposition(live) {
+        for stored in toasts[..newest].iter_mut().filter(|stored| live(stored)) {
+            if stored.shown_at.is_some() {
+                stored.superseded_at = Some(time);
+            } else {
+                stored.toast.options.dismiss(DismissReason::Superseded);
+            }
+        }
+    }
+    for stored in toasts.iter_mut() {
+        if stored
+            .superseded_at
+            .is_some_and(|at| time - at >= FADE_SECS)
+        {
+            stored.toast.options.dismiss(DismissReason::Superseded);
+        }
+    }
+}
+
+/// Whether a superseded toast is still fading out, in which case the next toast has to wait.
+pub(crate) fn is_fading_out(toasts: &[StoredToast]) -> bool {
+    toasts.iter().any(|stored| stored.superseded_at.is_some())
+}
+
+/// Opacity of a toast in single-slot mode, or `None` if the toast is not shown yet.
+pub(crate) fn opacity(
+    stored: &mut StoredToast,
+    fading_out: bool,
+    time: f64,
+    motion: Motion,
+) -> Option<f32> {
+    if let Some(superseded_at) = stored.superseded_at {
+        Some(1.0 - motion.fade_in(time - superseded_at, FADE_SECS))
+    } else if fading_out {
+        None
+    } else if stored.size.is_none() {
+        // Drawn invisibly to measure it
+        Some(1.0)
+    } else {
+        let shown_at = *stored.shown_at.get_or_insert(time);
+        Some(motion.fade_in(time - shown_at, FADE_SECS))
+    }
+}
@@ -0,0 +1,34 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use egui::{Context, Id};
+
+use crate::{Toast, ToastKind};
+
+pub type ToastSound = dyn Fn() + Send + Sync;
+
+pub type ToastShowCallback = dyn Fn(&Toast) + Send + Sync;
+
+/// Id of the mute toggle in the egui context's persisted memory.
+pub(crate) fn muted_id(toasts_id: Id) -> Id {
+    toasts_id.with("muted")
+}
+
+pub(crate) fn is_muted(ctx: &Context, toasts_id: Id) -> bool {
+    ctx.data_mut(|d| d.get_persisted(muted_id(toasts_id)).unwrap_or(false))
+}
+
+/// Play the sound of the given kind, unless the sounds are muted.
+pub(crate) fn play(
+    ctx: &Context,
+    toasts_id: Id,
+    sounds: &HashMap<ToastKind, Arc<ToastSound>>,
+    kind: ToastKind,
+) {
+    if is_muted(ctx, toasts_id) {
+        return;
+    }
+    if let Some(sound) = sounds.get(&kind) {
+        sound();
+    }
+}
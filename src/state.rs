@@ -0,0 +1,155 @@
+use egui::{Context, Id, Vec2};
+
+use crate::broadcast::Source;
+use crate::escalation::Occurrences;
+use crate::toast::next_sequence;
+use crate::{DismissReason, Toast, ToastId, ToastState};
+
+/// A toast stored in the egui context's memory, together with the state
+/// that [`Toasts::show`](crate::Toasts::show) keeps for it between frames.
+#[derive(Clone)]
+pub(crate) struct StoredToast {
+    pub(crate) toast: Toast,
+    /// Size of the toast measured on the previous frame, or `None` if the toast
+    /// has not been shown yet.
+    pub(crate) size: Option<Vec2>,
+    /// Set with [`ToastHandle::pause`](crate::ToastHandle::pause). The countdown of a paused
+    /// toast is stopped.
+    pub(crate) paused: bool,
+    /// Time the toast was first shown in [single-slot](crate::Toasts::single_slot) mode.
+    pub(crate) shown_at: Option<f64>,
+    /// Time the toast was replaced by a newer toast in single-slot mode. The toast
+    /// fades out before it is removed.
+    pub(crate) superseded_at: Option<f64>,
+    /// Whether the [sound](crate::Toasts::sound_for) of the toast was played and the
+    /// [show hook](crate::Toasts::on_show) was called.
+    pub(crate) announced: bool,
+    /// Whether the countdown of the toast was running on the latest draw. The time of the
+    /// updates is subtracted from the remaining time while this is set.
+    pub(crate) counting_down: bool,
+    /// Whether the remaining time was cut by
+    /// [`Toasts::dismiss_on_input`](crate::Toasts::dismiss_on_input). The toast fades out
+    /// over the rest of it.
+    pub(crate) input_dismissed: bool,
+    /// Number of toasts shown by this toast, including the toasts that were
+    /// [merged](crate::KindLimitPolicy::Merge) into it.
+    pub(crate) count: usize,
+    /// Position of the toast in the stack, see [`Toast::sequence`]. Kept when the toast
+    /// is replaced by a duplicate.
+    pub(crate) sequence: u64,
+    /// Times the toast was repeated, see [`Toasts::escalation`](crate::Toasts::escalation).
+    /// Kept when the toast is replaced by a duplicate.
+    pub(crate) occurrences: Occurrences,
+    /// The toast this is a copy of, if it was [broadcast](crate::ToastOptions::broadcast)
+    /// from another lane.
+    pub(crate) mirror: Option<Source>,
+}
+
+impl StoredToast {
+    pub(crate) fn new(toast: Toast) -> Self {
+        Self {
+            // The toasts shown without being added, e.g. the revealed suppressed toasts, are
+            // placed as if they were added now
+            sequence: toast.sequence.unwrap_or_else(next_sequence),
+            paused: toast.options.start_paused,
+            toast,
+            size: None,
+            shown_at: None,
+            superseded_at: None,
+            announced: false,
+            counting_down: false,
+            input_dismissed: false,
+            count: 1,
+            occurrences: Occurrences::default(),
+            mirror: None,
+        }
+    }
+}
+
+impl StoredToast {
+    /// Phase of the toast on this frame. `appearing` is set while the toast is measured or
+    /// fading in.
+    pub(crate) fn state(&self, ctx: &Context, appearing: bool) -> ToastState {
+        if self.superseded_at.is_some() {
+            ToastState::Closing(DismissReason::Superseded)
+        } else if appearing {
+            ToastState::Appearing
+        } else if self
+            .toast
+            .id
+            .is_some_and(|id| LastFrame::load(ctx, id).hovered)
+        {
+            ToastState::Hovered
+        } else {
+            ToastState::Idle
+        }
+    }
+}
+
+fn state_id(toast_id: ToastId) -> Id {
+    toast_id.area_id().with("state")
+}
+
+/// Store the phase of a toast for [`Toast::state`] before the toast is drawn.
+pub(crate) fn store_state(ctx: &Context, toast_id: ToastId, state: ToastState) {
+    ctx.data_mut(|d| d.insert_temp(state_id(toast_id), state));
+}
+
+pub(crate) fn load_state(ctx: &Context, toast_id: ToastId) -> ToastState {
+    ctx.data(|d| d.get_temp(state_id(toast_id)).unwrap_or_default())
+}
+
+fn active_id(toasts_id: Id) -> Id {
+    toasts_id.with("active")
+}
+
+/// Whether toasts are stored or queued under `toasts_id`. This is a single flag, so that
+/// [`Toasts::show`](crate::Toasts::show) can return right away on idle frames.
+pub(crate) fn is_active(ctx: &Context, toasts_id: Id) -> bool {
+    ctx.data(|d| d.get_temp(active_id(toasts_id)).unwrap_or(false))
+}
+
+pub(crate) fn set_active(ctx: &Context, toasts_id: Id, active: bool) {
+    ctx.data_mut(|d| d.insert_temp(active_id(toasts_id), active));
+}
+
+/// Remove the closed and expired toasts, and tell them why they were removed. Returns the
+/// removed toasts with the reasons.
+pub(crate) fn remove_dismissed(toasts: &mut Vec<StoredToast>) -> Vec<(ToastId, DismissReason)> {
+    let mut removed = Vec::new();
+    toasts.retain(|stored| {
+        let options = stored.toast.options;
+        if !options.is_expired() {
+            return true;
+        }
+        let reason = options.dismissed.unwrap_or(DismissReason::Expired);
+        stored.toast.notify_dismissed(reason);
+        if let Some(id) = stored.toast.id {
+            removed.push((id, reason));
+        }
+        false
+    });
+    removed
+}
+
+/// State of a toast on the previous frame, so that it can be read while drawing the toast.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct LastFrame {
+    pub(crate) hovered: bool,
+    /// Whether the countdown was stopped, by hovering or by [`ToastHandle::pause`](crate::ToastHandle::pause).
+    pub(crate) paused: bool,
+}
+
+impl LastFrame {
+    fn id(toast_id: ToastId) -> Id {
+        toast_id.area_id().with("last_frame")
+    }
+
+    pub(crate) fn load(ctx: &Context, toast_id: ToastId) -> Self {
+        ctx.data(|d| d.get_temp(Self::id(toast_id)).unwrap_or_default())
+    }
+
+    pub(crate) fn store(self, ctx: &Context, toast_id: ToastId) {
+        ctx.data_mut(|d| d.insert_temp(Self::id(toast_id), self));
+    }
+}
@@ -0,0 +1,12 @@
+use crate::{DismissReason, ToastId};
+
+/// What [`Toasts::update`](crate::Toasts::update) did, for laying out the app around the
+/// toasts before they are drawn, or for reacting to the dismissals in one place.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ToastsSummary {
+    /// The toasts drawn by the next [`Toasts::draw`](crate::Toasts::draw), in the order they
+    /// are stacked. This includes the toasts in collapsed groups and the toasts fading out.
+    pub toasts: Vec<ToastId>,
+    /// The toasts removed by the update, and why.
+    pub dismissed: Vec<(ToastId, DismissReason)>,
+}
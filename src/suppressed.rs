@@ -0,0 +1,90 @@
+use std::collections::{HashMap, VecDeque};
+
+use egui::{Context, Id};
+
+use crate::{Toast, ToastKind, ToastStrings};
+
+/// Toasts that were rejected or dropped when they were added, see
+/// [`Toasts::suppressed_badge`](crate::Toasts::suppressed_badge).
+#[derive(Clone, Default)]
+pub(crate) struct SuppressedToasts {
+    pub(crate) counts: HashMap<ToastKind, usize>,
+    /// The latest suppressed toasts, up to [`Toasts::retain_suppressed`](crate::Toasts::retain_suppressed).
+    retained: VecDeque<Toast>,
+}
+
+impl SuppressedToasts {
+    fn id(toasts_id: Id) -> Id {
+        toasts_id.with("suppressed")
+    }
+
+    pub(crate) fn load(ctx: &Context, toasts_id: Id) -> Self {
+        ctx.data(|d| d.get_temp(Self::id(toasts_id)).unwrap_or_default())
+    }
+
+    pub(crate) fn store(self, ctx: &Context, toasts_id: Id) {
+        ctx.data_mut(|d| {
+            if self.counts.is_empty() {
+                d.remove::<Self>(Self::id(toasts_id));
+            } else {
+                d.insert_temp(Self::id(toasts_id), self);
+            }
+        });
+    }
+
+    /// Count the toast, keeping the latest `retain` toasts.
+    pub(crate) fn add(&mut self, toast: Toast, retain: usize) {
+        *self.counts.entry(toast.kind).or_default() += 1;
+        if retain > 0 {
+            self.retained.push_back(toast);
+            while self.retained.len() > retain {
+                self.retained.pop_front();
+            }
+        }
+    }
+
+    pub(crate) fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// The counts of the kinds, the most severe first, one per line. Custom kinds are
+    /// counted as info toasts.
+    pub(crate) fn tooltip(&self, strings: &ToastStrings) -> String {
+        let mut lines: Vec<(u8, &str, usize)> = Vec::new();
+        for (kind, count) in &self.counts {
+            let label = strings.kind_label(*kind);
+            match lines.iter_mut().find(|line| line.1 == label) {
+                Some(line) => line.2 += count,
+                None => lines.push((kind.severity(), label, *count)),
+            }
+        }
+        lines.sort_by_key(|&(severity, label, _)| (std::cmp::Reverse(severity), label));
+        lines
+            .into_iter()
+            .map(|(_, label, count)| format!("{label}: {count}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Clear the counts and take the retained toasts, to be shown as new toasts. Their
+    /// [dismiss callbacks](Toast::on_dismiss) were called when they were suppressed, so
+    /// they are removed.
+    pub(crate) fn take(&mut self) -> Vec<Toast> {
+        self.counts.clear();
+        self.retained
+            .drain(..)
+            .map(|mut toast| {
+                (toast.id, toast.sequence) = (None, None);
+                toast.on_dismiss = None;
+                toast
+            })
+            .collect()
+    }
+}
+
+/// Count a suppressed toast if the badge is enabled.
+pub(crate) fn record(suppressed: &mut Option<SuppressedToasts>, toast: Toast, retain: usize) {
+    if let Some(suppressed) = suppressed {
+        suppressed.add(toast, retain);
+    }
+}
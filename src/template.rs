@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use egui::{Response, Ui};
+
+use crate::{Toast, ToastButton, ToastContents, ToastKind, ToastOptions, ToastStyle, WidgetText};
+
+/// A canonical toast format, such as "destructive action" or "tip", registered by name
+/// with [`Toasts::register_template`](crate::Toasts::register_template).
+///
+/// The toasts made from a template are regular toasts, so anything can be changed per
+/// toast with the builder methods of [`Toast`].
+///
+/// ```
+/// # use egui_toast::{Toast, ToastButton, ToastKind, ToastOptions, ToastTemplate};
+/// let destructive = ToastTemplate::new(ToastKind::Warning)
+///     .options(ToastOptions::default().duration_in_seconds(8.0))
+///     .button(ToastButton::new("Undo").primary(true));
+/// let toast = destructive.toast("Project deleted");
+/// assert_eq!(toast.kind, ToastKind::Warning);
+/// assert_eq!(toast.buttons.len(), 1);
+/// ```
+#[derive(Clone, Default)]
+pub struct ToastTemplate {
+    pub kind: ToastKind,
+    pub options: ToastOptions,
+    /// Style of the toasts, like [`Toast::style`].
+    pub style: Option<ToastStyle>,
+    /// Icon shown instead of the icon of the style, like [`Toast::icon`].
+    pub icon: Option<WidgetText>,
+    pub buttons: Vec<ToastButton>,
+    /// Renderer of the toasts, see [`Self::contents`].
+    pub contents: Option<Arc<ToastContents>>,
+}
+
+impl ToastTemplate {
+    pub fn new(kind: impl Into<ToastKind>) -> Self {
+        Self {
+            kind: kind.into(),
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub fn options(mut self, options: ToastOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: ToastStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    #[must_use]
+    pub fn icon(mut self, icon: impl Into<WidgetText>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Add an action button to the toasts.
+    #[must_use]
+    pub fn button(mut self, button: ToastButton) -> Self {
+        self.buttons.push(button);
+        self
+    }
+
+    /// Draw the toasts with a custom renderer.
+    ///
+    /// The renderer is registered for the kind of the template when the template is
+    /// registered, like with [`Toasts::custom_contents`](crate::Toasts::custom_contents),
+    /// so it is also used for the other toasts of the kind. Give the template a
+    /// [custom kind](ToastKind::Custom) to draw only its toasts with the renderer.
+    #[must_use]
+    pub fn contents(
+        mut self,
+        add_contents: impl Fn(&mut Ui, &mut Toast) -> Response + Send + Sync + 'static,
+    ) -> Self {
+        self.contents = Some(Arc::new(add_contents));
+        self
+    }
+
+    /// Make a toast with the template and the given text.
+    pub fn toast(&self, text: impl Into<WidgetText>) -> Toast {
+        Toast {
+            kind: self.kind,
+            text: text.into(),
+            options: self.options,
+            style: self.style.clone(),
+            icon: self.icon.clone(),
+            buttons: self.buttons.clone(),
+            ..Default::default()
+        }
+    }
+}
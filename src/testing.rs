@@ -0,0 +1,231 @@
+//! Run toasts in a headless egui context, for testing the behavior of toasts over
+//! several frames. Needs the `testing` feature.
+//!
+//! ```
+//! # use std::time::Duration;
+//! # use egui_toast::testing::ToastTestCtx;
+//! # use egui_toast::{Toast, ToastOptions, Toasts};
+//! let mut test = ToastTestCtx::new(Toasts::new());
+//! let options = ToastOptions::default().duration_in_seconds(1.0);
+//! let handle = test.add(Toast::new().text("Saved").options(options));
+//! test.run_frames(2);
+//! assert!(test.is_visible(handle.id()));
+//!
+//! test.run_for(Duration::from_secs(2), 10);
+//! assert!(!test.is_visible(handle.id()));
+//! assert!(test.stored_toasts().is_empty());
+//! ```
+
+use std::time::Duration;
+
+use egui::{Context, Event, Key, LayerId, Modifiers, PointerButton, Pos2, RawInput, Rect};
+
+use crate::state::StoredToast;
+use crate::{MockTime, Toast, ToastHandle, ToastId, Toasts};
+
+/// A headless egui context that shows a [`Toasts`] instance on every frame, with a
+/// [mock clock](MockTime) and a simulated pointer.
+pub struct ToastTestCtx {
+    ctx: Context,
+    toasts: Toasts,
+    time: MockTime,
+    pointer: Option<Pos2>,
+    events: Vec<Event>,
+}
+
+impl ToastTestCtx {
+    /// Show the toasts in a new context. The [time source](Toasts::time_source) of the toasts
+    /// is replaced with the clock of the test context.
+    pub fn new(toasts: Toasts) -> Self {
+        let time = MockTime::new();
+        Self {
+            ctx: Context::default(),
+            toasts: toasts.time_source(time.clone()),
+            time,
+            pointer: None,
+            events: Vec::new(),
+        }
+    }
+
+    /// The egui context the toasts are shown in.
+    pub fn ctx(&self) -> &Context {
+        &self.ctx
+    }
+
+    /// The toasts shown on every frame, e.g. to add toasts or change their settings.
+    pub fn toasts(&mut self) -> &mut Toasts {
+        &mut self.toasts
+    }
+
+    /// The clock of the toasts and the egui input.
+    pub fn time(&self) -> &MockTime {
+        &self.time
+    }
+
+    /// Add a toast, shown on the next frame.
+    pub fn add(&mut self, toast: Toast) -> ToastHandle {
+        self.toasts.add(toast)
+    }
+
+    /// Run a single frame at the current time.
+    pub fn run(&mut self) {
+        self.run_with(|_| {});
+    }
+
+    /// Run a single frame at the current time, drawing the UI of the app before the toasts.
+    pub fn run_with(&mut self, add_contents: impl FnOnce(&Context)) {
+        let mut events = std::mem::take(&mut self.events);
+        if let Some(pos) = self.pointer {
+            events.push(Event::PointerMoved(pos));
+        }
+        let input = RawInput {
+            time: Some(self.time.get()),
+            events,
+            ..Default::default()
+        };
+        let toasts = &mut self.toasts;
+        let mut add_contents = Some(add_contents);
+        let _ = self.ctx.run(input, |ctx| {
+            if let Some(add_contents) = add_contents.take() {
+                add_contents(ctx);
+            }
+            toasts.show(ctx);
+        });
+    }
+
+    /// Run `frames` frames without moving the time. New toasts are measured on their first
+    /// frame and drawn on the next one.
+    pub fn run_frames(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.run();
+        }
+    }
+
+    /// Move the time forward by `duration` over the given number of frames, at a
+    /// constant frame rate.
+    pub fn run_for(&mut self, duration: Duration, frames: u32) {
+        let frame_time = duration / frames.max(1);
+        for _ in 0..frames.max(1) {
+            self.time.advance(frame_time);
+            self.run();
+        }
+    }
+
+    /// Move the time forward without running a frame, like an app that is not redrawn.
+    pub fn advance(&mut self, duration: Duration) {
+        self.time.advance(duration);
+    }
+
+    /// Keep the pointer at `pos` on the following frames.
+    pub fn hover(&mut self, pos: Pos2) {
+        self.pointer = Some(pos);
+    }
+
+    /// Keep the pointer at the center of the toast on the following frames. Returns `false`
+    /// if the toast is not visible.
+    pub fn hover_toast(&mut self, id: ToastId) -> bool {
+        match self.rect(id) {
+            Some(rect) => {
+                self.hover(rect.center());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Click the primary mouse button at `pos` on the next frame. The pointer stays at `pos`.
+    pub fn click(&mut self, pos: Pos2) {
+        self.click_button(pos, PointerButton::Primary);
+    }
+
+    /// Click the secondary mouse button at `pos` on the next frame, e.g. to open a context menu.
+    /// The pointer stays at `pos`.
+    pub fn secondary_click(&mut self, pos: Pos2) {
+        self.click_button(pos, PointerButton::Secondary);
+    }
+
+    fn click_button(&mut self, pos: Pos2, button: PointerButton) {
+        self.hover(pos);
+        for pressed in [true, false] {
+            self.events.push(Event::PointerButton {
+                pos,
+                button,
+                pressed,
+                modifiers: Modifiers::NONE,
+            });
+        }
+    }
+
+    /// Press the primary mouse button at `pos` on the next frame and hold it, e.g. to drag
+    /// the pointer with [`Self::hover`] until [`Self::release`].
+    pub fn press(&mut self, pos: Pos2) {
+        self.hover(pos);
+        self.pointer_button(pos, true);
+    }
+
+    /// Release the primary mouse button at the pointer on the next frame.
+    pub fn release(&mut self) {
+        let pos = self.pointer.unwrap_or_default();
+        self.pointer_button(pos, false);
+    }
+
+    fn pointer_button(&mut self, pos: Pos2, pressed: bool) {
+        self.events.push(Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        });
+    }
+
+    /// Press and release `key` on the next frame.
+    pub fn press_key(&mut self, key: Key) {
+        for pressed in [true, false] {
+            self.events.push(Event::Key {
+                key,
+                physical_key: None,
+                pressed,
+                repeat: false,
+                modifiers: Modifiers::NONE,
+            });
+        }
+    }
+
+    /// Move the pointer out of the window.
+    pub fn leave(&mut self) {
+        if self.pointer.take().is_some() {
+            self.events.push(Event::PointerGone);
+        }
+    }
+
+    /// Whether the toast was drawn on its own on the latest frame. Toasts shown in a
+    /// [group](Toast::group) are drawn in the area of the group.
+    pub fn is_visible(&self, id: ToastId) -> bool {
+        self.rect(id).is_some()
+    }
+
+    /// Area the toast was drawn in on the latest frame, or `None` if it was not drawn on
+    /// its own.
+    pub fn rect(&self, id: ToastId) -> Option<Rect> {
+        let layer_id = LayerId::new(self.toasts.order, id.area_id());
+        self.ctx.memory(|m| {
+            m.areas()
+                .visible_layer_ids()
+                .contains(&layer_id)
+                .then(|| m.area_rect(id.area_id()))
+                .flatten()
+        })
+    }
+
+    /// The toasts stored in the context, in the order they are stacked. Toasts that were
+    /// added but not shown yet are not included.
+    pub fn stored_toasts(&self) -> Vec<Toast> {
+        self.ctx.data(|d| {
+            d.get_temp::<Vec<StoredToast>>(self.toasts.id)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|stored| stored.toast)
+                .collect()
+        })
+    }
+}
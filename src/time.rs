@@ -0,0 +1,111 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use egui::Context;
+
+/// Time scales below zero are treated as zero, and NaN as the normal speed.
+pub(crate) fn clamp_scale(scale: f32) -> f32 {
+    if scale.is_nan() {
+        1.0
+    } else {
+        scale.max(0.0)
+    }
+}
+
+/// Wall-clock time that `duration` of toast time takes at the time scale, or `None` if it
+/// never passes or is too long for a [`Duration`], e.g. at a tiny scale.
+pub(crate) fn unscaled(duration: Duration, scale: f32) -> Option<Duration> {
+    (scale > 0.0)
+        .then(|| Duration::try_from_secs_f64(duration.as_secs_f64() / f64::from(scale)).ok())
+        .flatten()
+}
+
+/// The clock the toasts expire by, see [`Toasts::time_source`](crate::Toasts::time_source).
+///
+/// The time is in seconds and must not go backwards. The toasts only use the differences
+/// between the times, so the starting point can be anything.
+pub trait TimeSource: Send + Sync {
+    fn now(&self, ctx: &Context) -> f64;
+}
+
+/// The time of the egui input, [`egui::InputState::time`]. This is the default time source.
+///
+/// The integration provides the time, so unlike [`std::time::Instant`] this works on
+/// `wasm32-unknown-unknown` as well.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct EguiTime;
+
+impl TimeSource for EguiTime {
+    fn now(&self, ctx: &Context) -> f64 {
+        ctx.input(|i| i.time)
+    }
+}
+
+/// A clock that only moves when told to, for testing the timing of toasts without
+/// waiting or faking the egui input.
+///
+/// Clones share the time, so one clone can be given to the toasts and another kept
+/// for moving the time forward.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use egui_toast::{MockTime, Toast, ToastOptions, Toasts};
+/// let ctx = egui::Context::default();
+/// let visible = |handle: egui_toast::ToastHandle| {
+///     ctx.read_response(handle.id().close_button_id()).is_some()
+/// };
+/// let time = MockTime::new();
+/// let mut toasts = Toasts::new().time_source(time.clone());
+/// let handle = toasts.add(
+///     Toast::new()
+///         .text("Saved")
+///         .options(ToastOptions::default().duration_in_seconds(2.0)),
+/// );
+/// for _ in 0..3 {
+///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+/// }
+///
+/// time.advance(Duration::from_millis(1500));
+/// let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+/// assert!(visible(handle));
+///
+/// time.advance(Duration::from_secs(1));
+/// for _ in 0..2 {
+///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+/// }
+/// assert!(!visible(handle));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct MockTime {
+    /// Bits of the time in seconds as `f64`.
+    seconds: Arc<AtomicU64>,
+}
+
+impl MockTime {
+    /// A clock at zero seconds.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the time, in seconds.
+    pub fn set(&self, seconds: f64) {
+        self.seconds.store(seconds.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Move the time forward.
+    pub fn advance(&self, duration: Duration) {
+        self.set(self.get() + duration.as_secs_f64());
+    }
+
+    /// The current time, in seconds.
+    pub fn get(&self) -> f64 {
+        f64::from_bits(self.seconds.load(Ordering::Relaxed))
+    }
+}
+
+impl TimeSource for MockTime {
+    fn now(&self, _ctx: &Context) -> f64 {
+        self.get()
+    }
+}
@@ -1,7 +1,100 @@
-use egui::{Color32, WidgetText};
+use egui::{
+    Color32, Context, FontId, Frame, Galley, Id, Rounding, Shadow, Stroke, Style, TextStyle,
+    TextWrapMode, Ui, Vec2, Visuals, WidgetText,
+};
+
+use crate::close::{self, CloseDecision, ToastCloseCallback};
+use crate::context_menu::ToastMenuExtra;
+use crate::contrast;
+use crate::scale;
+use crate::state::{self, LastFrame};
+use crate::{BlipStyle, ToastButton, ToastButtonStyle, ToastText};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
+/// Unique identifier of a toast, assigned when the toast is added with [`Toasts::add`](crate::Toasts::add).
+///
+/// The ids of the widgets drawn by the default toast contents are derived from this id,
+/// so they stay the same for the whole lifetime of the toast. This makes it possible to
+/// find the widgets in tests, e.g. with [`egui::Context::read_response`].
+///
+/// ```
+/// # use egui_toast::{Toast, ToastOptions, Toasts};
+/// # fn run(ctx: &egui::Context, toasts: &mut Toasts, time: f64, events: Vec<egui::Event>) {
+/// #     let input = egui::RawInput { time: Some(time), events, ..Default::default() };
+/// #     let _ = ctx.run(input, |ctx| toasts.show(ctx));
+/// # }
+/// # fn click(pos: egui::Pos2, pressed: bool) -> egui::Event {
+/// #     egui::Event::PointerButton {
+/// #         pos,
+/// #         button: egui::PointerButton::Primary,
+/// #         pressed,
+/// #         modifiers: Default::default(),
+/// #     }
+/// # }
+/// let ctx = egui::Context::default();
+/// let mut toasts = Toasts::new();
+/// let id = toasts.add(Toast::new().text("Hello").options(ToastOptions::default().duration_in_seconds(1.0))).id();
+/// run(&ctx, &mut toasts, 0.0, vec![]);
+/// run(&ctx, &mut toasts, 0.1, vec![]);
+///
+/// // Hovering the toast pauses its countdown
+/// let text_rect = ctx.read_response(id.text_id()).unwrap().rect;
+/// for i in 0..20 {
+///     run(&ctx, &mut toasts, 0.2 + i as f64 * 0.1, vec![egui::Event::PointerMoved(text_rect.center())]);
+/// }
+/// assert!(ctx.read_response(id.text_id()).is_some());
+///
+/// // Clicking the close button removes the toast
+/// let close_pos = ctx.read_response(id.close_button_id()).unwrap().rect.center();
+/// run(&ctx, &mut toasts, 2.3, vec![egui::Event::PointerMoved(close_pos), click(close_pos, true)]);
+/// run(&ctx, &mut toasts, 2.4, vec![click(close_pos, false)]);
+/// run(&ctx, &mut toasts, 2.5, vec![]);
+/// run(&ctx, &mut toasts, 2.6, vec![]);
+/// assert!(ctx.read_response(id.text_id()).is_none());
+/// assert!(ctx.read_response(id.close_button_id()).is_none());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ToastId(u64);
+
+impl ToastId {
+    pub(crate) fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// A new id for a toast that is not added yet, e.g. to update the toast if it is still
+    /// shown and add it otherwise, see [`Toast::with_id`].
+    pub fn new_unique() -> Self {
+        Self::next()
+    }
+
+    /// Id of the [`egui::Area`] the toast is drawn in.
+    pub fn area_id(self) -> Id {
+        Id::new("__toast").with(self.0)
+    }
+
+    /// Id of the close button drawn by the default toast contents.
+    pub fn close_button_id(self) -> Id {
+        self.area_id().with("close_button")
+    }
+
+    /// Id of the progress bar drawn by the default toast contents.
+    pub fn progress_bar_id(self) -> Id {
+        self.area_id().with("progress_bar")
+    }
+
+    /// Id of the text label drawn by the default toast contents.
+    pub fn text_id(self) -> Id {
+        self.area_id().with("text")
+    }
+}
+
 #[derive(Default, Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum ToastKind {
     #[default]
     Info,
@@ -11,18 +104,172 @@ pub enum ToastKind {
     Custom(u32),
 }
 
+impl ToastKind {
+    /// Used to pick the most severe kind of a group of toasts.
+    pub(crate) fn severity(self) -> u8 {
+        match self {
+            ToastKind::Error => 3,
+            ToastKind::Warning => 2,
+            ToastKind::Success => 1,
+            ToastKind::Info | ToastKind::Custom(_) => 0,
+        }
+    }
+}
+
 impl From<u32> for ToastKind {
     fn from(value: u32) -> ToastKind {
         ToastKind::Custom(value)
     }
 }
 
+pub type ToastDismissCallback = dyn Fn(&Toast, DismissReason) + Send + Sync;
+
+pub type ToastTextFn = dyn Fn() -> WidgetText + Send + Sync;
+
+/// Why a toast was removed, see [`Toast::on_dismiss`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[non_exhaustive]
+pub enum DismissReason {
+    /// The duration of the toast ran out.
+    Expired,
+    /// The toast was closed with [`Toast::close`], e.g. by the close button or an action button.
+    Closed,
+    /// The toast was replaced by a newer toast, see [`Toasts::single_slot`](crate::Toasts::single_slot)
+    /// and [`Toasts::deduplicate`](crate::Toasts::deduplicate).
+    Superseded,
+    /// There were too many toasts of the same kind, see
+    /// [`Toasts::max_visible_per_kind`](crate::Toasts::max_visible_per_kind).
+    Evicted,
+    /// The toast was merged into a newer toast of the same kind, see
+    /// [`KindLimitPolicy::Merge`](crate::KindLimitPolicy::Merge), or into the toast it repeated
+    /// after that [escalated](crate::Toasts::escalation).
+    Merged,
+    /// The [capacity](crate::Toasts::capacity) of the toasts was reached when the toast was added.
+    Dropped,
+    /// The toast was dismissed by the [clear all button](crate::Toasts::clear_all_button).
+    ClearedAll,
+    /// The user clicked outside the toasts, see
+    /// [`Toasts::dismiss_on_click_outside`](crate::Toasts::dismiss_on_click_outside).
+    ClickedOutside,
+    /// The [scope](Toast::scope) of the toast was cleared with
+    /// [`Toasts::clear_scope`](crate::Toasts::clear_scope).
+    ScopeCleared,
+    /// The [lane](ToastOptions::lane) of the toast was cleared with
+    /// [`Toasts::dismiss_lane`](crate::Toasts::dismiss_lane).
+    LaneCleared,
+    /// "Dismiss all of this kind" was picked in the [context menu](Toast::context_menu_extra)
+    /// of a toast of the same kind.
+    KindCleared,
+    /// The toast was never shown, because it was rejected by [`Toasts::on_add`](crate::Toasts::on_add)
+    /// or repeated a message within the [dedupe window](crate::Toasts::dedupe_window).
+    Rejected,
+}
+
+/// Phase of a toast on the current frame, see [`Toast::state`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum ToastState {
+    /// The toast is being shown for the first time, or fading in.
+    Appearing,
+    /// The toast is shown normally.
+    #[default]
+    Idle,
+    /// The pointer is over the toast.
+    Hovered,
+    /// The toast is fading out before it is removed, e.g. when it is
+    /// [superseded](crate::Toasts::single_slot). Toasts that are removed without
+    /// a fade-out animation skip this phase.
+    Closing(DismissReason),
+}
+
 #[derive(Clone, Default)]
 pub struct Toast {
     pub kind: ToastKind,
     pub text: WidgetText,
+    /// Text evaluated on every frame, shown instead of [`Self::text`]. Set with
+    /// [`Self::dynamic_text`].
+    pub dynamic_text: Option<Arc<ToastTextFn>>,
+    /// Text with emphasized parts, shown instead of [`Self::text`] while `text` holds its
+    /// plain text. Set with [`Self::rich_text`].
+    pub rich_text: Option<ToastText>,
+    /// Text read by screen readers instead of the plain text, see [`Self::accessible_text`].
+    pub accessible_text: Option<String>,
     pub options: ToastOptions,
-    pub style: ToastStyle,
+    /// Look of the toast. The style of the [`Toasts`](crate::Toasts::style) instance is used
+    /// if this is `None`.
+    pub style: Option<ToastStyle>,
+    /// Icon shown instead of the icon of the style.
+    pub icon: Option<WidgetText>,
+    /// Action buttons shown after the text.
+    pub buttons: Vec<ToastButton>,
+    /// Unique id of the toast, see [`Self::id`]. Assigned by [`Toasts::add`](crate::Toasts::add)
+    /// if not set with [`Self::with_id`].
+    pub(crate) id: Option<ToastId>,
+    /// Position of the toast in the order the toasts were added in, see [`Self::sequence`].
+    pub(crate) sequence: Option<u64>,
+    /// Key of the group the toast belongs to, see [`Self::group`].
+    pub group: Option<String>,
+    /// Scope of the toast, see [`Self::scope`].
+    pub scope: Option<Id>,
+    /// Toasts with a higher priority are drawn on top, see [`Self::priority`].
+    pub priority: i32,
+    /// Called when the toast is removed, see [`Self::on_dismiss`].
+    pub on_dismiss: Option<Arc<ToastDismissCallback>>,
+    /// Decides whether the user may close the toast, see [`Self::on_close_requested`].
+    pub on_close_requested: Option<Arc<ToastCloseCallback>>,
+    /// Progress of each stage of an operation, from 0 to 1, shown by the progress bar instead of
+    /// the remaining time. Set with [`ToastHandle::set_progress_segments`](crate::ToastHandle::set_progress_segments).
+    pub progress: Vec<f32>,
+    /// Sample text whose width is kept free for the text, see [`Self::reserve_width_for`].
+    pub width_sample: Option<WidgetText>,
+    /// Text shown in a collapsible section below the text, see [`Self::details`].
+    pub details: Option<WidgetText>,
+    /// Custom entries of the context menu, see [`Self::context_menu_extra`].
+    pub context_menu_extra: Option<Arc<ToastMenuExtra>>,
+}
+
+/// Shows the texts of the toast as plain text.
+///
+/// ```
+/// # use egui_toast::{Toast, ToastKind};
+/// let toast = Toast::new().kind(ToastKind::Error).text(egui::RichText::new("Failed").strong());
+/// let debug = format!("{toast:?}");
+/// assert!(debug.contains("kind: Error") && debug.contains(r#"text: "Failed""#));
+/// ```
+impl fmt::Debug for Toast {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Toast")
+            .field("kind", &self.kind)
+            .field("text", &self.text.text())
+            .field("dynamic_text", &self.dynamic_text.is_some())
+            .field("rich_text", &self.rich_text)
+            .field("accessible_text", &self.accessible_text)
+            .field("options", &self.options)
+            .field("style", &self.style)
+            .field("icon", &self.icon.as_ref().map(WidgetText::text))
+            .field("buttons", &self.buttons)
+            .field("id", &self.id)
+            .field("sequence", &self.sequence)
+            .field("group", &self.group)
+            .field("scope", &self.scope)
+            .field("priority", &self.priority)
+            .field("on_dismiss", &self.on_dismiss.is_some())
+            .field("on_close_requested", &self.on_close_requested.is_some())
+            .field("progress", &self.progress)
+            .field(
+                "width_sample",
+                &self.width_sample.as_ref().map(WidgetText::text),
+            )
+            .field("details", &self.details.as_ref().map(WidgetText::text))
+            .field("context_menu_extra", &self.context_menu_extra.is_some())
+            .finish()
+    }
+}
+
+/// Next number of the order the toasts are added in, see [`Toast::sequence`].
+pub(crate) fn next_sequence() -> u64 {
+    static NEXT_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+    NEXT_SEQUENCE.fetch_add(1, Ordering::Relaxed)
 }
 
 impl Toast {
@@ -30,29 +277,737 @@ impl Toast {
         Self::default()
     }
 
+    #[must_use]
     pub fn kind(mut self, kind: ToastKind) -> Self {
         self.kind = kind;
         self
     }
 
+    #[must_use]
     pub fn text(mut self, text: impl Into<WidgetText>) -> Self {
         self.text = text.into();
         self
     }
 
+    /// Show the text returned by `text`, which is called on every frame, e.g. for a value
+    /// the app already has at hand that changes all the time. It takes precedence over
+    /// [`Self::text`] and the texts set with a [handle](crate::ToastHandle::set_text).
+    ///
+    /// Deduplication and [`Self::plain_text`] call it once to get the current text.
+    ///
+    /// ```
+    /// # use std::sync::atomic::{AtomicU32, Ordering};
+    /// # use std::sync::Arc;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, Toasts};
+    /// let ping = Arc::new(AtomicU32::new(43));
+    /// let mut test = ToastTestCtx::new(Toasts::new());
+    /// let latest = ping.clone();
+    /// test.add(Toast::new().dynamic_text(move || {
+    ///     format!("Ping: {} ms", latest.load(Ordering::Relaxed)).into()
+    /// }));
+    /// test.run_frames(2);
+    /// ping.store(51, Ordering::Relaxed);
+    /// test.run();
+    /// assert_eq!(test.stored_toasts()[0].plain_text(), "Ping: 51 ms");
+    /// ```
+    #[must_use]
+    pub fn dynamic_text(mut self, text: impl Fn() -> WidgetText + Send + Sync + 'static) -> Self {
+        self.dynamic_text = Some(Arc::new(text));
+        self
+    }
+
+    /// Text shown by the default contents in a collapsible section below the text, e.g. the
+    /// output of a failed command. The section is labeled with [`ToastStrings::details`].
+    #[must_use]
+    pub fn details(mut self, details: impl Into<WidgetText>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    /// Add entries at the end of the [context menu](crate::Toasts::context_menu) of the
+    /// toast. `add_entries` can change the toast, e.g. close it.
+    ///
+    /// ```
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// # use std::sync::Arc;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, Toasts};
+    /// let shown = Arc::new(AtomicBool::new(false));
+    /// let menu_shown = shown.clone();
+    /// let mut test = ToastTestCtx::new(Toasts::new().context_menu(true));
+    /// let handle = test.add(Toast::new().text("Build failed").context_menu_extra(move |ui, _| {
+    ///     menu_shown.store(true, Ordering::Relaxed);
+    ///     let _ = ui.button("Show log");
+    /// }));
+    /// test.run_frames(2);
+    /// test.secondary_click(test.rect(handle.id()).unwrap().center());
+    /// test.run_frames(2);
+    /// assert!(shown.load(Ordering::Relaxed));
+    /// ```
+    #[must_use]
+    pub fn context_menu_extra(
+        mut self,
+        add_entries: impl Fn(&mut Ui, &mut Toast) + Send + Sync + 'static,
+    ) -> Self {
+        self.context_menu_extra = Some(Arc::new(add_entries));
+        self
+    }
+
+    /// Keep the width of `sample` free for the text, so that the toast does not change its
+    /// width when the text is [updated](crate::ToastHandle::set_text) with shorter or longer
+    /// texts, e.g. "Downloading 100%" for a download that shows its progress. See also
+    /// [`ToastOptions::never_shrink`].
+    ///
+    /// ```
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::new());
+    /// let sample = Toast::new().text("Downloading 100%");
+    /// let handle = test.add(sample.clone().reserve_width_for("Downloading 100%"));
+    /// let reference = test.add(sample);
+    /// test.run_frames(3);
+    /// for percent in [3, 47, 100] {
+    ///     handle.set_text(test.ctx(), format!("Downloading {percent}%"));
+    ///     test.run_frames(2);
+    ///     let width = test.rect(handle.id()).unwrap().width();
+    ///     assert_eq!(width, test.rect(reference.id()).unwrap().width());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn reserve_width_for(mut self, sample: impl Into<WidgetText>) -> Self {
+        self.width_sample = Some(sample.into());
+        self
+    }
+
+    /// Set the text to a galley that is already laid out, e.g. by a formatter that caches
+    /// its output.
+    ///
+    /// The default contents paint the galley as it is. Text set with [`Self::text`] is laid
+    /// out again on every frame, which egui caches, but the cache lookup still hashes the
+    /// whole text. The galley is only laid out again if it is wider than the space for the
+    /// text, and the result is kept until that width changes. The tradeoff is that the
+    /// galley keeps the fonts and colors it was laid out with: only the parts with
+    /// [`Color32::PLACEHOLDER`] follow the [text color](ToastStyle::text_color), and it is not
+    /// [scaled](crate::Toasts::scale).
+    ///
+    /// ```
+    /// # use egui_toast::Toast;
+    /// # egui_toast::__run_test_ui(|ui, _ctx| {
+    /// let galley = ui.fonts(|fonts| {
+    ///     fonts.layout_no_wrap("Build failed".into(), egui::FontId::monospace(12.0), egui::Color32::PLACEHOLDER)
+    /// });
+    /// let toast = Toast::new().galley(galley);
+    /// assert_eq!(toast.plain_text(), "Build failed");
+    /// # })
+    /// ```
+    #[must_use]
+    pub fn galley(mut self, galley: Arc<Galley>) -> Self {
+        self.text = WidgetText::Galley(galley);
+        self.rich_text = None;
+        self
+    }
+
+    /// Set the text to a [`ToastText`] with emphasized parts. [`Self::text`] is set to
+    /// the plain text, so that changing the text later replaces the rich text.
+    #[must_use]
+    pub fn rich_text(mut self, text: ToastText) -> Self {
+        self.text = text.plain_text().into();
+        self.rich_text = Some(text);
+        self
+    }
+
+    /// Set the text to `raw` output of a process, colored by its ANSI escape sequences.
+    /// Bold and the 16 basic foreground colors are supported, the other sequences are
+    /// dropped. [`Self::plain_text`] is the text without the sequences. Needs the `ansi`
+    /// feature.
+    ///
+    /// ```
+    /// # use egui_toast::Toast;
+    /// let toast = Toast::new().ansi_text("\x1b[1;31merror\x1b[0m: \x1b[4mmissing\x1b[24m `;`");
+    /// assert_eq!(toast.plain_text(), "error: missing `;`");
+    /// ```
+    #[cfg(feature = "ansi")]
+    #[must_use]
+    pub fn ansi_text(self, raw: &str) -> Self {
+        self.rich_text(crate::ansi::parse(raw))
+    }
+
+    #[must_use]
     pub fn options(mut self, options: ToastOptions) -> Self {
         self.options = options;
         self
     }
 
+    #[must_use]
     pub fn style(mut self, style: ToastStyle) -> Self {
-        self.style = style;
+        self.style = Some(style);
+        self
+    }
+
+    /// Show the given icon instead of the icon of the style, keeping the rest of the style.
+    #[must_use]
+    pub fn icon(mut self, icon: impl Into<WidgetText>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// The style the toast is drawn with: the style of the toast or the given default style,
+    /// with the icon of the toast.
+    pub(crate) fn resolve_style(&self, default: &ToastStyle) -> ToastStyle {
+        let mut style = self.style.clone().unwrap_or_else(|| default.clone());
+        if let Some(icon) = &self.icon {
+            style.icon_fallbacks.remove(&self.kind);
+            *style.primary_icon_mut(self.kind) = icon.clone();
+        }
+        style
+    }
+
+    /// Show the toast in a group together with the other toasts with the same key.
+    ///
+    /// The toasts of a group are shown as a single collapsible toast with a header
+    /// like "Build errors (12)". Each toast still expires on its own, and the group
+    /// disappears once all of its toasts have expired.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, ToastKind, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new();
+    /// let first = toasts.add(Toast::new().text("main.rs").group("Build errors")).id();
+    /// let second = toasts.add(Toast::new().kind(ToastKind::Error).text("lib.rs").group("Build errors")).id();
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// // Collapsed groups only show the header
+    /// assert!(ctx.read_response(first.text_id()).is_none());
+    ///
+    /// toasts.set_group_expanded(&ctx, "Build errors", true);
+    /// for _ in 0..3 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// let first = ctx.read_response(first.text_id()).unwrap().rect;
+    /// let second = ctx.read_response(second.text_id()).unwrap().rect;
+    /// assert!(first.bottom() <= second.top());
+    /// ```
+    #[must_use]
+    pub fn group(mut self, key: impl Into<String>) -> Self {
+        self.group = Some(key.into());
+        self
+    }
+
+    /// Tie the toast to a scope, such as a document tab, so that it can be removed with
+    /// [`Toasts::clear_scope`](crate::Toasts::clear_scope) when the tab is closed.
+    ///
+    /// [Deduplication](crate::Toasts::deduplicate) only replaces toasts of the same scope,
+    /// so the same message can be shown for two tabs.
+    #[must_use]
+    pub fn scope(mut self, key: impl Into<Id>) -> Self {
+        self.scope = Some(key.into());
+        self
+    }
+
+    /// Draw the toast on top of the toasts with a lower priority when they overlap, e.g. while
+    /// they slide to new positions or are dragged. Toasts with the same priority are drawn
+    /// newest on top. The priority does not change the place of the toast in the stack.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, Toasts};
+    /// let ctx = egui::Context::default();
+    /// // Overlapping toasts
+    /// let mut toasts = Toasts::new().spacing(-20.0);
+    /// let error = toasts.add(Toast::new().text("Upload failed").priority(1)).id();
+    /// let info = toasts.add(Toast::new().text("Uploading")).id();
+    /// for _ in 0..5 {
+    ///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// let rect = |id: egui_toast::ToastId| ctx.memory(|m| m.area_rect(id.area_id())).unwrap();
+    /// let overlap = rect(error).intersect(rect(info));
+    /// assert!(overlap.is_positive());
+    /// let top = ctx.layer_id_at(overlap.center()).unwrap();
+    /// assert_eq!(top.id, error.area_id());
+    /// ```
+    #[must_use]
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
         self
     }
 
+    /// Whether the pointer was over the toast on the previous frame.
+    ///
+    /// Can be used by [custom contents](crate::Toasts::custom_contents), e.g. to show
+    /// a close button only when the toast is hovered.
+    pub fn is_hovered(&self, ctx: &Context) -> bool {
+        self.id.is_some_and(|id| LastFrame::load(ctx, id).hovered)
+    }
+
+    /// [Scale](crate::Toasts::scale) the toast is drawn at on the current frame.
+    ///
+    /// The text sizes and the spacing of the egui style are already scaled for
+    /// [custom contents](crate::Toasts::custom_contents), so this is only needed for sizes
+    /// that the custom contents pick themselves.
+    ///
+    /// ```
+    /// # use std::sync::{Arc, Mutex};
+    /// # use egui_toast::{Toast, ToastKind, Toasts};
+    /// let ctx = egui::Context::default();
+    /// let seen = Arc::new(Mutex::new(None));
+    /// let mut toasts = Toasts::new().scale(1.5).custom_contents(ToastKind::Info, {
+    ///     let seen = seen.clone();
+    ///     move |ui, toast| {
+    ///         *seen.lock().unwrap() = Some(toast.scale(ui.ctx()));
+    ///         let size = egui::Vec2::splat(32.0 * toast.scale(ui.ctx()));
+    ///         ui.allocate_exact_size(size, egui::Sense::hover()).1
+    ///     }
+    /// });
+    /// toasts.add(Toast::new().kind(ToastKind::Info));
+    /// let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// assert_eq!(*seen.lock().unwrap(), Some(1.5));
+    /// ```
+    pub fn scale(&self, ctx: &Context) -> f32 {
+        self.id.map_or(1.0, |id| scale::load(ctx, id))
+    }
+
+    /// Phase of the toast on the current frame.
+    ///
+    /// Can be used by [custom contents](crate::Toasts::custom_contents), e.g. to draw
+    /// the toast differently while it is fading out.
+    ///
+    /// ```
+    /// # use std::sync::{Arc, Mutex};
+    /// # use egui_toast::{DismissReason, Toast, ToastState, Toasts};
+    /// # fn run(ctx: &egui::Context, toasts: &mut Toasts, time: f64) {
+    /// #     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    /// #     let _ = ctx.run(input, |ctx| toasts.show(ctx));
+    /// # }
+    /// let ctx = egui::Context::default();
+    /// let states = Arc::new(Mutex::new(Vec::new()));
+    /// let mut toasts = Toasts::new().single_slot(true).custom_contents(0, {
+    ///     let states = states.clone();
+    ///     move |ui, toast| {
+    ///         states.lock().unwrap().push(toast.state(ui.ctx()));
+    ///         ui.label(toast.text.clone())
+    ///     }
+    /// });
+    /// toasts.add(Toast::new().kind(0.into()).text("Deleted"));
+    /// for i in 0..5 {
+    ///     run(&ctx, &mut toasts, i as f64 * 0.1);
+    /// }
+    /// toasts.add(Toast::new().text("Archived"));
+    /// run(&ctx, &mut toasts, 0.5);
+    ///
+    /// let states = states.lock().unwrap();
+    /// assert_eq!(states[0], ToastState::Appearing);
+    /// assert_eq!(states[4], ToastState::Idle);
+    /// assert_eq!(states[5], ToastState::Closing(DismissReason::Superseded));
+    /// ```
+    pub fn state(&self, ctx: &Context) -> ToastState {
+        self.id
+            .map_or_else(ToastState::default, |id| state::load_state(ctx, id))
+    }
+
+    /// Text read by screen readers instead of the shown text, e.g. a description of a terse
+    /// error code. Also returned by [`Self::announcement`], for mirroring the toast to a
+    /// system notification.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, ToastKind, ToastStyle, Toasts};
+    /// let toast = Toast::new()
+    ///     .kind(ToastKind::Error)
+    ///     .text("E1042")
+    ///     .accessible_text("Could not reach the license server (E1042)");
+    /// let style = ToastStyle::default().announce_kind(true);
+    /// let announced = "Error: Could not reach the license server (E1042)";
+    /// assert_eq!(toast.announcement(&style), announced);
+    ///
+    /// # #[cfg(feature = "accesskit")] {
+    /// let ctx = egui::Context::default();
+    /// ctx.enable_accesskit();
+    /// let mut toasts = Toasts::new().style(style);
+    /// toasts.add(toast);
+    /// let mut nodes = Vec::new();
+    /// for _ in 0..3 {
+    ///     let output = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    ///     nodes = output.platform_output.accesskit_update.unwrap().nodes;
+    /// }
+    /// assert!(nodes.iter().any(|(_, node)| node.name() == Some(announced)));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn accessible_text(mut self, text: impl Into<String>) -> Self {
+        self.accessible_text = Some(text.into());
+        self
+    }
+
+    /// The text read by screen readers: the [accessible text](Self::accessible_text), or
+    /// the [plain text](Self::plain_text) if it is not set, after the kind of the toast if
+    /// `style` [announces it](ToastStyle::announce_kind).
+    pub fn announcement(&self, style: &ToastStyle) -> String {
+        let text = self
+            .accessible_text
+            .clone()
+            .unwrap_or_else(|| self.plain_text());
+        if style.announce_kind {
+            let strings = &style.strings;
+            let prefix = strings
+                .kind_prefix
+                .replace("{kind}", strings.kind_label(self.kind));
+            format!("{prefix}{text}")
+        } else {
+            text
+        }
+    }
+
+    /// Id of the toast, or `None` before it is added. Toasts are the same toast if their
+    /// ids are equal, whatever their contents.
+    pub fn id(&self) -> Option<ToastId> {
+        self.id
+    }
+
+    /// Sequence number of the toast, or `None` before it is added. The numbers are taken in
+    /// increasing order when the toasts are added, by all [`Toasts`](crate::Toasts) instances
+    /// and [queues](crate::ToastQueue), so this is the order the toasts were added in. An
+    /// [id](Self::with_id) made earlier doesn't change it.
+    ///
+    /// The toasts are stacked in this order, which also breaks the ties between toasts of the
+    /// same [priority](crate::Toast::priority) and decides which toasts are the oldest, e.g.
+    /// for [`Toasts::max_visible_per_kind`](crate::Toasts::max_visible_per_kind). A
+    /// [duplicate](crate::Toasts::deduplicate) that replaces a visible toast takes its place.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, ToastId, ToastOptions, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::new().reduce_motion(true));
+    /// let queue = test.toasts().queue();
+    /// let mut seed = 7_u32;
+    /// let mut random = move || {
+    ///     seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+    ///     (seed >> 16) % 100
+    /// };
+    /// // An id made before the other toasts are added doesn't move the toast up the stack
+    /// let early = ToastId::new_unique();
+    /// let mut ids = Vec::new();
+    /// for i in 0..30 {
+    ///     let options = ToastOptions::default().duration_in_millis(100 + 50 * random() as u64);
+    ///     let toast = Toast::new().text(format!("Toast {i}")).options(options);
+    ///     // Toasts added directly and through the queue in the same frame keep their order
+    ///     ids.push(if i % 3 == 0 { queue.add(test.ctx(), toast) } else { test.add(toast) }.id());
+    /// }
+    /// ids.push(test.add(Toast::new().text("Late").with_id(early)).id());
+    /// for frame in 0..100 {
+    ///     test.run_for(Duration::from_millis(50), 1);
+    ///     let tops: Vec<f32> = ids.iter().filter_map(|&id| test.rect(id)).map(|rect| rect.top()).collect();
+    ///     assert!(tops.windows(2).all(|pair| pair[0] <= pair[1]), "frame {frame}: {tops:?}");
+    /// }
+    /// test.run();
+    /// let stored = test.stored_toasts();
+    /// assert_eq!(stored.last().and_then(Toast::id), Some(early));
+    /// assert!(stored.windows(2).all(|pair| pair[0].sequence() < pair[1].sequence()));
+    /// ```
+    pub fn sequence(&self) -> Option<u64> {
+        self.sequence
+    }
+
+    /// Take the next sequence number, when the toast is added.
+    pub(crate) fn assign_sequence(&mut self) {
+        self.sequence = Some(next_sequence());
+    }
+
+    /// Add the toast with an id made with [`ToastId::new_unique`] instead of a new one.
+    ///
+    /// Only one toast can have the id at a time. [`Toasts::try_add`](crate::Toasts::try_add)
+    /// returns [`AddResult::DuplicateId`](crate::AddResult::DuplicateId) with a handle to the
+    /// toast that has the id, which can be used to update that toast instead:
+    ///
+    /// ```
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{AddResult, Toast, ToastId, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::new());
+    /// let ctx = test.ctx().clone();
+    /// let id = ToastId::new_unique();
+    /// let mut sync = |test: &mut ToastTestCtx, text: &str| {
+    ///     match test.toasts().try_add(&ctx, Toast::new().with_id(id).text(text)) {
+    ///         AddResult::DuplicateId(handle) => handle.set_text(&ctx, text),
+    ///         result => assert!(result.handle().is_some()),
+    ///     }
+    /// };
+    /// sync(&mut test, "Syncing 1 file");
+    /// test.run_frames(2);
+    /// sync(&mut test, "Syncing 2 files");
+    /// test.run();
+    /// let [toast] = test.stored_toasts().try_into().unwrap();
+    /// assert_eq!((toast.id(), toast.plain_text().as_str()), (Some(id), "Syncing 2 files"));
+    /// ```
+    #[must_use]
+    pub fn with_id(mut self, id: ToastId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Whether the countdown of the toast was stopped on the previous frame, either because
+    /// the toast was hovered (see [`Toasts::hover_pauses`](crate::Toasts::hover_pauses))
+    /// or because it was [paused](crate::ToastHandle::pause).
+    pub fn is_paused(&self, ctx: &Context) -> bool {
+        self.id.is_some_and(|id| LastFrame::load(ctx, id).paused)
+    }
+
+    /// Text of the toast as a plain string, e.g. for copying it to the clipboard or mirroring it
+    /// to a system notification.
+    ///
+    /// The formatting of [rich text](egui::RichText), [layout jobs](egui::text::LayoutJob)
+    /// and galleys is lost.
+    ///
+    /// ```
+    /// # use egui_toast::Toast;
+    /// let toast = Toast::new().text(egui::RichText::new("Saved").strong());
+    /// assert_eq!(toast.plain_text(), "Saved");
+    /// ```
+    pub fn plain_text(&self) -> String {
+        match &self.dynamic_text {
+            Some(text) => text().text().to_owned(),
+            None => self.text.text().to_owned(),
+        }
+    }
+
+    /// Call `on_dismiss` with the reason when the toast is removed.
+    ///
+    /// The callback is called once, on the frame after the toast was closed or expired.
+    #[must_use]
+    pub fn on_dismiss(
+        mut self,
+        on_dismiss: impl Fn(&Toast, DismissReason) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_dismiss = Some(Arc::new(on_dismiss));
+        self
+    }
+
+    /// Decide whether the toast is closed when the user asks to close it, with the close
+    /// button or by pressing Enter while a toast without a primary button is
+    /// [focused](crate::Toasts::focusable).
+    ///
+    /// This is not called when the toast expires, is closed by an action button, with
+    /// [`Self::close`] or [`ToastHandle::close`](crate::ToastHandle::close), or is dismissed by the [`Toasts`](crate::Toasts), e.g. by
+    /// [`Toasts::clear_scope`](crate::Toasts::clear_scope).
+    ///
+    /// ```
+    /// # use std::sync::atomic::{AtomicUsize, Ordering};
+    /// # use std::sync::Arc;
+    /// # use egui_toast::{CloseDecision, Toast, ToastButton, Toasts};
+    /// # fn click(pos: egui::Pos2, pressed: bool) -> egui::Event {
+    /// #     egui::Event::PointerButton {
+    /// #         pos,
+    /// #         button: egui::PointerButton::Primary,
+    /// #         pressed,
+    /// #         modifiers: Default::default(),
+    /// #     }
+    /// # }
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new();
+    /// let requests = Arc::new(AtomicUsize::new(0));
+    /// let counter = requests.clone();
+    /// let id = toasts.add(Toast::new()
+    ///     .text("You have unsaved changes")
+    ///     .button(ToastButton::new("Save").primary(true))
+    ///     .button(ToastButton::new("Discard"))
+    ///     .on_close_requested(move |_toast| {
+    ///         counter.fetch_add(1, Ordering::Relaxed);
+    ///         CloseDecision::Deny { shake: true }
+    ///     })).id();
+    /// let mut run = |events: Vec<egui::Event>| {
+    ///     let input = egui::RawInput { events, ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| toasts.show(ctx));
+    /// };
+    /// for _ in 0..3 {
+    ///     run(vec![]);
+    /// }
+    ///
+    /// let close_pos = ctx.read_response(id.close_button_id()).unwrap().rect.center();
+    /// run(vec![egui::Event::PointerMoved(close_pos), click(close_pos, true)]);
+    /// run(vec![click(close_pos, false)]);
+    /// run(vec![]);
+    /// run(vec![]);
+    /// assert_eq!(requests.load(Ordering::Relaxed), 1);
+    /// assert!(ctx.read_response(id.text_id()).is_some());
+    /// ```
+    #[must_use]
+    pub fn on_close_requested(
+        mut self,
+        on_close_requested: impl Fn(&mut Toast) -> CloseDecision + Send + Sync + 'static,
+    ) -> Self {
+        self.on_close_requested = Some(Arc::new(on_close_requested));
+        self
+    }
+
+    /// Close the toast like the close button does: unless [`Self::on_close_requested`] denies
+    /// the request, in which case the toast is kept. Returns whether the toast was closed.
+    ///
+    /// This is meant for [custom contents](crate::Toasts::custom_contents) that draw their
+    /// own close button.
+    pub fn request_close(&mut self, ctx: &Context) -> bool {
+        let decision = match self.on_close_requested.clone() {
+            Some(on_close_requested) => on_close_requested(self),
+            None => CloseDecision::Allow,
+        };
+        match decision {
+            CloseDecision::Allow => {
+                self.close();
+                true
+            }
+            CloseDecision::Deny { shake } => {
+                if let (true, Some(id)) = (shake, self.id) {
+                    close::start_shake(ctx, id.area_id());
+                }
+                false
+            }
+        }
+    }
+
+    /// Add an action button
+    #[must_use]
+    pub fn button(mut self, button: ToastButton) -> Self {
+        self.buttons.push(button);
+        self
+    }
+
+    /// Time the toast has been shown, counted for [stopwatch](ToastOptions::stopwatch) toasts.
+    /// Zero for the other toasts.
+    ///
+    /// Custom contents can show it with [`format_elapsed`](crate::format_elapsed).
+    pub fn elapsed(&self) -> Duration {
+        self.options.elapsed
+    }
+
+    /// How urgent the toast is, from 0 to 1: the [urgency](ToastOptions::urgency) of its
+    /// options, or the default of its kind.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, ToastKind, ToastOptions};
+    /// assert_eq!(Toast::new().kind(ToastKind::Warning).urgency(), 0.5);
+    /// let options = ToastOptions::default().urgency(1.0);
+    /// assert_eq!(Toast::new().options(options).urgency(), 1.0);
+    /// ```
+    pub fn urgency(&self) -> f32 {
+        self.options.urgency.unwrap_or(match self.kind {
+            ToastKind::Warning => 0.5,
+            ToastKind::Error => 0.9,
+            _ => 0.2,
+        })
+    }
+
+    /// Whether the toast is dismissed by the input of
+    /// [`Toasts::dismiss_on_input`](crate::Toasts::dismiss_on_input): the
+    /// [option](ToastOptions::dismiss_on_input) of the toast, or the default of its kind.
+    pub fn dismisses_on_input(&self) -> bool {
+        self.options
+            .dismiss_on_input
+            .unwrap_or(matches!(self.kind, ToastKind::Info | ToastKind::Success))
+    }
+
+    /// The text drawn by the default contents: the [dynamic text](Self::dynamic_text), the
+    /// [rich text](Self::rich_text) laid out with the visuals of `ui`, or the text if it was
+    /// changed.
+    pub(crate) fn display_text(&self, ui: &Ui, style: &ToastStyle) -> WidgetText {
+        if let Some(text) = &self.dynamic_text {
+            return text();
+        }
+        match &self.rich_text {
+            Some(rich_text) if rich_text.plain_text() == self.text.text() => {
+                rich_text.to_widget_text(ui.style(), style.accent_color(self.kind))
+            }
+            _ => match &self.text {
+                WidgetText::Galley(galley) => WidgetText::Galley(self.fit_galley(ui, galley)),
+                text => text.clone(),
+            },
+        }
+    }
+
+    /// The [galley](Self::galley) of the toast, laid out again if it does not fit in the
+    /// width available in `ui`.
+    fn fit_galley(&self, ui: &Ui, galley: &Arc<Galley>) -> Arc<Galley> {
+        let max_width = match ui.wrap_mode() {
+            TextWrapMode::Extend => f32::INFINITY,
+            TextWrapMode::Wrap | TextWrapMode::Truncate => ui.available_width(),
+        };
+        let Some(toast_id) = self.id else {
+            return galley.clone();
+        };
+        if galley.size().x <= max_width {
+            return galley.clone();
+        }
+        // The galley laid out for the width, and the galley it was laid out from
+        type Fitted = (f32, Arc<Galley>, Arc<Galley>);
+        let fitted_id = toast_id.text_id().with("fitted_galley");
+        let fitted = ui.data(|d| d.get_temp::<Fitted>(fitted_id));
+        if let Some((width, source, fitted)) = fitted {
+            if width == max_width && Arc::ptr_eq(&source, galley) {
+                return fitted;
+            }
+        }
+        let mut job = (*galley.job).clone();
+        job.wrap.max_width = max_width;
+        if ui.wrap_mode() == TextWrapMode::Truncate {
+            job.wrap.max_rows = 1;
+        }
+        let fitted = ui.fonts(|fonts| fonts.layout_job(job));
+        ui.data_mut(|d| {
+            d.insert_temp::<Fitted>(fitted_id, (max_width, galley.clone(), fitted.clone()))
+        });
+        fitted
+    }
+
     /// Close the toast immediately
     pub fn close(&mut self) {
-        self.options.ttl_sec = 0.0;
+        self.options.dismiss(DismissReason::Closed);
+    }
+
+    /// Fraction shown by the progress: the [external progress](Self::progress) of all stages,
+    /// or the remaining time if it is not set.
+    pub(crate) fn progress_fraction(&self) -> f32 {
+        if self.progress.is_empty() {
+            self.options.progress() as f32
+        } else {
+            self.progress.iter().sum::<f32>() / self.progress.len() as f32
+        }
+    }
+
+    /// Call the [`Self::on_dismiss`] callback.
+    pub(crate) fn notify_dismissed(&self, reason: DismissReason) {
+        log::debug!(
+            "Toast {:?} was dismissed ({reason:?}): {:?}",
+            self.id,
+            self.text.text()
+        );
+        if let Some(on_dismiss) = &self.on_dismiss {
+            on_dismiss(self, reason);
+        }
+    }
+
+    /// Whether the toasts show the same message, whatever their kinds, see
+    /// [`Toasts::deduplicate`](crate::Toasts::deduplicate).
+    pub(crate) fn has_same_message(&self, other: &Toast) -> bool {
+        self.group == other.group
+            && self.scope == other.scope
+            && self.plain_text() == other.plain_text()
+    }
+
+    /// Call the callback of the button at `index` and close the toast.
+    pub(crate) fn click_button(&mut self, index: usize) {
+        if let Some(on_click) = self.buttons.get(index).and_then(|b| b.on_click.clone()) {
+            on_click(self);
+        }
+        self.close();
+    }
+
+    /// Click the primary button, or request to close the toast if it has none.
+    /// Returns whether the toast was closed.
+    pub(crate) fn activate(&mut self, ctx: &Context) -> bool {
+        match self.buttons.iter().position(|button| button.primary) {
+            Some(index) => {
+                self.click_button(index);
+                true
+            }
+            None => self.request_close(ctx),
+        }
     }
 }
 
@@ -62,31 +1017,882 @@ pub struct ToastStyle {
     pub warning_icon: WidgetText,
     pub error_icon: WidgetText,
     pub success_icon: WidgetText,
+    /// Icons used instead of the icon of a kind if the fonts can't display it, tried in order.
+    pub icon_fallbacks: HashMap<ToastKind, Vec<WidgetText>>,
     pub close_button_text: WidgetText,
+    /// Horizontal direction of the toast contents.
+    pub layout_direction: LayoutDirection,
+    /// Order of the parts of the default toast contents, see [`Self::layout`].
+    pub layout: Vec<ToastSlot>,
+    /// Texts shown by the default toast contents.
+    pub strings: ToastStrings,
+    /// Look of the [primary](ToastButton::primary) action buttons.
+    pub primary_button: ToastButtonStyle,
+    /// Look of the other action buttons.
+    pub secondary_button: ToastButtonStyle,
+    /// How the remaining time is visualized.
+    pub progress_style: ProgressStyle,
+    /// Color of the progress while the countdown is stopped, e.g. because the toast is hovered.
+    /// Defaults to a dimmed version of the normal color if `None`.
+    pub progress_paused_color: Option<Color32>,
+    /// Space between the segments of a progress bar showing several
+    /// [stages](crate::ToastHandle::set_progress_segments).
+    pub progress_segment_gap: f32,
+    /// Colors of the progress segments, in order. The normal progress color is used for
+    /// the segments without a color.
+    pub progress_segment_colors: Vec<Color32>,
+    /// Opacity of the toast while it is [paused](crate::ToastHandle::pause).
+    pub paused_opacity: f32,
+    /// Space between the frame of the toast and its contents.
+    pub inner_margin: f32,
+    /// Rounding of the frame of the toast. Follows the window rounding of the egui style if `None`.
+    pub rounding: Option<Rounding>,
+    /// Fill of the frame of the toast. Follows the window fill of the egui style if `None`.
+    pub fill: Option<Color32>,
+    /// Outline of the frame of the toast. Follows the window stroke of the egui style if `None`.
+    pub stroke: Option<Stroke>,
+    /// Shadow of the frame of the toast. Follows the window shadow of the egui style if `None`.
+    pub shadow: Option<Shadow>,
+    /// Color of the text and the progress. Follows the text color of the egui style if `None`.
+    pub text_color: Option<Color32>,
+    /// Highlight of the frame while the pointer is over a toast that has buttons, to show
+    /// that the toast is interactive. Not highlighted if `None`.
+    pub hover: Option<ToastHoverStyle>,
+    /// Outline the toasts in the accent color of their kind, with a width that grows with
+    /// their [urgency](Toast::urgency), up to 2 points.
+    pub urgency_outline: bool,
+    /// Minimum contrast ratio between the text and the fill, see [`Self::enforce_contrast`].
+    pub min_contrast: Option<f32>,
+    /// A bar in the accent color of the kind on the leading edge of the toast, shown instead
+    /// of the icon. See [`Self::accent_edge`].
+    pub accent_edge: Option<AccentEdge>,
+    /// Animation played when the text or the kind of a shown toast changes. See
+    /// [`Self::update_blip`].
+    pub update_blip: Option<BlipStyle>,
+    /// Read the kind of the toasts to screen readers before their text, see
+    /// [`Self::announce_kind`].
+    pub announce_kind: bool,
+    pub info_color: Color32,
+    pub warning_color: Color32,
+    pub error_color: Color32,
+    pub success_color: Color32,
+}
+
+/// Highlight of a hovered toast, see [`ToastStyle::hover`]. Each field overrides the look of
+/// the frame while the toast is hovered.
+///
+/// ```
+/// # use egui_toast::{ToastHoverStyle, ToastStyle};
+/// let style = ToastStyle {
+///     hover: Some(ToastHoverStyle {
+///         shadow: Some(egui::Shadow {
+///             offset: egui::vec2(0.0, 6.0),
+///             blur: 24.0,
+///             spread: 0.0,
+///             color: egui::Color32::from_black_alpha(96),
+///         }),
+///         ..Default::default()
+///     }),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct ToastHoverStyle {
+    /// Fill of the frame. A slightly lighter version of the normal fill if `None`.
+    pub fill: Option<Color32>,
+    /// Outline of the frame. An outline in the [accent color](ToastStyle::accent_color) of
+    /// the kind if `None`.
+    pub stroke: Option<Stroke>,
+    /// Shadow of the frame, e.g. a larger shadow to raise the toast. The normal shadow
+    /// if `None`.
+    pub shadow: Option<Shadow>,
+}
+
+/// A bar on the leading edge of a toast, in the [accent color](ToastStyle::accent_color) of
+/// its kind, see [`ToastStyle::accent_edge`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct AccentEdge {
+    /// Width of the bar, in points. Defaults to 3.
+    pub width: f32,
+    /// Whether the bar follows the rounded corners of the frame. Otherwise the bar is a plain
+    /// rectangle that covers the corners.
+    pub rounding_aware: bool,
+}
+
+impl Default for AccentEdge {
+    fn default() -> Self {
+        Self {
+            width: 3.0,
+            rounding_aware: true,
+        }
+    }
+}
+
+/// Lighten a fill color a little, keeping its opacity.
+fn lighten(color: Color32) -> Color32 {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    let lighten = |c: u8| c + (255 - c) / 10;
+    Color32::from_rgba_unmultiplied(lighten(r), lighten(g), lighten(b), a)
+}
+
+/// Shows the icons as plain text.
+impl fmt::Debug for ToastStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let icon_fallbacks: HashMap<_, Vec<_>> = self
+            .icon_fallbacks
+            .iter()
+            .map(|(kind, icons)| (kind, icons.iter().map(WidgetText::text).collect()))
+            .collect();
+        f.debug_struct("ToastStyle")
+            .field("info_icon", &self.info_icon.text())
+            .field("warning_icon", &self.warning_icon.text())
+            .field("error_icon", &self.error_icon.text())
+            .field("success_icon", &self.success_icon.text())
+            .field("icon_fallbacks", &icon_fallbacks)
+            .field("close_button_text", &self.close_button_text.text())
+            .field("layout_direction", &self.layout_direction)
+            .field("layout", &self.layout)
+            .field("strings", &self.strings)
+            .field("primary_button", &self.primary_button)
+            .field("secondary_button", &self.secondary_button)
+            .field("progress_style", &self.progress_style)
+            .field("progress_paused_color", &self.progress_paused_color)
+            .field("progress_segment_gap", &self.progress_segment_gap)
+            .field("progress_segment_colors", &self.progress_segment_colors)
+            .field("paused_opacity", &self.paused_opacity)
+            .field("inner_margin", &self.inner_margin)
+            .field("rounding", &self.rounding)
+            .field("fill", &self.fill)
+            .field("stroke", &self.stroke)
+            .field("shadow", &self.shadow)
+            .field("text_color", &self.text_color)
+            .field("hover", &self.hover)
+            .field("urgency_outline", &self.urgency_outline)
+            .field("min_contrast", &self.min_contrast)
+            .field("accent_edge", &self.accent_edge)
+            .field("update_blip", &self.update_blip)
+            .field("announce_kind", &self.announce_kind)
+            .field("info_color", &self.info_color)
+            .field("warning_color", &self.warning_color)
+            .field("error_color", &self.error_color)
+            .field("success_color", &self.success_color)
+            .finish()
+    }
+}
+
+impl ToastStyle {
+    /// Darken or lighten the colors of the text that have less than `min_ratio` contrast
+    /// against the fill of the toast, e.g. a yellow text on a light fill. The ratio is the
+    /// [WCAG contrast ratio](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio), from 1 to 21;
+    /// 4.5 is the level recommended for normal text.
+    ///
+    /// Each differently colored part of a [layout job](egui::text::LayoutJob) is adjusted on
+    /// its own, and colors with enough contrast are kept. Text without a color of its own
+    /// follows the visuals, and [galleys](Toast::galley) are drawn as they are.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, ToastStyle, Toasts};
+    /// let light = ToastStyle {
+    ///     fill: Some(egui::Color32::from_gray(245)),
+    ///     ..Default::default()
+    /// }
+    /// .enforce_contrast(4.5);
+    /// let mut toasts = Toasts::new().style(light);
+    /// let text = egui::RichText::new("Low disk space").color(egui::Color32::YELLOW);
+    /// toasts.add(Toast::new().text(text));
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut output = egui::FullOutput::default();
+    /// for _ in 0..3 {
+    ///     output = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// let color = output.shapes.iter().find_map(|clipped| match &clipped.shape {
+    ///     egui::Shape::Text(text) if text.galley.text() == "Low disk space" => {
+    ///         Some(text.galley.job.sections[0].format.color)
+    ///     }
+    ///     _ => None,
+    /// });
+    /// // Darkened for the light fill
+    /// let color = color.unwrap();
+    /// assert!(color.r() < 200 && color.r() >= color.b());
+    /// ```
+    #[must_use]
+    pub fn enforce_contrast(mut self, min_ratio: f32) -> Self {
+        self.min_contrast = Some(min_ratio);
+        self
+    }
+
+    /// Mark the kind of the toasts with a bar on their leading edge instead of the icon,
+    /// like the notifications of some editors. The bar is on the right in
+    /// [right-to-left](Self::layout_direction) layouts, and it stops above the progress bar.
+    ///
+    /// ```
+    /// # use egui_toast::{AccentEdge, Toast, ToastStyle, Toasts};
+    /// let style = ToastStyle::default().accent_edge(Some(AccentEdge::default()));
+    /// let mut toasts = Toasts::new().style(style);
+    /// toasts.add(Toast::new().text("Indexing finished"));
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut output = egui::FullOutput::default();
+    /// for _ in 0..3 {
+    ///     output = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+    /// }
+    /// // The frame shape clipped to the leading edge
+    /// let edge = output.shapes.iter().find(|clipped| match &clipped.shape {
+    ///     egui::Shape::Rect(rect) => {
+    ///         clipped.clip_rect.width() == 3.0 && clipped.clip_rect.left() == rect.rect.left()
+    ///     }
+    ///     _ => false,
+    /// });
+    /// assert!(edge.is_some());
+    /// ```
+    #[must_use]
+    pub fn accent_edge(mut self, edge: Option<AccentEdge>) -> Self {
+        self.accent_edge = edge;
+        self
+    }
+
+    /// Play a brief animation when the text or the kind of a shown toast changes, e.g. when
+    /// a retry count is [updated](crate::ToastHandle::set_text), so that the change is
+    /// noticed. Changes made with [`ToastHandle::set_text_quiet`](crate::ToastHandle::set_text_quiet)
+    /// don't play it.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{BlipStyle, Toast, ToastStyle, Toasts};
+    /// let style = ToastStyle::default().update_blip(Some(BlipStyle::default()));
+    /// let mut test = ToastTestCtx::new(Toasts::new().style(style));
+    /// let handle = test.add(Toast::new().text("Retrying (1)"));
+    /// test.run_frames(2);
+    /// let layer = egui::LayerId::new(egui::Order::Foreground, handle.id().area_id());
+    /// let scale = |test: &ToastTestCtx| {
+    ///     test.ctx().memory(|m| m.layer_transforms.get(&layer).map_or(1.0, |t| t.scaling))
+    /// };
+    ///
+    /// handle.set_text(test.ctx(), "Retrying (2)");
+    /// test.run_for(Duration::from_millis(150), 3);
+    /// assert!(scale(&test) > 1.0);
+    /// test.run_for(Duration::from_millis(300), 3);
+    /// assert_eq!(scale(&test), 1.0);
+    ///
+    /// handle.set_text_quiet(test.ctx(), "Retrying (3)");
+    /// test.run_for(Duration::from_millis(150), 3);
+    /// assert_eq!(scale(&test), 1.0);
+    /// ```
+    #[must_use]
+    pub fn update_blip(mut self, blip: Option<BlipStyle>) -> Self {
+        self.update_blip = blip;
+        self
+    }
+
+    /// Start the [announcements](Toast::announcement) of the toasts with their kind, e.g.
+    /// "Error: ", since screen reader users don't see the icon. The prefix is set with
+    /// [`ToastStrings::kind_prefix`], and it is not shown.
+    #[must_use]
+    pub fn announce_kind(mut self, announce: bool) -> Self {
+        self.announce_kind = announce;
+        self
+    }
+
+    /// The text with its colors adjusted for the [minimum contrast](Self::enforce_contrast)
+    /// against `fill`.
+    pub(crate) fn contrasting_text(&self, ui: &Ui, text: WidgetText, fill: Color32) -> WidgetText {
+        match self.min_contrast {
+            Some(min_ratio) => contrast::enforce(text, ui.style(), fill, min_ratio),
+            None => text,
+        }
+    }
+
+    /// Order of the parts of the default toast contents, from the leading edge to the trailing
+    /// edge. Defaults to [`ToastSlot::DEFAULT_LAYOUT`].
+    ///
+    /// The [progress bar](ToastSlot::Progress) is drawn at the top of the toast if it comes
+    /// first, and at the bottom otherwise. Parts that are left out are not drawn, a part that
+    /// is repeated is only placed the first time, and an empty layout falls back to the
+    /// default. The order is mirrored for [right-to-left](Self::layout_direction) toasts.
+    ///
+    /// ```
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, ToastOptions, ToastSlot, ToastStyle, Toasts};
+    /// use ToastSlot::*;
+    /// let style = ToastStyle::default().layout(vec![Progress, Close, Text, Spacer, Icon]);
+    /// let mut test = ToastTestCtx::new(Toasts::new().style(style));
+    /// let options = ToastOptions::default().duration_in_seconds(5.0);
+    /// let handle = test.add(Toast::new().text("Saved").options(options));
+    /// test.run_frames(2);
+    /// let response = |id| test.ctx().read_response(id).unwrap().rect;
+    /// let id = handle.id();
+    /// assert!(response(id.close_button_id()).left() < response(id.text_id()).left());
+    /// assert!(response(id.progress_bar_id()).top() < response(id.text_id()).top());
+    /// ```
+    #[must_use]
+    pub fn layout(mut self, layout: Vec<ToastSlot>) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// The [layout](Self::layout) without repeated parts, or the default layout if it is
+    /// empty.
+    pub(crate) fn slots(&self) -> Vec<ToastSlot> {
+        if self.layout.is_empty() {
+            return ToastSlot::DEFAULT_LAYOUT.to_vec();
+        }
+        let mut slots = Vec::with_capacity(self.layout.len());
+        for &slot in &self.layout {
+            if !slots.contains(&slot) {
+                slots.push(slot);
+            }
+        }
+        slots
+    }
+
+    /// Set how the remaining time is visualized.
+    #[must_use]
+    pub fn progress_style(mut self, progress_style: ProgressStyle) -> Self {
+        self.progress_style = progress_style;
+        self
+    }
+
+    /// Color of the progress, which is dimmed while the countdown is stopped.
+    pub(crate) fn progress_color(&self, color: Color32, paused: bool) -> Color32 {
+        match (paused, self.progress_paused_color) {
+            (false, _) => color,
+            (true, Some(paused_color)) => paused_color,
+            (true, None) => color.gamma_multiply(0.4),
+        }
+    }
+
+    /// Frame drawn around the default contents of a toast.
+    ///
+    /// The frame has no stroke, since the stroke is drawn on top of the contents,
+    /// see [`Self::frame_stroke`].
+    pub(crate) fn frame(&self, style: &Style) -> Frame {
+        let mut frame = Frame::window(style)
+            .inner_margin(self.inner_margin)
+            .stroke(Stroke::NONE);
+        if let Some(rounding) = self.rounding {
+            frame = frame.rounding(rounding);
+        }
+        if let Some(fill) = self.fill {
+            frame = frame.fill(fill);
+        }
+        if let Some(shadow) = self.shadow {
+            frame = frame.shadow(shadow);
+        }
+        frame
+    }
+
+    /// The style with its lengths multiplied by `scale` and rounded to whole pixels, see
+    /// [`Toasts::scale`](crate::Toasts::scale).
+    pub(crate) fn scaled(&self, ctx: &Context, scale: f32) -> Self {
+        let mut style = self.clone();
+        if scale != 1.0 {
+            style.inner_margin = scale::length(ctx, self.inner_margin, scale);
+            style.progress_segment_gap = scale::length(ctx, self.progress_segment_gap, scale);
+            if let Some(edge) = &mut style.accent_edge {
+                edge.width = scale::length(ctx, edge.width, scale);
+            }
+        }
+        style
+    }
+
+    /// Outline drawn around the default contents of a toast.
+    pub(crate) fn frame_stroke(&self, visuals: &Visuals) -> Stroke {
+        self.stroke.unwrap_or(visuals.window_stroke)
+    }
+
+    /// Apply the [hover highlight](Self::hover) to the frame and the outline of a toast.
+    pub(crate) fn highlight(&self, frame: &mut Frame, stroke: &mut Stroke, kind: ToastKind) {
+        let Some(hover) = self.hover else {
+            return;
+        };
+        frame.fill = hover.fill.unwrap_or_else(|| lighten(frame.fill));
+        *stroke = hover
+            .stroke
+            .unwrap_or_else(|| Stroke::new(stroke.width.max(1.0), self.accent_color(kind)));
+        if let Some(shadow) = hover.shadow {
+            frame.shadow = shadow;
+        }
+    }
+
+    /// Color of the text and the progress of a toast.
+    pub(crate) fn text_color(&self, visuals: &Visuals) -> Color32 {
+        self.text_color.unwrap_or(visuals.text_color())
+    }
+
+    /// Use the text color of the style for the contents of the toast.
+    pub(crate) fn apply_text_color(&self, ui: &mut Ui) {
+        if let Some(text_color) = self.text_color {
+            ui.visuals_mut().override_text_color = Some(text_color);
+        }
+    }
+
+    /// Style with plain ASCII icons, for fonts that don't have the default icons.
+    pub fn ascii() -> Self {
+        Self {
+            info_icon: WidgetText::from("[i]").color(INFO_COLOR),
+            warning_icon: WidgetText::from("[!]").color(WARNING_COLOR),
+            error_icon: WidgetText::from("[x]").color(ERROR_COLOR),
+            success_icon: WidgetText::from("[v]").color(SUCCESS_COLOR),
+            close_button_text: WidgetText::from("X"),
+            ..Default::default()
+        }
+    }
+
+    /// Set the icons that are tried in order if the fonts can't display the icon of the kind.
+    ///
+    /// ```
+    /// # use egui_toast::{ToastKind, ToastStyle};
+    /// let style = ToastStyle::default()
+    ///     .icon_fallbacks(ToastKind::Warning, vec!["⚠".into(), "[!]".into()]);
+    /// ```
+    #[must_use]
+    pub fn icon_fallbacks(mut self, kind: ToastKind, fallbacks: Vec<WidgetText>) -> Self {
+        self.icon_fallbacks.insert(kind, fallbacks);
+        self
+    }
+
+    /// Configured icon of the given toast kind, without the fallbacks.
+    fn primary_icon(&self, kind: ToastKind) -> &WidgetText {
+        match kind {
+            ToastKind::Warning => &self.warning_icon,
+            ToastKind::Error => &self.error_icon,
+            ToastKind::Success => &self.success_icon,
+            _ => &self.info_icon,
+        }
+    }
+
+    fn primary_icon_mut(&mut self, kind: ToastKind) -> &mut WidgetText {
+        match kind {
+            ToastKind::Warning => &mut self.warning_icon,
+            ToastKind::Error => &mut self.error_icon,
+            ToastKind::Success => &mut self.success_icon,
+            _ => &mut self.info_icon,
+        }
+    }
+
+    /// Icon of the given toast kind: the configured icon, or the first of the
+    /// [fallbacks](Self::icon_fallbacks) that the fonts can display.
+    pub(crate) fn icon(&self, ctx: &Context, kind: ToastKind) -> WidgetText {
+        let primary = self.primary_icon(kind);
+        let font_id = TextStyle::Body.resolve(&ctx.style());
+        std::iter::once(primary)
+            .chain(self.icon_fallbacks.get(&kind).into_iter().flatten())
+            .find(|icon| has_glyphs(ctx, &font_id, icon))
+            .unwrap_or(primary)
+            .clone()
+    }
+
+    /// Check whether the fonts of the context can display the configured icons and the close
+    /// button. Only valid after the first frame, once egui has loaded the fonts.
+    ///
+    /// Can be used to switch to the [ASCII icons](Self::ascii) if the fonts of the app
+    /// don't cover the default icons:
+    /// ```
+    /// # use egui_toast::ToastStyle;
+    /// let ctx = egui::Context::default();
+    /// ctx.set_fonts(egui::FontDefinitions::empty());
+    /// let _ = ctx.run(Default::default(), |_| {});
+    ///
+    /// let mut style = ToastStyle::default();
+    /// if !style.validate_fonts(&ctx).is_empty() {
+    ///     style = ToastStyle::ascii();
+    /// }
+    /// assert_eq!(style.info_icon.text(), "[i]");
+    /// ```
+    pub fn validate_fonts(&self, ctx: &Context) -> Vec<MissingGlyph> {
+        let kinds = [
+            ToastKind::Info,
+            ToastKind::Warning,
+            ToastKind::Error,
+            ToastKind::Success,
+        ];
+        let style = ctx.style();
+        let icons = kinds.map(|kind| {
+            let font_id = TextStyle::Body.resolve(&style);
+            (Some(kind), self.primary_icon(kind), font_id)
+        });
+        let close_button = (
+            None,
+            &self.close_button_text,
+            TextStyle::Button.resolve(&style),
+        );
+        let mut missing = Vec::new();
+        for (kind, text, font_id) in icons.into_iter().chain([close_button]) {
+            let text = text.text();
+            for glyph in text.chars() {
+                if !ctx.fonts(|fonts| fonts.has_glyph(&font_id, glyph)) {
+                    missing.push(MissingGlyph {
+                        kind,
+                        text: text.to_owned(),
+                        glyph,
+                    });
+                }
+            }
+        }
+        missing
+    }
+
+    /// Accent color of the given toast kind.
+    pub fn accent_color(&self, kind: ToastKind) -> Color32 {
+        match kind {
+            ToastKind::Warning => self.warning_color,
+            ToastKind::Error => self.error_color,
+            ToastKind::Success => self.success_color,
+            _ => self.info_color,
+        }
+    }
+}
+
+fn has_glyphs(ctx: &Context, font_id: &FontId, text: &WidgetText) -> bool {
+    ctx.fonts(|fonts| fonts.has_glyphs(font_id, text.text()))
+}
+
+/// A glyph that the fonts can't display, see [`ToastStyle::validate_fonts`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingGlyph {
+    /// Kind of the icon that has the glyph, or `None` for the close button.
+    pub kind: Option<ToastKind>,
+    /// The text of the icon or the close button.
+    pub text: String,
+    /// The missing glyph.
+    pub glyph: char,
+}
+
+/// A part of the default toast contents, placed in the order of [`ToastStyle::layout`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ToastSlot {
+    /// The icon of the kind of the toast.
+    Icon,
+    /// The text of the toast.
+    Text,
+    /// The [action buttons](Toast::button).
+    Buttons,
+    /// The close button.
+    Close,
+    /// The progress bar, drawn across the top or the bottom of the toast.
+    Progress,
+    /// Pushes the parts after it to the trailing edge of a toast that is wider than its
+    /// contents, e.g. because of its [minimum size](crate::Toasts::min_size).
+    Spacer,
+}
+
+impl ToastSlot {
+    /// The icon, text and buttons at the leading edge, the close button at the trailing edge,
+    /// and the progress bar at the bottom.
+    pub const DEFAULT_LAYOUT: [Self; 6] = [
+        Self::Icon,
+        Self::Text,
+        Self::Buttons,
+        Self::Spacer,
+        Self::Close,
+        Self::Progress,
+    ];
+}
+
+/// How the default toast contents visualize the remaining time of the toast.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ProgressStyle {
+    /// A bar at the bottom of the toast, or at the top, see [`ToastSlot::Progress`].
+    #[default]
+    Bar,
+    /// An arc around the close button, drawn in the [accent color](ToastStyle::accent_color)
+    /// of the toast. Falls back to [`ProgressStyle::Bar`] if the close button is hidden.
+    CircleAroundClose,
+}
+
+/// Texts shown by the default toast contents, in addition to the toast's own text.
+///
+/// All built-in texts are taken from here, so the toasts can be fully translated by
+/// replacing the English defaults.
+///
+/// With the `accesskit` feature, the accessible names are exposed to screen readers,
+/// and the toast itself has the role of an alert (warnings and errors) or a status:
+/// ```
+/// # #[cfg(feature = "accesskit")] {
+/// use egui::accesskit::Role;
+/// use egui_toast::{Toast, ToastKind, Toasts};
+///
+/// let ctx = egui::Context::default();
+/// ctx.enable_accesskit();
+/// let mut toasts = Toasts::new();
+/// toasts.add(Toast::new().kind(ToastKind::Error).text("Failed"));
+///
+/// let mut nodes = Vec::new();
+/// for _ in 0..3 {
+///     let output = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+///     nodes = output.platform_output.accesskit_update.unwrap().nodes;
+/// }
+/// let has_name = |name| nodes.iter().any(|(_, node)| node.name() == Some(name));
+/// assert!(has_name("Dismiss notification"));
+/// assert!(has_name("Error"));
+/// assert!(nodes.iter().any(|(_, node)| node.role() == Role::Alert));
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ToastStrings {
+    /// Tooltip of the close button. No tooltip is shown if this is empty.
+    pub close_button_tooltip: String,
+    /// Accessible name of the close button, read by screen readers.
+    pub close_button_label: String,
+    /// Accessible name of the info icon.
+    pub info_label: String,
+    /// Accessible name of the warning icon.
+    pub warning_label: String,
+    /// Accessible name of the error icon.
+    pub error_label: String,
+    /// Accessible name of the success icon.
+    pub success_label: String,
+    /// Accessible name of the button that expands a [group](Toast::group) of toasts.
+    pub expand_group_label: String,
+    /// Accessible name of the button that collapses a [group](Toast::group) of toasts.
+    pub collapse_group_label: String,
+    /// Title of the summary of [automatically grouped](crate::Toasts::auto_group) info toasts.
+    /// `{count}` is replaced with the number of grouped toasts.
+    pub more_info: String,
+    /// Like [`Self::more_info`], but for warnings.
+    pub more_warnings: String,
+    /// Like [`Self::more_info`], but for errors.
+    pub more_errors: String,
+    /// Like [`Self::more_info`], but for successes.
+    pub more_successes: String,
+    /// Shown after the text of a toast that other toasts were
+    /// [merged](crate::KindLimitPolicy::Merge) into. `{count}` is replaced with the number of toasts.
+    pub merged_count: String,
+    /// Text of the toast reporting how many toasts were dropped because the
+    /// [capacity](crate::Toasts::capacity) was reached. `{count}` is replaced with the number.
+    pub dropped_toasts: String,
+    /// Text of the toast reporting how many toasts were suppressed because far too many
+    /// toasts were added at once, see [`Toasts::capacity`](crate::Toasts::capacity).
+    /// `{count}` is replaced with the number.
+    pub suppressed_toasts: String,
+    /// Label of the [clear all button](crate::Toasts::clear_all_button). `{count}` is replaced
+    /// with the number of toasts cleared by the button.
+    pub clear_all: String,
+    /// Label of the [suppressed badge](crate::Toasts::suppressed_badge). `{count}` is replaced
+    /// with the number of suppressed toasts.
+    pub suppressed_badge: String,
+    /// Label of the collapsible [details](Toast::details) section.
+    pub details: String,
+    /// Entry of the [context menu](crate::Toasts::context_menu) that copies the text of the
+    /// toast to the clipboard.
+    pub copy_text: String,
+    /// Entry of the context menu that opens the [details](Toast::details).
+    pub open_details: String,
+    /// Entry of the context menu that closes the toast.
+    pub dismiss: String,
+    /// Entry of the context menu that dismisses the toasts of the same kind.
+    pub dismiss_kind: String,
+    /// Prefix of the [announcements](Toast::announcement) with
+    /// [`ToastStyle::announce_kind`]. `{kind}` is replaced with the label of the kind.
+    pub kind_prefix: String,
 }
 
+impl Default for ToastStrings {
+    fn default() -> Self {
+        Self {
+            close_button_tooltip: "Close".to_owned(),
+            close_button_label: "Dismiss notification".to_owned(),
+            info_label: "Info".to_owned(),
+            warning_label: "Warning".to_owned(),
+            error_label: "Error".to_owned(),
+            success_label: "Success".to_owned(),
+            expand_group_label: "Show all".to_owned(),
+            collapse_group_label: "Show less".to_owned(),
+            more_info: "{count} more messages".to_owned(),
+            more_warnings: "{count} more warnings".to_owned(),
+            more_errors: "{count} more errors".to_owned(),
+            more_successes: "{count} more successes".to_owned(),
+            merged_count: "×{count}".to_owned(),
+            dropped_toasts: "Too many notifications, {count} dropped".to_owned(),
+            suppressed_toasts: "{count} notifications suppressed".to_owned(),
+            clear_all: "Clear all ({count})".to_owned(),
+            suppressed_badge: "{count} suppressed".to_owned(),
+            details: "Details".to_owned(),
+            copy_text: "Copy text".to_owned(),
+            open_details: "Open details".to_owned(),
+            dismiss: "Dismiss".to_owned(),
+            dismiss_kind: "Dismiss all of this kind".to_owned(),
+            kind_prefix: "{kind}: ".to_owned(),
+        }
+    }
+}
+
+impl ToastStrings {
+    /// Title of the summary of `count` [automatically grouped](crate::Toasts::auto_group)
+    /// toasts of the given kind.
+    pub fn more_toasts(&self, kind: ToastKind, count: usize) -> String {
+        let template = match kind {
+            ToastKind::Warning => &self.more_warnings,
+            ToastKind::Error => &self.more_errors,
+            ToastKind::Success => &self.more_successes,
+            _ => &self.more_info,
+        };
+        template.replace("{count}", &count.to_string())
+    }
+
+    /// Accessible name of the icon of the given kind.
+    pub fn kind_label(&self, kind: ToastKind) -> &str {
+        match kind {
+            ToastKind::Warning => &self.warning_label,
+            ToastKind::Error => &self.error_label,
+            ToastKind::Success => &self.success_label,
+            _ => &self.info_label,
+        }
+    }
+}
+
+/// Horizontal direction of the default toast contents.
+///
+/// In a right-to-left layout the whole toast is mirrored: the icon is on the right,
+/// the close button on the left, and the progress bar shrinks towards the right.
+///
+/// ```
+/// # use egui_toast::{LayoutDirection, Toast, ToastStyle, Toasts};
+/// let ctx = egui::Context::default();
+/// let mut toasts = Toasts::new();
+/// let id = toasts.add(Toast::new().text("مرحبا").style(ToastStyle {
+///     layout_direction: LayoutDirection::RightToLeft,
+///     ..Default::default()
+/// })).id();
+/// for _ in 0..3 {
+///     let _ = ctx.run(Default::default(), |ctx| toasts.show(ctx));
+/// }
+/// let close = ctx.read_response(id.close_button_id()).unwrap().rect;
+/// let text = ctx.read_response(id.text_id()).unwrap().rect;
+/// let progress = ctx.read_response(id.progress_bar_id()).unwrap().rect;
+/// assert!(close.right() <= text.left());
+/// assert!(!progress.intersects(close) && !progress.intersects(text));
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum LayoutDirection {
+    /// Follow the layout of the [`egui::Ui`] the toast is drawn in.
+    #[default]
+    Auto,
+    LeftToRight,
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    pub(crate) fn is_right_to_left(self, ui: &egui::Ui) -> bool {
+        match self {
+            Self::Auto => ui.layout().prefer_right_to_left(),
+            Self::LeftToRight => false,
+            Self::RightToLeft => true,
+        }
+    }
+}
+
+const INFO_COLOR: Color32 = Color32::from_rgb(0, 155, 255);
+const WARNING_COLOR: Color32 = Color32::from_rgb(255, 212, 0);
+const ERROR_COLOR: Color32 = Color32::from_rgb(255, 32, 0);
+const SUCCESS_COLOR: Color32 = Color32::from_rgb(0, 255, 32);
+
 impl Default for ToastStyle {
     fn default() -> Self {
         Self {
-            info_icon: WidgetText::from("ℹ").color(Color32::from_rgb(0, 155, 255)),
-            warning_icon: WidgetText::from("⚠").color(Color32::from_rgb(255, 212, 0)),
-            error_icon: WidgetText::from("❗").color(Color32::from_rgb(255, 32, 0)),
-            success_icon: WidgetText::from("✔").color(Color32::from_rgb(0, 255, 32)),
+            info_icon: WidgetText::from("ℹ").color(INFO_COLOR),
+            warning_icon: WidgetText::from("⚠").color(WARNING_COLOR),
+            error_icon: WidgetText::from("❗").color(ERROR_COLOR),
+            success_icon: WidgetText::from("✔").color(SUCCESS_COLOR),
+            icon_fallbacks: HashMap::new(),
             close_button_text: WidgetText::from("🗙"),
+            layout_direction: LayoutDirection::Auto,
+            layout: ToastSlot::DEFAULT_LAYOUT.to_vec(),
+            strings: ToastStrings::default(),
+            primary_button: ToastButtonStyle::default(),
+            secondary_button: ToastButtonStyle::default(),
+            progress_style: ProgressStyle::Bar,
+            progress_paused_color: None,
+            progress_segment_gap: 2.0,
+            progress_segment_colors: Vec::new(),
+            paused_opacity: 0.8,
+            inner_margin: 10.0,
+            rounding: None,
+            fill: None,
+            stroke: None,
+            shadow: None,
+            text_color: None,
+            hover: None,
+            urgency_outline: false,
+            min_contrast: None,
+            accent_edge: None,
+            update_blip: None,
+            announce_kind: false,
+            info_color: INFO_COLOR,
+            warning_color: WARNING_COLOR,
+            error_color: ERROR_COLOR,
+            success_color: SUCCESS_COLOR,
         }
     }
 }
 
 #[derive(Debug, Copy, Clone)]
 pub struct ToastOptions {
-    /// Whether the toast should include an icon.
+    /// Whether the toast should include an icon. Follows
+    /// [`Toasts::show_icons`](crate::Toasts::show_icons) unless set with [`Self::show_icon`].
     pub show_icon: bool,
-    /// Whether the toast should visualize the remaining time
+    /// Whether the toast should visualize the remaining time. Follows
+    /// [`Toasts::show_progress_bars`](crate::Toasts::show_progress_bars) unless set with
+    /// [`Self::show_progress`].
     pub show_progress: bool,
-    /// The toast is removed when this reaches zero.
-    pub(crate) ttl_sec: f64,
-    /// Initial value of ttl_sec, used for progress
-    pub(crate) initial_ttl_sec: f64,
+    /// Whether the toast should have a close button.
+    pub show_close_button: bool,
+    /// Whether the toast is added [paused](crate::ToastHandle::pause).
+    pub start_paused: bool,
+    /// Whether the text keeps the width of the widest text the toast has shown, see
+    /// [`Self::never_shrink`].
+    pub never_shrink: bool,
+    /// Whether the remaining time can be set by clicking or dragging the progress bar, see
+    /// [`Self::progress_interactive`].
+    pub progress_interactive: bool,
+    /// Minimum size of the toast, overriding [`Toasts::min_size`](crate::Toasts::min_size).
+    pub min_size: Option<Vec2>,
+    /// Initial duration of the toast, or `None` if the toast never expires.
+    pub(crate) duration: Option<Duration>,
+    /// The toast is removed when this reaches zero. Not counted down if the toast never expires.
+    pub(crate) remaining: Duration,
+    /// Why the toast was dismissed before it expired.
+    pub(crate) dismissed: Option<DismissReason>,
+    /// Whether the toast counts up instead of down, see [`Self::stopwatch`].
+    pub(crate) stopwatch: bool,
+    /// Time the toast has been shown, counted in [stopwatch](Self::stopwatch) mode.
+    pub(crate) elapsed: Duration,
+    /// Set with [`Self::urgency`]. Follows the kind of the toast if `None`.
+    pub(crate) urgency: Option<f32>,
+    /// Set with [`Self::time_scale`]. Follows [`Toasts::time_scale`](crate::Toasts::time_scale)
+    /// if `None`.
+    pub(crate) time_scale: Option<f32>,
+    /// Set with [`Self::dismiss_on_input`]. Follows the kind of the toast if `None`.
+    pub(crate) dismiss_on_input: Option<bool>,
+    /// See [`Self::lane`].
+    pub(crate) lane: Option<Id>,
+    /// See [`Self::broadcast`].
+    pub(crate) broadcast: bool,
+    /// See [`Self::selectable_text`]. Follows the egui style if `None`.
+    pub(crate) selectable_text: Option<bool>,
+    /// Lane whose copy of the broadcast toast was dismissed, see [`Self::dismissed_in`].
+    pub(crate) dismissed_in: Option<Id>,
+    /// See [`Self::context_menu`].
+    pub(crate) context_menu: Option<bool>,
+    /// See [`Self::min_display`].
+    pub(crate) min_display: Duration,
+    /// Time the toast has been on screen.
+    pub(crate) displayed: Duration,
+    /// Reason of a bulk dismissal that waits for the [minimum display time](Self::min_display).
+    pub(crate) deferred: Option<DismissReason>,
+    /// Whether [`Self::show_icon`] was called, so that the instance default does not apply.
+    pub(crate) show_icon_set: bool,
+    /// Whether [`Self::show_progress`] was called.
+    pub(crate) show_progress_set: bool,
 }
 
 impl Default for ToastOptions {
@@ -94,50 +1900,623 @@ impl Default for ToastOptions {
         Self {
             show_icon: true,
             show_progress: true,
-            ttl_sec: f64::INFINITY,
-            initial_ttl_sec: f64::INFINITY,
+            show_close_button: true,
+            start_paused: false,
+            never_shrink: false,
+            progress_interactive: false,
+            min_size: None,
+            duration: None,
+            remaining: Duration::ZERO,
+            dismissed: None,
+            stopwatch: false,
+            elapsed: Duration::ZERO,
+            urgency: None,
+            time_scale: None,
+            dismiss_on_input: None,
+            lane: None,
+            broadcast: false,
+            selectable_text: None,
+            dismissed_in: None,
+            context_menu: None,
+            min_display: Duration::ZERO,
+            displayed: Duration::ZERO,
+            deferred: None,
+            show_icon_set: false,
+            show_progress_set: false,
         }
     }
 }
 
 impl ToastOptions {
     /// Set duration of the toast. [None] duration means the toast never expires.
+    ///
+    /// The duration is measured with the time of the egui input, so a toast also expires on
+    /// time in apps that only repaint on input. egui is asked to repaint when the toast expires,
+    /// and a toast whose time ran out between two frames is not drawn again.
+    ///
+    /// ```
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// # fn run(ctx: &egui::Context, toasts: &mut Toasts, time: f64) -> egui::FullOutput {
+    /// #     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    /// #     ctx.run(input, |ctx| toasts.show(ctx))
+    /// # }
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new();
+    /// let options = ToastOptions::default().duration_in_seconds(3.0).show_progress(false);
+    /// toasts.add(Toast::new().text("Saved").options(options));
+    /// run(&ctx, &mut toasts, 0.0);
+    /// let output = run(&ctx, &mut toasts, 0.1);
+    /// assert!(!output.shapes.is_empty());
+    /// let repaint_delay = output.viewport_output[&egui::ViewportId::ROOT].repaint_delay;
+    /// assert!(repaint_delay.as_secs_f64() <= 3.0);
+    ///
+    /// // The next frame comes long after the toast expired
+    /// let output = run(&ctx, &mut toasts, 100.0);
+    /// assert!(output.shapes.is_empty());
+    /// ```
+    #[must_use]
     pub fn duration(mut self, duration: impl Into<Option<Duration>>) -> Self {
-        self.ttl_sec = duration
-            .into()
-            .map_or(f64::INFINITY, |duration| duration.as_secs_f64());
-        self.initial_ttl_sec = self.ttl_sec;
+        self.duration = duration.into();
+        self.remaining = self.duration.unwrap_or_default();
+        self
+    }
+
+    /// Count up the time since the toast was first shown instead of counting down, for a toast
+    /// about an ongoing activity, such as "Recording… 01:23".
+    ///
+    /// The toast never expires, and stays until it is closed, e.g. with
+    /// [`ToastHandle::close`](crate::ToastHandle::close). The default contents show the
+    /// [elapsed time](Toast::elapsed) after the text, with a blinking dot instead of the
+    /// progress bar, and repaint once per second.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// # fn run(ctx: &egui::Context, toasts: &mut Toasts, time: f64) -> egui::FullOutput {
+    /// #     let input = egui::RawInput { time: Some(time), ..Default::default() };
+    /// #     ctx.run(input, |ctx| toasts.show(ctx))
+    /// # }
+    /// let ctx = egui::Context::default();
+    /// let mut toasts = Toasts::new();
+    /// let options = ToastOptions::default().stopwatch(true);
+    /// let handle = toasts.add(Toast::new().text("Recording…").options(options));
+    /// run(&ctx, &mut toasts, 0.0);
+    /// run(&ctx, &mut toasts, 0.0);
+    /// let output = run(&ctx, &mut toasts, 83.5);
+    /// let repaint_delay = output.viewport_output[&egui::ViewportId::ROOT].repaint_delay;
+    /// assert!(repaint_delay <= Duration::from_millis(500));
+    ///
+    /// handle.close(&ctx);
+    /// run(&ctx, &mut toasts, 84.0);
+    /// run(&ctx, &mut toasts, 84.1);
+    /// assert!(ctx.read_response(handle.id().text_id()).is_none());
+    /// ```
+    #[must_use]
+    pub fn stopwatch(mut self, stopwatch: bool) -> Self {
+        self.stopwatch = stopwatch;
+        if stopwatch {
+            self = self.duration(None);
+        }
+        self
+    }
+
+    /// How urgent the toast is, from 0 to 1, for apps that map it to e.g. controller rumble
+    /// or the volume of a sound, see [`Toasts::on_show`](crate::Toasts::on_show).
+    ///
+    /// Defaults to 0.2 for info and success toasts, 0.5 for warnings and 0.9 for errors.
+    /// Read it with [`Toast::urgency`].
+    #[must_use]
+    pub fn urgency(mut self, urgency: f32) -> Self {
+        // `f32::clamp` keeps NaN
+        self.urgency = Some(if urgency.is_nan() {
+            0.0
+        } else {
+            urgency.clamp(0.0, 1.0)
+        });
         self
     }
 
+    /// Whether the toast is dismissed by the input of
+    /// [`Toasts::dismiss_on_input`](crate::Toasts::dismiss_on_input).
+    ///
+    /// Defaults to `true` for info and success toasts, and to `false` for the other kinds,
+    /// which usually need the attention of the user. Read it with
+    /// [`Toast::dismisses_on_input`].
+    #[must_use]
+    pub fn dismiss_on_input(mut self, dismiss_on_input: bool) -> Self {
+        self.dismiss_on_input = Some(dismiss_on_input);
+        self
+    }
+
+    /// Show the toast in another lane: the [`Toasts`](crate::Toasts) instance created with
+    /// [`Toasts::with_id`](crate::Toasts::with_id) and the given id, e.g. a stack of critical
+    /// alerts in the middle of the screen. This takes precedence over
+    /// [`Toasts::route_kind`](crate::Toasts::route_kind).
+    #[must_use]
+    pub fn lane(mut self, lane: impl Into<Id>) -> Self {
+        self.lane = Some(lane.into());
+        self
+    }
+
+    /// Also show the toast in the lanes its lane [broadcasts to](crate::Toasts::broadcast_to),
+    /// e.g. a critical alert in the main viewport and in a detached monitor viewport.
+    ///
+    /// The toast is stored once, in its lane, and the other lanes show copies of it on their
+    /// next update. The copies count down with the toast, hovering any of them pauses the
+    /// toast, and dismissing any of them dismisses the toast, with
+    /// [`Self::dismissed_in`] set to the lane of the copy. The sound and the
+    /// [show hook](crate::Toasts::on_show) only run for the toast itself.
+    ///
+    /// ```
+    /// # use std::sync::{Arc, Mutex};
+    /// # use egui_toast::{Align2, DismissReason, Toast, ToastOptions, Toasts};
+    /// fn run(ctx: &egui::Context, mut lanes: [&mut Toasts; 2], events: Vec<egui::Event>) {
+    ///     let input = egui::RawInput { events, ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| lanes.iter_mut().for_each(|lane| lane.show(ctx)));
+    /// }
+    /// let ctx = egui::Context::default();
+    /// let monitor_id = egui::Id::new("monitor");
+    /// let mut main = Toasts::new().broadcast_to(monitor_id);
+    /// let mut monitor = Toasts::with_id(monitor_id).anchor(Align2::LEFT_TOP, (0.0, 0.0));
+    /// let dismissed = Arc::new(Mutex::new(None));
+    /// let on_dismiss = dismissed.clone();
+    /// let options = ToastOptions::default().duration_in_seconds(5.0).broadcast(true);
+    /// let handle = main.add(Toast::new().text("Reactor overheating").options(options).on_dismiss(
+    ///     move |toast, reason| *on_dismiss.lock().unwrap() = Some((reason, toast.options.dismissed_in())),
+    /// ));
+    /// for _ in 0..3 {
+    ///     run(&ctx, [&mut main, &mut monitor], vec![]);
+    /// }
+    /// let [copy] = monitor.occupied_rects(&ctx).try_into().unwrap();
+    /// assert_eq!(main.occupied_rects(&ctx).len(), 1);
+    ///
+    /// // Hovering the copy pauses the toast
+    /// let hover = egui::Event::PointerMoved(copy.center());
+    /// run(&ctx, [&mut main, &mut monitor], vec![hover]);
+    /// run(&ctx, [&mut main, &mut monitor], vec![]);
+    /// assert!(handle.is_paused(&ctx));
+    ///
+    /// // Dismissing the copy dismisses the toast
+    /// monitor.dismiss_lane(&ctx, monitor_id);
+    /// for _ in 0..2 {
+    ///     run(&ctx, [&mut main, &mut monitor], vec![]);
+    /// }
+    /// let dismissed = *dismissed.lock().unwrap();
+    /// assert_eq!(dismissed, Some((DismissReason::LaneCleared, Some(monitor_id))));
+    /// assert!(main.occupied_rects(&ctx).is_empty());
+    /// ```
+    #[must_use]
+    pub fn broadcast(mut self, broadcast: bool) -> Self {
+        self.broadcast = broadcast;
+        self
+    }
+
+    /// Whether the text of the default contents can be selected with the mouse, e.g. to copy
+    /// a part of it. Follows [`egui::style::Interaction::selectable_labels`] unless set.
+    ///
+    /// The toast is paused while its text is being selected or has a selection, even when the
+    /// pointer leaves the toast, so that it does not vanish mid-selection. The selection ends
+    /// when egui clears it or the pointer is pressed outside the text. Keys don't
+    /// [dismiss](crate::Toasts::dismiss_on_input) the toasts meanwhile, so that the selection
+    /// can be copied.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Align2, Toast, ToastOptions, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::new().anchor(Align2::RIGHT_BOTTOM, (0.0, 0.0)));
+    /// let options = ToastOptions::default().duration_in_seconds(1.0).selectable_text(true);
+    /// let id = test.add(Toast::new().text("Saved to /tmp/report.pdf").options(options)).id();
+    /// test.run_frames(3);
+    /// let text = test.ctx().read_response(id.text_id()).unwrap().rect;
+    /// # // Without fonts, the text has no size to select
+    /// # if !text.is_positive() { return; }
+    ///
+    /// // Select the text by dragging over it and out of the toast
+    /// test.press(text.left_center());
+    /// test.run();
+    /// test.hover(text.right_center() - egui::vec2(0.0, 200.0));
+    /// test.run_for(Duration::from_secs(2), 10);
+    /// test.release();
+    /// test.run_for(Duration::from_secs(2), 10);
+    /// assert!(test.is_visible(id));
+    ///
+    /// // Pressing elsewhere ends the selection
+    /// test.click(egui::pos2(10.0, 10.0));
+    /// test.run_for(Duration::from_secs(2), 10);
+    /// assert!(!test.is_visible(id));
+    /// ```
+    #[must_use]
+    pub fn selectable_text(mut self, selectable: bool) -> Self {
+        self.selectable_text = Some(selectable);
+        self
+    }
+
+    /// Whether right-clicking the toast opens its context menu, overriding
+    /// [`Toasts::context_menu`](crate::Toasts::context_menu).
+    #[must_use]
+    pub fn context_menu(mut self, context_menu: bool) -> Self {
+        self.context_menu = Some(context_menu);
+        self
+    }
+
+    /// Keep the toast on screen for at least `min_display`, also when it is dismissed together
+    /// with other toasts: by the [clear all button](crate::Toasts::clear_all_button),
+    /// [clicking outside](crate::Toasts::dismiss_on_click_outside),
+    /// [input](crate::Toasts::dismiss_on_input), [`Toasts::clear_scope`](crate::Toasts::clear_scope)
+    /// or [`Toasts::dismiss_lane`](crate::Toasts::dismiss_lane). These dismissals are deferred
+    /// until the time has passed, and the toast is then dismissed with their reason.
+    ///
+    /// Closing the toast itself, with the close button or a [handle](crate::ToastHandle::close),
+    /// removes it right away. The duration is extended to `min_display` if it is shorter.
+    ///
+    /// ```
+    /// # use std::sync::{Arc, Mutex};
+    /// # use std::time::Duration;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{DismissReason, Toast, ToastOptions, Toasts};
+    /// let reasons = Arc::new(Mutex::new(Vec::new()));
+    /// let dismissed = reasons.clone();
+    /// let options = ToastOptions::default()
+    ///     .duration_in_seconds(10.0)
+    ///     .min_display(Duration::from_secs(1));
+    /// let mut test = ToastTestCtx::new(Toasts::new());
+    /// let toast = Toast::new()
+    ///     .text("Upload failed")
+    ///     .scope("uploads")
+    ///     .options(options)
+    ///     .on_dismiss(move |_, reason| dismissed.lock().unwrap().push(reason));
+    /// let handle = test.add(toast);
+    /// test.run_frames(2);
+    ///
+    /// let ctx = test.ctx().clone();
+    /// test.toasts().clear_scope(&ctx, "uploads");
+    /// test.run_for(Duration::from_millis(500), 5);
+    /// assert!(test.is_visible(handle.id()));
+    /// test.run_for(Duration::from_secs(1), 10);
+    /// assert!(!test.is_visible(handle.id()));
+    /// assert_eq!(*reasons.lock().unwrap(), [DismissReason::ScopeCleared]);
+    /// ```
+    #[must_use]
+    pub fn min_display(mut self, min_display: Duration) -> Self {
+        self.min_display = min_display;
+        self
+    }
+
+    /// How fast the time of this toast runs, overriding
+    /// [`Toasts::time_scale`](crate::Toasts::time_scale).
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::new());
+    /// // Practically frozen, the repaint would only be due in far more than `Duration::MAX`
+    /// let options = ToastOptions::default().duration_in_seconds(10.0).time_scale(1e-30);
+    /// let frozen = test.add(Toast::new().text("Frozen").options(options)).id();
+    /// let stopwatch = ToastOptions::default().stopwatch(true).time_scale(1e-30);
+    /// let elapsed = test.add(Toast::new().text("Elapsed").options(stopwatch)).id();
+    /// test.run_for(Duration::from_secs(20), 10);
+    /// assert!(test.is_visible(frozen) && test.is_visible(elapsed));
+    /// ```
+    #[must_use]
+    pub fn time_scale(mut self, time_scale: f32) -> Self {
+        self.time_scale = Some(crate::time::clamp_scale(time_scale));
+        self
+    }
+
+    /// The time scale of the toast, or `global` if it does not override it.
+    pub(crate) fn time_scale_or(self, global: f32) -> f32 {
+        self.time_scale.unwrap_or(global)
+    }
+
+    /// Whether the toast counts up, see [`Self::stopwatch`].
+    pub fn is_stopwatch(self) -> bool {
+        self.stopwatch
+    }
+
     /// Set duration of the toast in milliseconds.
+    #[must_use]
     pub fn duration_in_millis(self, millis: u64) -> Self {
         self.duration(Duration::from_millis(millis))
     }
 
     /// Set duration of the toast in seconds.
+    ///
+    /// Negative and NaN durations are treated as zero, and infinite durations mean
+    /// that the toast never expires. A toast with a zero duration is not shown, unless
+    /// [`Toasts::min_display_time`](crate::Toasts::min_display_time) is set.
+    ///
+    /// ```
+    /// # use egui_toast::ToastOptions;
+    /// let remaining = |secs| ToastOptions::default().duration_in_seconds(secs).progress();
+    /// assert_eq!(remaining(2.0), 1.0);
+    /// assert_eq!(remaining(0.0), 0.0);
+    /// assert_eq!(remaining(-1.0), 0.0);
+    /// assert_eq!(remaining(f64::NAN), 0.0);
+    ///
+    /// let forever = ToastOptions::default().duration_in_seconds(f64::INFINITY);
+    /// assert_eq!(forever.remaining(), None);
+    /// let expired = ToastOptions::default().duration_in_seconds(-1.0);
+    /// assert_eq!(expired.remaining(), Some(std::time::Duration::ZERO));
+    /// ```
+    #[must_use]
     pub fn duration_in_seconds(self, secs: f64) -> Self {
-        self.duration(Duration::from_secs_f64(secs))
+        // `f64::max` returns zero for NaN, and too long durations never expire
+        self.duration(Duration::try_from_secs_f64(secs.max(0.0)).ok())
     }
 
     /// Visualize remaining time using a progress bar.
+    #[must_use]
     pub fn show_progress(mut self, show_progress: bool) -> Self {
         self.show_progress = show_progress;
+        self.show_progress_set = true;
         self
     }
 
     /// Show type icon in the toast.
+    #[must_use]
     pub fn show_icon(mut self, show_icon: bool) -> Self {
         self.show_icon = show_icon;
+        self.show_icon_set = true;
+        self
+    }
+
+    /// Show a close button in the toast.
+    #[must_use]
+    pub fn show_close_button(mut self, show_close_button: bool) -> Self {
+        self.show_close_button = show_close_button;
         self
     }
 
+    /// Add the toast [paused](crate::ToastHandle::pause), so that its countdown only starts
+    /// after [`ToastHandle::resume`](crate::ToastHandle::resume) is called.
+    #[must_use]
+    pub fn start_paused(mut self, start_paused: bool) -> Self {
+        self.start_paused = start_paused;
+        self
+    }
+
+    /// Keep the width of the widest text the toast has shown, so that the toast does not
+    /// get narrower when its text is [updated](crate::ToastHandle::set_text) with a shorter
+    /// text. The width is kept for the lifetime of the toast.
+    ///
+    /// ```
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::new());
+    /// let options = ToastOptions::default().never_shrink(true);
+    /// let handle = test.add(Toast::new().text("Downloading 100%").options(options));
+    /// test.run_frames(3);
+    /// let width = test.rect(handle.id()).unwrap().width();
+    /// handle.set_text(test.ctx(), "Done");
+    /// test.run_frames(2);
+    /// assert_eq!(test.rect(handle.id()).unwrap().width(), width);
+    /// ```
+    #[must_use]
+    pub fn never_shrink(mut self, never_shrink: bool) -> Self {
+        self.never_shrink = never_shrink;
+        self
+    }
+
+    /// Let the user set the remaining time by clicking or dragging the progress bar of the
+    /// default contents: the far end of the bar restores the full duration, and dragging
+    /// towards it gives the toast more time. The toast is paused while it is hovered as usual.
+    ///
+    /// This only applies to the bar showing the remaining time, not to
+    /// [progress segments](crate::ToastHandle::set_progress_segments).
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::testing::ToastTestCtx;
+    /// # use egui_toast::{Toast, ToastOptions, Toasts};
+    /// let mut test = ToastTestCtx::new(Toasts::new());
+    /// let options = ToastOptions::default()
+    ///     .duration_in_seconds(10.0)
+    ///     .progress_interactive(true);
+    /// let handle = test.add(Toast::new().text("Build finished").options(options));
+    /// test.run_frames(2);
+    /// test.run_for(Duration::from_secs(6), 6);
+    ///
+    /// let bar = test.ctx().read_response(handle.id().progress_bar_id()).unwrap().rect;
+    /// test.click(bar.right_center() - egui::vec2(1.0, 0.0));
+    /// test.run();
+    /// let remaining = test.stored_toasts()[0].options.remaining().unwrap();
+    /// assert!(remaining > Duration::from_secs(9));
+    /// ```
+    #[must_use]
+    pub fn progress_interactive(mut self, progress_interactive: bool) -> Self {
+        self.progress_interactive = progress_interactive;
+        self
+    }
+
+    /// Set the minimum size of the toast, overriding [`Toasts::min_size`](crate::Toasts::min_size).
+    #[must_use]
+    pub fn min_size(mut self, min_size: impl Into<Vec2>) -> Self {
+        self.min_size = Some(min_size.into());
+        self
+    }
+
+    /// Lane whose copy of the [broadcast](Self::broadcast) toast was dismissed, which
+    /// dismissed the toast, e.g. to tell in [`Toast::on_dismiss`] which viewport the toast
+    /// was closed in. `None` if the toast was dismissed in its own lane.
+    pub fn dismissed_in(self) -> Option<Id> {
+        self.dismissed_in
+    }
+
+    /// Initial duration of the toast, or `None` if it never expires.
+    pub fn initial_duration(self) -> Option<Duration> {
+        self.duration
+    }
+
+    /// Remaining time of the toast, or `None` if it never expires.
+    ///
+    /// The time is counted down with the full precision of [`Duration`], so the progress
+    /// moves smoothly even when the frames are less than a millisecond apart.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use egui_toast::ToastOptions;
+    /// let options = ToastOptions::default().duration(Duration::from_secs(1));
+    /// assert_eq!(options.remaining(), Some(Duration::from_secs(1)));
+    /// assert_eq!(ToastOptions::default().remaining(), None);
+    /// ```
+    pub fn remaining(self) -> Option<Duration> {
+        if self.is_expired() {
+            return Some(Duration::ZERO);
+        }
+        self.duration.map(|_| self.remaining)
+    }
+
     /// Remaining time of the toast between 1..0
+    ///
+    /// This is always a number in that range, whatever went into the duration:
+    /// ```
+    /// # use egui_toast::ToastOptions;
+    /// let weird = [
+    ///     f64::NAN, -f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 0.0, -0.0,
+    ///     f64::MIN_POSITIVE, f64::MAX, f64::MIN, f64::EPSILON, 1e-320, -1e-320,
+    /// ];
+    /// for secs in weird {
+    ///     let options = ToastOptions::default().duration_in_seconds(secs);
+    ///     let progress = options.progress();
+    ///     assert!((0.0..=1.0).contains(&progress), "{secs}: {progress}");
+    ///     let _ = options.remaining();
+    /// }
+    /// ```
     pub fn progress(self) -> f64 {
-        if self.ttl_sec.is_finite() && self.initial_ttl_sec > 0.0 {
-            self.ttl_sec / self.initial_ttl_sec
-        } else {
-            0.0
+        match self.duration {
+            Some(duration) if !self.is_expired() && !duration.is_zero() => {
+                (self.remaining.as_secs_f64() / duration.as_secs_f64()).clamp(0.0, 1.0)
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Set the remaining time to `fraction` of the duration, as if the countdown had
+    /// been running for the rest of it.
+    pub(crate) fn seek(&mut self, fraction: f32) {
+        if let (Some(duration), false) = (self.duration, self.is_expired()) {
+            // `mul_f32` panics if rounding the product overflows, e.g. for `Duration::MAX`
+            let fraction = f64::from(fraction.clamp(0.0, 1.0));
+            self.remaining =
+                Duration::try_from_secs_f64(duration.as_secs_f64() * fraction).unwrap_or(duration);
+        }
+    }
+
+    /// Subtract `elapsed` seconds from the remaining time.
+    pub(crate) fn count_down(&mut self, elapsed: f64) {
+        let elapsed = Duration::try_from_secs_f64(elapsed).unwrap_or(Duration::MAX);
+        self.remaining = self.remaining.saturating_sub(elapsed);
+    }
+
+    /// Add `elapsed` seconds to the time shown by a [stopwatch](Self::stopwatch).
+    pub(crate) fn count_up(&mut self, elapsed: f64) {
+        let elapsed = Duration::try_from_secs_f64(elapsed).unwrap_or(Duration::MAX);
+        self.elapsed = self.elapsed.saturating_add(elapsed);
+    }
+
+    /// Time until the [stopwatch](Self::stopwatch) shows the next second.
+    pub(crate) fn until_next_second(self) -> Duration {
+        Duration::from_secs(1) - Duration::from_nanos(self.elapsed.subsec_nanos().into())
+    }
+
+    /// Show the icon and the progress as the instance does by default, unless the options
+    /// set them.
+    pub(crate) fn apply_defaults(&mut self, show_icon: bool, show_progress: bool) {
+        if !self.show_icon_set {
+            self.show_icon = show_icon;
+        }
+        if !self.show_progress_set {
+            self.show_progress = show_progress;
+        }
+    }
+
+    /// Shorten the remaining time to `remaining`. Returns `false` if the toast never expires
+    /// or has less time left.
+    pub(crate) fn cut_to(&mut self, remaining: Duration) -> bool {
+        if self.duration.is_none() || self.is_expired() || self.remaining <= remaining {
+            return false;
         }
+        self.remaining = remaining;
+        true
+    }
+
+    /// Make sure that the toast is shown for at least `min_duration`.
+    pub(crate) fn extend_to(&mut self, min_duration: Duration) {
+        if self.duration.is_some() && self.dismissed.is_none() && self.remaining < min_duration {
+            self.duration = Some(min_duration);
+            self.remaining = min_duration;
+        }
+    }
+
+    /// Dismiss the toast together with other toasts, once it was shown for its
+    /// [minimum display time](Self::min_display).
+    pub(crate) fn dismiss_in_bulk(&mut self, reason: DismissReason) {
+        if self.displayed >= self.min_display {
+            self.dismiss(reason);
+        } else if !self.is_expired() {
+            self.deferred.get_or_insert(reason);
+        }
+    }
+
+    /// Time until the toast was shown for its [minimum display time](Self::min_display).
+    pub(crate) fn until_min_display(self) -> Duration {
+        self.min_display.saturating_sub(self.displayed)
+    }
+
+    /// Add `elapsed` seconds to the time the toast has been on screen, and carry out the
+    /// deferred dismissal once the minimum display time has passed.
+    pub(crate) fn count_displayed(&mut self, elapsed: f64) {
+        let elapsed = Duration::try_from_secs_f64(elapsed).unwrap_or(Duration::MAX);
+        self.displayed = self.displayed.saturating_add(elapsed);
+        if let Some(reason) = self.deferred.filter(|_| self.displayed >= self.min_display) {
+            self.deferred = None;
+            self.dismiss(reason);
+        }
+    }
+
+    /// Remove the toast on the next frame. The first reason is kept if the toast is
+    /// dismissed several times.
+    pub(crate) fn dismiss(&mut self, reason: DismissReason) {
+        self.remaining = Duration::ZERO;
+        self.dismissed.get_or_insert(reason);
+    }
+
+    /// Whether the toast should be removed.
+    pub(crate) fn is_expired(self) -> bool {
+        self.dismissed.is_some() || (self.duration.is_some() && self.remaining.is_zero())
+    }
+}
+
+/// Options with the given [duration](ToastOptions::duration).
+///
+/// ```
+/// # use std::time::Duration;
+/// # use egui_toast::ToastOptions;
+/// let options = ToastOptions::from(Duration::from_millis(2500));
+/// assert_eq!(options.initial_duration(), ToastOptions::default().duration_in_seconds(2.5).initial_duration());
+/// ```
+impl From<Duration> for ToastOptions {
+    fn from(duration: Duration) -> Self {
+        Self::default().duration(duration)
+    }
+}
+
+/// Format the [elapsed time](Toast::elapsed) of a stopwatch as `mm:ss`, or as `h:mm:ss`
+/// after an hour.
+///
+/// ```
+/// # use std::time::Duration;
+/// assert_eq!(egui_toast::format_elapsed(Duration::from_secs(83)), "01:23");
+/// assert_eq!(egui_toast::format_elapsed(Duration::from_secs(3723)), "1:02:03");
+/// ```
+pub fn format_elapsed(elapsed: Duration) -> String {
+    let seconds = elapsed.as_secs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
     }
 }
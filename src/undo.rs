@@ -0,0 +1,40 @@
+use std::sync::{Arc, Mutex};
+
+use egui::WidgetText;
+
+use crate::{Toast, ToastButton, ToastOptions};
+
+type Commit = Box<dyn FnOnce() + Send>;
+
+/// A toast with an undo button that calls `on_commit` when the toast goes away, unless
+/// the button was clicked, see [`Toasts::undoable`](crate::Toasts::undoable).
+pub(crate) fn toast(
+    text: WidgetText,
+    undo_label: WidgetText,
+    on_commit: impl FnOnce() + Send + 'static,
+    options: ToastOptions,
+) -> Toast {
+    // Taken by whichever comes first, the undo button or the dismissal
+    let commit: Arc<Mutex<Option<Commit>>> = Arc::new(Mutex::new(Some(Box::new(on_commit))));
+    let undo = commit.clone();
+    Toast::new()
+        .text(text)
+        .options(options)
+        .button(
+            ToastButton::new(undo_label)
+                .primary(true)
+                .on_click(move |_toast| drop(take(&undo))),
+        )
+        .on_dismiss(move |_toast, _reason| {
+            if let Some(commit) = take(&commit) {
+                commit();
+            }
+        })
+}
+
+fn take(commit: &Mutex<Option<Commit>>) -> Option<Commit> {
+    commit
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .take()
+}